@@ -0,0 +1,88 @@
+//! Generate golden test vectors in the JSON lines format of `tests/test_kuids.txt`
+//!
+//! Each line has the unix `timestamp`, the upper case hex `payload` and the base62 `ksuid`,
+//! so that other implementations can validate against the same corpus:
+//! ```sh
+//! cargo run --example gen-vectors -- 1000 > vectors.txt
+//! ```
+//!
+//! With `--ms` the vectors are for [`KsuidMs`] instead, the `timestamp` is in milliseconds and
+//! the `payload` is 15 bytes.
+
+use std::io::{self, BufWriter, Write};
+use std::process::ExitCode;
+
+use svix_ksuid::{EntropySource, Ksuid, KsuidLike, KsuidMs, OsEntropy};
+
+const USAGE: &str = "Usage: gen-vectors [--ms] [COUNT]";
+const DEFAULT_COUNT: usize = 100;
+
+fn main() -> ExitCode {
+    let mut ms = false;
+    let mut count = DEFAULT_COUNT;
+    for arg in std::env::args().skip(1) {
+        match arg.as_str() {
+            "--ms" => ms = true,
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                return ExitCode::SUCCESS;
+            }
+            _ => match arg.parse() {
+                Ok(n) => count = n,
+                Err(_) => {
+                    eprintln!("Invalid argument {:?}\n{}", arg, USAGE);
+                    return ExitCode::FAILURE;
+                }
+            },
+        }
+    }
+
+    match write_vectors(io::stdout().lock(), count, ms) {
+        Ok(()) => ExitCode::SUCCESS,
+        // Stop quietly when piped into e.g. `head`
+        Err(e) if e.kind() == io::ErrorKind::BrokenPipe => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Failed to write vectors: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn write_vectors(out: impl Write, count: usize, ms: bool) -> io::Result<()> {
+    let mut out = BufWriter::new(out);
+    for _ in 0..count {
+        // Fully random bytes, so the timestamps cover the whole range too
+        let mut bytes = [0u8; 20];
+        OsEntropy.fill_bytes(&mut bytes).map_err(io::Error::other)?;
+        if ms {
+            // Millisecond timestamps can't represent every sub-second value, so round-trip
+            // through one to get a vector that can be rebuilt from the line
+            let random = KsuidMs::from_bytes(bytes);
+            let ksuid =
+                KsuidMs::from_millis(Some(random.timestamp_millis()), Some(random.payload()));
+            write_line(
+                &mut out,
+                ksuid.timestamp_millis(),
+                ksuid.payload(),
+                &ksuid.to_base62(),
+            )?;
+        } else {
+            let ksuid = Ksuid::from_bytes(bytes);
+            write_line(
+                &mut out,
+                ksuid.timestamp_seconds(),
+                ksuid.payload(),
+                &ksuid.to_base62(),
+            )?;
+        }
+    }
+    out.flush()
+}
+
+fn write_line(out: &mut impl Write, timestamp: i64, payload: &[u8], ksuid: &str) -> io::Result<()> {
+    write!(out, "{{\"timestamp\":{},\"payload\":\"", timestamp)?;
+    for byte in payload {
+        write!(out, "{:02X}", byte)?;
+    }
+    writeln!(out, "\",\"ksuid\":\"{}\"}}", ksuid)
+}
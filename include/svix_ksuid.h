@@ -0,0 +1,87 @@
+#ifndef SVIX_KSUID_H
+#define SVIX_KSUID_H
+
+/* Generated with cbindgen, do not edit manually. */
+
+#include <stddef.h>
+#include <stdint.h>
+
+// The number of bytes in a ksuid
+#define KSUID_FFI_BYTES 20
+
+// The size of the buffer needed by `ksuid_to_string`, including the trailing NUL
+#define KSUID_FFI_STRING_SIZE 28
+
+// Returned on success
+#define KSUID_OK 0
+
+// Returned when one of the pointers passed is NULL
+#define KSUID_ERR_NULL -1
+
+// Returned when the input isn't a valid ksuid
+#define KSUID_ERR_INVALID -2
+
+#if defined(SVIX_KSUID_GETRANDOM)
+// Create a new ksuid for the current time with a random payload
+//
+// Only available with the `getrandom` feature (see `SVIX_KSUID_GETRANDOM`).
+//
+// # Safety
+// `out` must either be NULL or point to a writable buffer of at least 20 bytes.
+int ksuid_new(uint8_t *out);
+#endif
+
+#if defined(SVIX_KSUID_GETRANDOM)
+// Create a new ksuid with ms accuracy for the current time with a random payload
+//
+// Only available with the `getrandom` feature (see `SVIX_KSUID_GETRANDOM`).
+//
+// # Safety
+// `out` must either be NULL or point to a writable buffer of at least 20 bytes.
+int ksuid_ms_new(uint8_t *out);
+#endif
+
+// Create a new ksuid from a unix timestamp (in seconds) and a 16 byte payload
+//
+// # Safety
+// `payload` must either be NULL or point to a readable buffer of at least 16 bytes, and
+// `out` must either be NULL or point to a writable buffer of at least 20 bytes.
+int ksuid_from_parts(int64_t timestamp, const uint8_t *payload, uint8_t *out);
+
+// Parse a base62 ksuid of `len` bytes (not including a NUL terminator, which isn't needed)
+//
+// # Safety
+// `s` must either be NULL or point to a readable buffer of at least `len` bytes, and `out`
+// must either be NULL or point to a writable buffer of at least 20 bytes.
+int ksuid_parse(const char *s, size_t len, uint8_t *out);
+
+// Check whether a string of `len` bytes is a valid base62 ksuid
+//
+// Returns `1` if it's valid, `0` if it isn't, and a negative value on failure.
+//
+// # Safety
+// `s` must either be NULL or point to a readable buffer of at least `len` bytes.
+int ksuid_is_valid(const char *s, size_t len);
+
+// Write the base62 representation of a ksuid, followed by a NUL terminator
+//
+// # Safety
+// `id` must either be NULL or point to a readable buffer of at least 20 bytes, and `out`
+// must either be NULL or point to a writable buffer of at least 28 bytes.
+int ksuid_to_string(const uint8_t *id, char *out);
+
+// Get the unix timestamp (in seconds) of a ksuid
+//
+// # Safety
+// `id` must either be NULL or point to a readable buffer of at least 20 bytes, and `out`
+// must either be NULL or point to a writable `int64_t`.
+int ksuid_timestamp(const uint8_t *id, int64_t *out);
+
+// Get the unix timestamp (in milliseconds) of a ksuid with ms accuracy
+//
+// # Safety
+// `id` must either be NULL or point to a readable buffer of at least 20 bytes, and `out`
+// must either be NULL or point to a writable `int64_t`.
+int ksuid_ms_timestamp_millis(const uint8_t *id, int64_t *out);
+
+#endif  /* SVIX_KSUID_H */
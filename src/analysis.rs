@@ -0,0 +1,77 @@
+//! Estimates for capacity planning and security reviews
+//!
+//! Two ksuids can only collide if they have the same timestamp, so these work per timestamp
+//! tick (a second for [`Ksuid`](crate::Ksuid), 4 milliseconds for
+//! [`KsuidMs`](crate::KsuidMs)) and assume that ids are created at a steady rate with
+//! uniformly random payloads.
+
+use std::time::Duration;
+
+use crate::KsuidLike;
+
+/// The number of random bits in the payload of `K`
+///
+/// # Examples
+/// ```
+/// use svix_ksuid::analysis::payload_bits;
+/// use svix_ksuid::{Ksuid, KsuidMs};
+///
+/// assert_eq!(payload_bits::<Ksuid>(), 128);
+/// assert_eq!(payload_bits::<KsuidMs>(), 120);
+/// ```
+pub fn payload_bits<K: KsuidLike>() -> u32 {
+    8 * K::PAYLOAD_BYTES as u32
+}
+
+/// The number of distinct timestamps per second of `K`
+///
+/// `KsuidMs` has a fraction byte, but only uses 250 of its values (4 millisecond units).
+fn ticks_per_second<K: KsuidLike>() -> f64 {
+    1.0 / K::PRECISION.as_duration().as_secs_f64()
+}
+
+/// The expected number of pairs of colliding ids when creating `ids_per_second` ids of type
+/// `K` for `horizon`
+///
+/// # Examples
+/// ```
+/// use std::time::Duration;
+/// use svix_ksuid::analysis::expected_collisions;
+/// use svix_ksuid::Ksuid;
+///
+/// let year = Duration::from_secs(365 * 24 * 60 * 60);
+/// assert!(expected_collisions::<Ksuid>(1_000_000.0, year) < 1e-18);
+/// ```
+pub fn expected_collisions<K: KsuidLike>(ids_per_second: f64, horizon: Duration) -> f64 {
+    let ticks_per_second = ticks_per_second::<K>();
+    let ids_per_tick = ids_per_second / ticks_per_second;
+    let ticks = horizon.as_secs_f64() * ticks_per_second;
+    // With Poisson arrivals the expected number of pairs in a tick is `ids_per_tick^2 / 2`,
+    // each colliding with probability `2^-bits`.
+    let pairs = ticks * ids_per_tick * ids_per_tick / 2.0;
+    pairs * 2f64.powi(-(payload_bits::<K>() as i32))
+}
+
+/// The probability of at least one collision when creating `ids_per_second` ids of type `K`
+/// for `horizon`
+///
+/// This is the birthday bound `1 - e^-expected_collisions`, and is accurate even for very
+/// small probabilities.
+///
+/// # Examples
+/// ```
+/// use std::time::Duration;
+/// use svix_ksuid::analysis::collision_probability;
+/// use svix_ksuid::{Ksuid, KsuidMs};
+///
+/// let year = Duration::from_secs(365 * 24 * 60 * 60);
+/// let ksuid = collision_probability::<Ksuid>(1_000_000.0, year);
+/// let ksuid_ms = collision_probability::<KsuidMs>(1_000_000.0, year);
+/// assert!(ksuid > 0.0 && ksuid < 1e-18);
+/// // At a steady rate, the 8 fewer random bits (256 times fewer payloads) are almost made up
+/// // for by the 250 times finer timestamps
+/// assert!((ksuid_ms / ksuid - 256.0 / 250.0).abs() < 1e-9);
+/// ```
+pub fn collision_probability<K: KsuidLike>(ids_per_second: f64, horizon: Duration) -> f64 {
+    -(-expected_collisions::<K>(ids_per_second, horizon)).exp_m1()
+}
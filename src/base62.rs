@@ -0,0 +1,196 @@
+//! Allocation free base62 encoding of the 20 ksuid bytes
+//!
+//! This is a port of the "fast" encoder used by the segment reference implementation.
+
+use std::fmt;
+
+use byteorder::{BigEndian, ByteOrder};
+
+use crate::{Error, BASE_62_CHARS, TOTAL_BYTES, TOTAL_BYTES_BASE62};
+
+const SRC_BASE: u64 = 1 << 32;
+const DST_BASE: u64 = 62;
+/// The number of base62 digits decoded at once, the most that fit in a u32
+const CHUNK_DIGITS: usize = 5;
+/// Marks the bytes that aren't in the alphabet in the reverse lookup table
+const INVALID: u8 = u8::MAX;
+
+/// A 62 character alphabet used for encoding and decoding base62 ksuids
+///
+/// Encoded ksuids only sort the same as their bytes if the alphabet is in ascending order.
+///
+/// # Examples
+/// ```
+/// use svix_ksuid::*;
+///
+/// let alphabet =
+///     Base62Alphabet::new("0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ").unwrap();
+/// let ksuid = Ksuid::from_base62("0ujtsYcgvSTl8PAuAdqWYSMnLOv").unwrap();
+/// assert_eq!(ksuid.to_base62_with(&alphabet), "0UJTSyCGVstL8paUaDQwysmNloV");
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Base62Alphabet {
+    chars: [u8; 62],
+    /// The value of every byte, or `INVALID`
+    values: [u8; 256],
+}
+
+impl Base62Alphabet {
+    /// The alphabet used by the segment reference implementation (`0-9A-Za-z`)
+    pub const STANDARD: Self = Self::from_chars(*BASE_62_CHARS);
+
+    /// Build the reverse lookup table, the characters must be unique
+    const fn from_chars(chars: [u8; 62]) -> Self {
+        let mut values = [INVALID; 256];
+        let mut i = 0;
+        while i < chars.len() {
+            values[chars[i] as usize] = i as u8;
+            i += 1;
+        }
+        Self { chars, values }
+    }
+
+    /// Create a new alphabet from 62 unique ASCII characters
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    ///
+    /// assert!(Base62Alphabet::new("0123456789").is_err());
+    /// ```
+    pub fn new(chars: &str) -> Result<Self, Error> {
+        let chars: [u8; 62] = chars.as_bytes().try_into().map_err(|_| {
+            Error::new(format!(
+                "Got base62 alphabet of unexpected length {}",
+                chars.len()
+            ))
+        })?;
+        let mut seen = [false; 128];
+        for &c in &chars {
+            if !c.is_ascii() {
+                return Err(Error::new(
+                    "Base62 alphabet must only contain ASCII characters".to_owned(),
+                ));
+            }
+            if seen[c as usize] {
+                return Err(Error::new(format!(
+                    "Base62 alphabet contains duplicate character {:?}",
+                    c as char
+                )));
+            }
+            seen[c as usize] = true;
+        }
+        Ok(Self::from_chars(chars))
+    }
+
+    /// Get the characters of the alphabet
+    pub fn as_str(&self) -> &str {
+        // Only ever constructed from ASCII
+        std::str::from_utf8(&self.chars).unwrap()
+    }
+
+    pub(crate) fn chars(&self) -> &[u8; 62] {
+        &self.chars
+    }
+}
+
+impl fmt::Debug for Base62Alphabet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Base62Alphabet")
+            .field(&self.as_str())
+            .finish()
+    }
+}
+
+impl Default for Base62Alphabet {
+    fn default() -> Self {
+        Self::STANDARD
+    }
+}
+
+/// View an encoded buffer as a string
+pub(crate) fn as_str(buf: &[u8; TOTAL_BYTES_BASE62]) -> &str {
+    // Alphabets are always ASCII
+    std::str::from_utf8(buf).unwrap()
+}
+
+/// Encode the ksuid bytes as base62 into a stack buffer
+pub(crate) fn encode(src: &[u8; TOTAL_BYTES]) -> [u8; TOTAL_BYTES_BASE62] {
+    encode_with(src, &Base62Alphabet::STANDARD)
+}
+
+/// Encode the ksuid bytes as base62 into a stack buffer, using the provided alphabet
+pub(crate) fn encode_with(
+    src: &[u8; TOTAL_BYTES],
+    alphabet: &Base62Alphabet,
+) -> [u8; TOTAL_BYTES_BASE62] {
+    let mut parts = [0u32; TOTAL_BYTES / 4];
+    for (part, chunk) in parts.iter_mut().zip(src.chunks_exact(4)) {
+        *part = BigEndian::read_u32(chunk);
+    }
+
+    let chars = alphabet.chars();
+    let mut dst = [chars[0]; TOTAL_BYTES_BASE62];
+    let mut n = TOTAL_BYTES_BASE62;
+    let mut len = parts.len();
+    while len != 0 {
+        let mut quotient_len = 0;
+        let mut remainder = 0u64;
+        for i in 0..len {
+            let value = parts[i] as u64 + remainder * SRC_BASE;
+            let digit = value / DST_BASE;
+            remainder = value % DST_BASE;
+            if quotient_len != 0 || digit != 0 {
+                parts[quotient_len] = digit as u32;
+                quotient_len += 1;
+            }
+        }
+        n -= 1;
+        dst[n] = chars[remainder as usize];
+        len = quotient_len;
+    }
+    dst
+}
+
+/// Decode a base62 ksuid into its bytes, using the provided alphabet
+///
+/// The digits are looked up in the alphabet's reverse table and folded into u32 limbs
+/// [`CHUNK_DIGITS`] at a time, with one more limb than a ksuid has so that values that
+/// overflow 20 bytes can be detected.
+pub(crate) fn decode_with(
+    src: &[u8; TOTAL_BYTES_BASE62],
+    alphabet: &Base62Alphabet,
+) -> Result<[u8; TOTAL_BYTES], Error> {
+    // 27 base62 digits always fit in 192 bits
+    let mut limbs = [0u32; TOTAL_BYTES / 4 + 1];
+    for chunk in src.chunks(CHUNK_DIGITS) {
+        let mut value = 0u64;
+        let mut multiplier = 1u64;
+        for &c in chunk {
+            let digit = alphabet.values[c as usize];
+            if digit == INVALID {
+                return Err(Error::new("Failed to decode".to_owned()));
+            }
+            value = value * DST_BASE + digit as u64;
+            multiplier *= DST_BASE;
+        }
+        let mut carry = value;
+        for limb in limbs.iter_mut().rev() {
+            let product = *limb as u64 * multiplier + carry;
+            *limb = product as u32;
+            carry = product / SRC_BASE;
+        }
+    }
+
+    let (overflow, limbs) = limbs.split_first().unwrap();
+    if *overflow != 0 {
+        return Err(Error::new(
+            "Got base62 ksuid that overflows 20 bytes".to_owned(),
+        ));
+    }
+    let mut dst = [0u8; TOTAL_BYTES];
+    for (chunk, &limb) in dst.chunks_exact_mut(4).zip(limbs) {
+        BigEndian::write_u32(chunk, limb);
+    }
+    Ok(dst)
+}
@@ -0,0 +1,93 @@
+//! Tagged CBOR (RFC 8949) encoding of ksuids, without any CBOR dependency
+//!
+//! A ksuid is encoded as [`KSUID_CBOR_TAG`] wrapping a byte string of its 20 raw bytes.
+
+use crate::{Error, TOTAL_BYTES};
+
+/// The CBOR tag number used for ksuids
+///
+/// This is `0x4b535549` (the ASCII for `KSUI`). It is **not** registered with IANA, it's a tag
+/// chosen by this crate, so other CBOR implementations won't know it unless told about it. The
+/// tagged item is a byte string of the 20 raw bytes of the ksuid.
+///
+/// # Examples
+/// ```
+/// use svix_ksuid::*;
+///
+/// let ksuid = Ksuid::new(None, None);
+/// let cbor = ksuid.to_cbor();
+/// // Tag 0x4b535549, then a 20 byte byte string
+/// assert_eq!(cbor[..6], [0xda, 0x4b, 0x53, 0x55, 0x49, 0x54]);
+/// assert_eq!(u32::from_be_bytes(cbor[1..5].try_into().unwrap()) as u64, KSUID_CBOR_TAG);
+/// ```
+pub const KSUID_CBOR_TAG: u64 = 0x4b53_5549;
+
+/// The length of an encoded ksuid: the 5 byte tag, the 1 byte string header and the bytes
+pub(crate) const CBOR_BYTES: usize = 6 + TOTAL_BYTES;
+
+const MAJOR_BYTES: u8 = 2;
+const MAJOR_TAG: u8 = 6;
+
+pub(crate) fn encode(bytes: &[u8; TOTAL_BYTES]) -> [u8; CBOR_BYTES] {
+    let mut buf = [0u8; CBOR_BYTES];
+    buf[0] = (MAJOR_TAG << 5) | 26;
+    buf[1..5].copy_from_slice(&(KSUID_CBOR_TAG as u32).to_be_bytes());
+    buf[5] = (MAJOR_BYTES << 5) | TOTAL_BYTES as u8;
+    buf[6..].copy_from_slice(bytes);
+    buf
+}
+
+/// Read the header of a data item, returning its major type and argument
+fn read_head(input: &mut &[u8]) -> Result<(u8, u64), Error> {
+    let truncated = || Error::new("Got truncated CBOR ksuid".to_owned());
+    let (&initial, rest) = input.split_first().ok_or_else(truncated)?;
+    let len = match initial & 0x1f {
+        info @ 0..=23 => {
+            *input = rest;
+            return Ok((initial >> 5, info as u64));
+        }
+        24 => 1,
+        25 => 2,
+        26 => 4,
+        27 => 8,
+        _ => {
+            return Err(Error::new(
+                "Got CBOR ksuid with an indefinite or reserved length".to_owned(),
+            ))
+        }
+    };
+    if rest.len() < len {
+        return Err(truncated());
+    }
+    let (argument, rest) = rest.split_at(len);
+    *input = rest;
+    let argument = argument.iter().fold(0, |n, &b| (n << 8) | b as u64);
+    Ok((initial >> 5, argument))
+}
+
+pub(crate) fn decode(mut input: &[u8]) -> Result<[u8; TOTAL_BYTES], Error> {
+    // Any argument width is accepted, as they are all well-formed CBOR
+    match read_head(&mut input)? {
+        (MAJOR_TAG, KSUID_CBOR_TAG) => {}
+        (MAJOR_TAG, tag) => {
+            return Err(Error::new(format!(
+                "Expected CBOR tag {}, got {}",
+                KSUID_CBOR_TAG, tag
+            )))
+        }
+        _ => return Err(Error::new("Expected a tagged CBOR ksuid".to_owned())),
+    }
+    match read_head(&mut input)? {
+        (MAJOR_BYTES, len) if len == input.len() as u64 => input
+            .try_into()
+            .map_err(|_| Error::new(format!("Got ksuid of unexpected length {}", len))),
+        (MAJOR_BYTES, len) => Err(Error::new(format!(
+            "Got CBOR ksuid of length {} with {} bytes left",
+            len,
+            input.len()
+        ))),
+        _ => Err(Error::new(
+            "Expected a CBOR byte string in the ksuid tag".to_owned(),
+        )),
+    }
+}
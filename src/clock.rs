@@ -0,0 +1,58 @@
+use std::sync::Arc;
+use std::time::SystemTime;
+
+/// A source of the current time for generating ksuids
+///
+/// By default the system clock is used (see [`SystemClock`]). Implement this trait to use a
+/// different time source, e.g. a mock clock in tests (see `testing::MockClock` with the
+/// `test-util` feature) or an RTC on embedded devices.
+///
+/// # Examples
+/// ```
+/// use svix_ksuid::*;
+/// use std::time::{Duration, SystemTime, UNIX_EPOCH};
+///
+/// struct Fixed;
+///
+/// impl Clock for Fixed {
+///     fn now(&self) -> SystemTime {
+///         UNIX_EPOCH + Duration::from_secs(1_621_627_443)
+///     }
+/// }
+///
+/// let mut generator = Generator::new().with_clock(Fixed);
+/// let ksuid: Ksuid = generator.generate();
+/// assert_eq!(ksuid.timestamp_seconds(), 1_621_627_443);
+/// ```
+pub trait Clock {
+    /// The current time
+    fn now(&self) -> SystemTime;
+}
+
+impl<C: Clock + ?Sized> Clock for &C {
+    fn now(&self) -> SystemTime {
+        (**self).now()
+    }
+}
+
+impl<C: Clock + ?Sized> Clock for Box<C> {
+    fn now(&self) -> SystemTime {
+        (**self).now()
+    }
+}
+
+impl<C: Clock + ?Sized> Clock for Arc<C> {
+    fn now(&self) -> SystemTime {
+        (**self).now()
+    }
+}
+
+/// The default clock, backed by `SystemTime::now()`
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+}
@@ -0,0 +1,206 @@
+//! A compact encoding for ascending streams of ksuids
+//!
+//! Every ksuid is written as the difference to the previous one (the first one to the nil
+//! ksuid), as a LEB128 varint of the 160-bit difference. Ksuids that are close together, e.g.
+//! from the same second, take fewer than 20 bytes.
+
+use std::io::{self, BufWriter, ErrorKind, Read, Write};
+use std::marker::PhantomData;
+
+use crate::{add_bytes, sub_bytes, Ksuid, KsuidLike, TOTAL_BYTES};
+
+/// The most bytes a 160-bit varint can take
+const MAX_VARINT_BYTES: usize = (TOTAL_BYTES * 8).div_ceil(7);
+
+/// Writes an ascending stream of ksuids as varint deltas to an `io::Write`
+///
+/// Writes are buffered like with [`KsuidWriter`](crate::KsuidWriter), so prefer calling
+/// [`DeltaWriter::flush`] or [`DeltaWriter::into_inner`] explicitly over relying on drop.
+///
+/// # Examples
+/// ```
+/// use svix_ksuid::*;
+///
+/// let mut ids: Vec<Ksuid> = (0..100).map(|_| Ksuid::new(None, None)).collect();
+/// ids.sort();
+///
+/// let mut writer = DeltaWriter::new(Vec::new());
+/// writer.write_all(&ids).unwrap();
+/// let encoded = writer.into_inner().unwrap();
+/// assert!(encoded.len() < ids.len() * 20);
+///
+/// let decoded: Vec<Ksuid> = DeltaReader::new(&encoded[..])
+///     .collect::<Result<_, _>>()
+///     .unwrap();
+/// assert_eq!(decoded, ids);
+/// ```
+pub struct DeltaWriter<W: Write> {
+    inner: BufWriter<W>,
+    previous: [u8; TOTAL_BYTES],
+}
+
+impl<W: Write> DeltaWriter<W> {
+    /// Create a new writer
+    pub fn new(inner: W) -> Self {
+        Self {
+            inner: BufWriter::new(inner),
+            previous: [0; TOTAL_BYTES],
+        }
+    }
+
+    /// Write a single ksuid
+    ///
+    /// Fails with `ErrorKind::InvalidInput` if it's smaller than the previous one. Repeated
+    /// ksuids are allowed.
+    pub fn write<K: KsuidLike>(&mut self, ksuid: &K) -> io::Result<()> {
+        if ksuid.bytes() < &self.previous {
+            return Err(io::Error::new(
+                ErrorKind::InvalidInput,
+                "Ksuids must be written in ascending order",
+            ));
+        }
+        let mut buf = [0u8; MAX_VARINT_BYTES];
+        let len = encode_varint(&sub_bytes(ksuid.bytes(), &self.previous), &mut buf);
+        self.inner.write_all(&buf[..len])?;
+        self.previous = *ksuid.bytes();
+        Ok(())
+    }
+
+    /// Write all of the ksuids
+    pub fn write_all<'a, K: KsuidLike + 'a>(
+        &mut self,
+        ksuids: impl IntoIterator<Item = &'a K>,
+    ) -> io::Result<()> {
+        for ksuid in ksuids {
+            self.write(ksuid)?;
+        }
+        Ok(())
+    }
+
+    /// Flush the buffered data to the underlying writer
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+
+    /// Flush the buffered data and return the underlying writer
+    pub fn into_inner(self) -> io::Result<W> {
+        self.inner.into_inner().map_err(|e| e.into_error())
+    }
+}
+
+/// Reads a stream of ksuids written by [`DeltaWriter`] from an `io::Read`
+///
+/// Reads are done a byte at a time, so wrap the reader in a `BufReader` unless it's already in
+/// memory. It's also an iterator of `io::Result<K>`, which ends at the end of the stream.
+pub struct DeltaReader<R: Read, K = Ksuid> {
+    inner: R,
+    previous: [u8; TOTAL_BYTES],
+    ksuid: PhantomData<K>,
+}
+
+impl<R: Read, K: KsuidLike<Type = K>> DeltaReader<R, K> {
+    /// Create a new reader
+    pub fn new(inner: R) -> Self {
+        Self {
+            inner,
+            previous: [0; TOTAL_BYTES],
+            ksuid: PhantomData,
+        }
+    }
+
+    /// Read the next ksuid, or `None` at the end of the stream
+    ///
+    /// Fails with `ErrorKind::UnexpectedEof` if the stream ends in the middle of a ksuid, and
+    /// with `ErrorKind::InvalidData` if it's corrupted.
+    pub fn read(&mut self) -> io::Result<Option<K>> {
+        let delta = match decode_varint(&mut self.inner)? {
+            Some(delta) => delta,
+            None => return Ok(None),
+        };
+        let bytes = add_bytes(&self.previous, &delta);
+        if bytes < self.previous {
+            return Err(invalid_data());
+        }
+        self.previous = bytes;
+        Ok(Some(K::from_bytes(bytes)))
+    }
+
+    /// Return the underlying reader
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R: Read, K: KsuidLike<Type = K>> Iterator for DeltaReader<R, K> {
+    type Item = io::Result<K>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read().transpose()
+    }
+}
+
+fn invalid_data() -> io::Error {
+    io::Error::new(ErrorKind::InvalidData, "Invalid ksuid delta")
+}
+
+/// Split a 160-bit big-endian number into its high 32 and low 128 bits
+fn split(bytes: &[u8; TOTAL_BYTES]) -> (u32, u128) {
+    let high = u32::from_be_bytes(bytes[..4].try_into().unwrap());
+    let low = u128::from_be_bytes(bytes[4..].try_into().unwrap());
+    (high, low)
+}
+
+fn join(high: u32, low: u128) -> [u8; TOTAL_BYTES] {
+    let mut bytes = [0u8; TOTAL_BYTES];
+    bytes[..4].copy_from_slice(&high.to_be_bytes());
+    bytes[4..].copy_from_slice(&low.to_be_bytes());
+    bytes
+}
+
+fn encode_varint(value: &[u8; TOTAL_BYTES], buf: &mut [u8; MAX_VARINT_BYTES]) -> usize {
+    let (mut high, mut low) = split(value);
+    let mut len = 0;
+    loop {
+        let byte = (low & 0x7f) as u8;
+        low = (low >> 7) | ((high as u128) << 121);
+        high >>= 7;
+        if high == 0 && low == 0 {
+            buf[len] = byte;
+            return len + 1;
+        }
+        buf[len] = byte | 0x80;
+        len += 1;
+    }
+}
+
+fn decode_varint<R: Read>(reader: &mut R) -> io::Result<Option<[u8; TOTAL_BYTES]>> {
+    let (mut high, mut low) = (0u32, 0u128);
+    for i in 0..MAX_VARINT_BYTES {
+        let mut byte = [0u8];
+        if let Err(e) = reader.read_exact(&mut byte) {
+            return match e.kind() {
+                ErrorKind::UnexpectedEof if i == 0 => Ok(None),
+                _ => Err(e),
+            };
+        }
+        let bits = (byte[0] & 0x7f) as u128;
+        let shift = 7 * i as u32;
+        if shift < 128 {
+            low |= bits << shift;
+            if shift > 121 {
+                high |= (bits >> (128 - shift)) as u32;
+            }
+        } else {
+            // Only 32 bits are left for the high part
+            let bits = bits as u64;
+            if bits >> (160 - shift) != 0 {
+                return Err(invalid_data());
+            }
+            high |= (bits << (shift - 128)) as u32;
+        }
+        if byte[0] & 0x80 == 0 {
+            return Ok(Some(join(high, low)));
+        }
+    }
+    Err(invalid_data())
+}
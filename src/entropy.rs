@@ -0,0 +1,158 @@
+use std::sync::{Mutex, OnceLock};
+
+use crate::Error;
+
+/// A source of entropy for the random payload of ksuids
+///
+/// By default payloads come from the operating system (see [`OsEntropy`]). Implement this
+/// trait to use a different source, e.g. an HSM or a DRBG, either per construction with
+/// [`KsuidLike::new_with_entropy`](crate::KsuidLike::new_with_entropy) or for the whole
+/// library with [`set_entropy_source`].
+///
+/// # Examples
+/// ```
+/// use svix_ksuid::*;
+///
+/// struct Fixed;
+///
+/// impl EntropySource for Fixed {
+///     fn fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+///         dest.fill(7);
+///         Ok(())
+///     }
+/// }
+///
+/// let ksuid = Ksuid::new_with_entropy(None, &mut Fixed).unwrap();
+/// assert_eq!(ksuid.payload(), &[7u8; 16]);
+/// ```
+pub trait EntropySource {
+    /// Fill `dest` with random bytes
+    fn fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error>;
+}
+
+impl<E: EntropySource + ?Sized> EntropySource for &mut E {
+    fn fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        (**self).fill_bytes(dest)
+    }
+}
+
+impl<E: EntropySource + ?Sized> EntropySource for Box<E> {
+    fn fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        (**self).fill_bytes(dest)
+    }
+}
+
+/// The default entropy source, backed by the operating system through `getrandom`
+#[cfg(feature = "getrandom")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct OsEntropy;
+
+#[cfg(feature = "getrandom")]
+impl EntropySource for OsEntropy {
+    fn fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        getrandom::getrandom(dest).map_err(|e| Error::new(format!("Failed to get entropy: {}", e)))
+    }
+}
+
+/// The entropy source set with [`set_entropy_source`], falling back to [`OsEntropy`]
+///
+/// This is what ksuids created with a random payload use by default. Without the `getrandom`
+/// feature there is no fallback, so it fails until a source is set.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DefaultEntropy;
+
+impl EntropySource for DefaultEntropy {
+    fn fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        match ENTROPY_SOURCE.get() {
+            Some(source) => source
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .fill_bytes(dest),
+            None => fallback_fill_bytes(dest),
+        }
+    }
+}
+
+#[cfg(feature = "getrandom")]
+fn fallback_fill_bytes(dest: &mut [u8]) -> Result<(), Error> {
+    OsEntropy.fill_bytes(dest)
+}
+
+#[cfg(not(feature = "getrandom"))]
+fn fallback_fill_bytes(_dest: &mut [u8]) -> Result<(), Error> {
+    Err(Error::new(
+        "No entropy source was set with set_entropy_source".to_owned(),
+    ))
+}
+
+type GlobalEntropySource = Mutex<Box<dyn EntropySource + Send>>;
+
+static ENTROPY_SOURCE: OnceLock<GlobalEntropySource> = OnceLock::new();
+
+/// Set the entropy source used for all ksuids created with a random payload
+///
+/// This can only be set once, and should be done at startup before any ksuids are created.
+/// Until it's set, [`DefaultEntropy`] falls back to [`OsEntropy`] with the `getrandom`
+/// feature, and fails without it.
+///
+/// # Examples
+/// ```
+/// use svix_ksuid::*;
+///
+/// set_entropy_source(OsEntropy).unwrap();
+/// assert!(set_entropy_source(OsEntropy).is_err());
+/// ```
+pub fn set_entropy_source<E: EntropySource + Send + 'static>(source: E) -> Result<(), Error> {
+    ENTROPY_SOURCE
+        .set(Mutex::new(Box::new(source)))
+        .map_err(|_| Error::new("Entropy source was already set".to_owned()))
+}
+
+type EntropyFailureHook = Box<dyn Fn(&Error) + Send + Sync>;
+
+static ENTROPY_FAILURE_HOOK: OnceLock<EntropyFailureHook> = OnceLock::new();
+
+/// Set a hook that is called whenever an entropy source fails while creating a ksuid
+///
+/// The hook is called with the error before it's returned, or before the library panics for
+/// constructors that don't return errors (e.g. [`KsuidLike::new`](crate::KsuidLike::new)).
+/// Use it to alert on degraded entropy instead of finding out from crash logs. It's called
+/// for the global entropy source as well as for sources passed to
+/// [`KsuidLike::new_with_entropy`](crate::KsuidLike::new_with_entropy) and
+/// [`Generator`](crate::Generator), but not when calling
+/// [`EntropySource::fill_bytes`] directly.
+///
+/// This can only be set once, like [`set_entropy_source`]. Keep the hook cheap and don't
+/// create ksuids from it.
+///
+/// # Examples
+/// ```
+/// use svix_ksuid::*;
+///
+/// set_entropy_failure_hook(|err| eprintln!("ksuid entropy failure: {}", err)).unwrap();
+/// ```
+pub fn set_entropy_failure_hook<F: Fn(&Error) + Send + Sync + 'static>(
+    hook: F,
+) -> Result<(), Error> {
+    ENTROPY_FAILURE_HOOK
+        .set(Box::new(hook))
+        .map_err(|_| Error::new("Entropy failure hook was already set".to_owned()))
+}
+
+/// Fill `dest` using `source`, reporting failures to the entropy failure hook
+pub(crate) fn fill<E: EntropySource + ?Sized>(
+    source: &mut E,
+    dest: &mut [u8],
+) -> Result<(), Error> {
+    let result = source.fill_bytes(dest);
+    if let (Err(err), Some(hook)) = (&result, ENTROPY_FAILURE_HOOK.get()) {
+        hook(err);
+    }
+    result
+}
+
+/// Fill `dest` using the global entropy source, panicking on failure
+#[cfg(feature = "getrandom")]
+pub(crate) fn fill_random(dest: &mut [u8]) {
+    fill(&mut DefaultEntropy, dest).unwrap();
+}
@@ -0,0 +1,193 @@
+//! C bindings
+//!
+//! Ksuids are passed around as 20 byte buffers, and all of the functions return `0` on success
+//! and a negative value on failure. The C header is in `include/svix_ksuid.h` and is generated
+//! with `cbindgen` (see `cbindgen.toml`). Define `SVIX_KSUID_GETRANDOM` before including it
+//! when the library is built with the `getrandom` feature, to declare the functions that need
+//! it.
+//!
+//! To build a static library for linking from C or C++:
+//! ```sh
+//! cargo rustc --release --features ffi --crate-type staticlib
+//! ```
+
+use std::os::raw::{c_char, c_int};
+use std::{ptr, slice};
+
+use crate::{
+    base62, from_millis_and_payload, is_valid_ksuid, Ksuid, KsuidLike, KsuidMs, TimestampOverflow,
+    TOTAL_BYTES, TOTAL_BYTES_BASE62,
+};
+
+/// The number of bytes in a ksuid
+pub const KSUID_FFI_BYTES: usize = 20;
+/// The size of the buffer needed by `ksuid_to_string`, including the trailing NUL
+pub const KSUID_FFI_STRING_SIZE: usize = 28;
+
+// Literals so that cbindgen can evaluate them
+const _: () = assert!(KSUID_FFI_BYTES == TOTAL_BYTES);
+const _: () = assert!(KSUID_FFI_STRING_SIZE == TOTAL_BYTES_BASE62 + 1);
+
+/// Returned on success
+pub const KSUID_OK: c_int = 0;
+/// Returned when one of the pointers passed is NULL
+pub const KSUID_ERR_NULL: c_int = -1;
+/// Returned when the input isn't a valid ksuid
+pub const KSUID_ERR_INVALID: c_int = -2;
+
+/// Create a new ksuid for the current time with a random payload
+///
+/// Only available with the `getrandom` feature (see `SVIX_KSUID_GETRANDOM`).
+///
+/// # Safety
+/// `out` must either be NULL or point to a writable buffer of at least 20 bytes.
+#[cfg(feature = "getrandom")]
+#[no_mangle]
+pub unsafe extern "C" fn ksuid_new(out: *mut u8) -> c_int {
+    write_bytes(Ksuid::from_seconds(None, None).bytes(), out)
+}
+
+/// Create a new ksuid with ms accuracy for the current time with a random payload
+///
+/// Only available with the `getrandom` feature (see `SVIX_KSUID_GETRANDOM`).
+///
+/// # Safety
+/// `out` must either be NULL or point to a writable buffer of at least 20 bytes.
+#[cfg(feature = "getrandom")]
+#[no_mangle]
+pub unsafe extern "C" fn ksuid_ms_new(out: *mut u8) -> c_int {
+    write_bytes(KsuidMs::from_millis(None, None).bytes(), out)
+}
+
+/// Create a new ksuid from a unix timestamp (in seconds) and a 16 byte payload
+///
+/// # Safety
+/// `payload` must either be NULL or point to a readable buffer of at least 16 bytes, and
+/// `out` must either be NULL or point to a writable buffer of at least 20 bytes.
+#[no_mangle]
+pub unsafe extern "C" fn ksuid_from_parts(
+    timestamp: i64,
+    payload: *const u8,
+    out: *mut u8,
+) -> c_int {
+    if payload.is_null() {
+        return KSUID_ERR_NULL;
+    }
+    let payload = slice::from_raw_parts(payload, Ksuid::PAYLOAD_BYTES);
+    let ksuid = from_millis_and_payload::<Ksuid>(
+        timestamp.saturating_mul(1_000),
+        payload,
+        TimestampOverflow::Wrap,
+    )
+    .unwrap();
+    write_bytes(ksuid.bytes(), out)
+}
+
+/// Parse a base62 ksuid of `len` bytes (not including a NUL terminator, which isn't needed)
+///
+/// # Safety
+/// `s` must either be NULL or point to a readable buffer of at least `len` bytes, and `out`
+/// must either be NULL or point to a writable buffer of at least 20 bytes.
+#[no_mangle]
+pub unsafe extern "C" fn ksuid_parse(s: *const c_char, len: usize, out: *mut u8) -> c_int {
+    let s = match read_str(s, len) {
+        Ok(s) => s,
+        Err(code) => return code,
+    };
+    match Ksuid::from_base62(s) {
+        Ok(ksuid) => write_bytes(ksuid.bytes(), out),
+        Err(_) => KSUID_ERR_INVALID,
+    }
+}
+
+/// Check whether a string of `len` bytes is a valid base62 ksuid
+///
+/// Returns `1` if it's valid, `0` if it isn't, and a negative value on failure.
+///
+/// # Safety
+/// `s` must either be NULL or point to a readable buffer of at least `len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn ksuid_is_valid(s: *const c_char, len: usize) -> c_int {
+    match read_str(s, len) {
+        Ok(s) => is_valid_ksuid(s) as c_int,
+        Err(KSUID_ERR_INVALID) => 0,
+        Err(code) => code,
+    }
+}
+
+/// Write the base62 representation of a ksuid, followed by a NUL terminator
+///
+/// # Safety
+/// `id` must either be NULL or point to a readable buffer of at least 20 bytes, and `out`
+/// must either be NULL or point to a writable buffer of at least 28 bytes.
+#[no_mangle]
+pub unsafe extern "C" fn ksuid_to_string(id: *const u8, out: *mut c_char) -> c_int {
+    let ksuid = match read_ksuid(id) {
+        Some(ksuid) => ksuid,
+        None => return KSUID_ERR_NULL,
+    };
+    if out.is_null() {
+        return KSUID_ERR_NULL;
+    }
+    let encoded = base62::encode(ksuid.bytes());
+    ptr::copy_nonoverlapping(encoded.as_ptr(), out as *mut u8, TOTAL_BYTES_BASE62);
+    *out.add(TOTAL_BYTES_BASE62) = 0;
+    KSUID_OK
+}
+
+/// Get the unix timestamp (in seconds) of a ksuid
+///
+/// # Safety
+/// `id` must either be NULL or point to a readable buffer of at least 20 bytes, and `out`
+/// must either be NULL or point to a writable `int64_t`.
+#[no_mangle]
+pub unsafe extern "C" fn ksuid_timestamp(id: *const u8, out: *mut i64) -> c_int {
+    match (read_ksuid(id), out.is_null()) {
+        (Some(ksuid), false) => {
+            *out = ksuid.timestamp_seconds();
+            KSUID_OK
+        }
+        _ => KSUID_ERR_NULL,
+    }
+}
+
+/// Get the unix timestamp (in milliseconds) of a ksuid with ms accuracy
+///
+/// # Safety
+/// `id` must either be NULL or point to a readable buffer of at least 20 bytes, and `out`
+/// must either be NULL or point to a writable `int64_t`.
+#[no_mangle]
+pub unsafe extern "C" fn ksuid_ms_timestamp_millis(id: *const u8, out: *mut i64) -> c_int {
+    match (read_ksuid(id), out.is_null()) {
+        (Some(ksuid), false) => {
+            *out = KsuidMs::from_bytes(*ksuid.bytes()).timestamp_millis();
+            KSUID_OK
+        }
+        _ => KSUID_ERR_NULL,
+    }
+}
+
+unsafe fn write_bytes(bytes: &[u8; TOTAL_BYTES], out: *mut u8) -> c_int {
+    if out.is_null() {
+        return KSUID_ERR_NULL;
+    }
+    ptr::copy_nonoverlapping(bytes.as_ptr(), out, TOTAL_BYTES);
+    KSUID_OK
+}
+
+unsafe fn read_ksuid(id: *const u8) -> Option<Ksuid> {
+    if id.is_null() {
+        return None;
+    }
+    let mut bytes = [0u8; TOTAL_BYTES];
+    ptr::copy_nonoverlapping(id, bytes.as_mut_ptr(), TOTAL_BYTES);
+    Some(Ksuid::from_bytes(bytes))
+}
+
+unsafe fn read_str<'a>(s: *const c_char, len: usize) -> Result<&'a str, c_int> {
+    if s.is_null() {
+        return Err(KSUID_ERR_NULL);
+    }
+    let bytes = slice::from_raw_parts(s as *const u8, len);
+    std::str::from_utf8(bytes).map_err(|_| KSUID_ERR_INVALID)
+}
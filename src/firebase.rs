@@ -0,0 +1,103 @@
+//! Conversions between Firebase push ids and `KsuidMs`
+//!
+//! A push id is 20 characters of a 64 character alphabet: 8 for a 48-bit timestamp in
+//! milliseconds followed by 12 for 72 random bits. The alphabet is in ascending ASCII order,
+//! so push ids sort by time like ksuids do.
+
+use crate::{from_millis_and_payload, Error, KsuidLike, KsuidMs, TimestampOverflow};
+
+const PUSH_CHARS: &[u8; 64] = b"-0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ_abcdefghijklmnopqrstuvwxyz";
+const PUSH_ID_LENGTH: usize = 20;
+const TIMESTAMP_CHARS: usize = 8;
+const RANDOM_CHARS: usize = PUSH_ID_LENGTH - TIMESTAMP_CHARS;
+/// 72 random bits
+const RANDOM_BYTES: usize = RANDOM_CHARS * 6 / 8;
+
+fn decode_char(c: u8) -> Option<u64> {
+    PUSH_CHARS.iter().position(|&x| x == c).map(|i| i as u64)
+}
+
+fn encode_chars(mut value: u128, out: &mut [u8]) {
+    for c in out.iter_mut().rev() {
+        *c = PUSH_CHARS[(value & 0x3f) as usize];
+        value >>= 6;
+    }
+}
+
+impl KsuidMs {
+    /// Creates new KsuidMs from a Firebase push id, e.g. `-JSOpn9ZC54A4P4RoqVa`
+    ///
+    /// `KsuidMs` timestamps only have 4ms accuracy, so the remaining milliseconds are stored
+    /// in the first byte of the payload, followed by the 72 random bits of the push id in the
+    /// next 9 bytes and zeros. This keeps the order of the push ids, and the conversion back
+    /// with [`KsuidMs::to_firebase_push_id`] is lossless. Push ids from before
+    /// [`KSUID_MIN_TIMESTAMP`](crate::KSUID_MIN_TIMESTAMP) fail with
+    /// [`Error::TimestampOutOfRange`].
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    ///
+    /// let ksuid = KsuidMs::from_firebase_push_id("-JSOpn9ZC54A4P4RoqVa").unwrap();
+    /// assert_eq!(ksuid.timestamp_millis(), 1_405_961_581_220);
+    /// assert_eq!(ksuid.to_firebase_push_id(), "-JSOpn9ZC54A4P4RoqVa");
+    /// ```
+    pub fn from_firebase_push_id(s: &str) -> Result<Self, Error> {
+        if s.len() != PUSH_ID_LENGTH {
+            return Err(Error::new(format!(
+                "Got firebase push id of unexpected length {}",
+                s.len()
+            )));
+        }
+        let mut millis = 0u64;
+        let mut random = 0u128;
+        for (i, c) in s.bytes().enumerate() {
+            let digit = decode_char(c).ok_or_else(|| {
+                Error::new(format!(
+                    "Firebase push id contains invalid character {:?}",
+                    c as char
+                ))
+            })?;
+            if i < TIMESTAMP_CHARS {
+                millis = (millis << 6) | digit;
+            } else {
+                random = (random << 6) | digit as u128;
+            }
+        }
+
+        let mut payload = [0u8; Self::PAYLOAD_BYTES];
+        payload[0] = (millis % 4) as u8;
+        payload[1..=RANDOM_BYTES].copy_from_slice(&random.to_be_bytes()[16 - RANDOM_BYTES..]);
+        // 48 bits always fit
+        from_millis_and_payload::<Self>(millis as i64, &payload, TimestampOverflow::Error)
+    }
+
+    /// Convert the KsuidMs to a Firebase push id
+    ///
+    /// This is the inverse of [`KsuidMs::from_firebase_push_id`]. For other ksuids, the
+    /// milliseconds are taken from the timestamp and the low 2 bits of the first byte of the
+    /// payload, and the random bits from the next 9 bytes, so the rest of the payload is lost.
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    ///
+    /// let a = KsuidMs::from_millis(Some(1_621_627_443_000), None);
+    /// let b = KsuidMs::from_millis(Some(1_621_627_443_004), None);
+    /// assert!(a.to_firebase_push_id() < b.to_firebase_push_id());
+    /// ```
+    pub fn to_firebase_push_id(&self) -> String {
+        let payload = self.payload();
+        let millis = self.timestamp_millis() as u128 + (payload[0] & 3) as u128;
+        let random = payload[1..=RANDOM_BYTES]
+            .iter()
+            .fold(0u128, |n, &b| (n << 8) | b as u128);
+
+        let mut buf = [0u8; PUSH_ID_LENGTH];
+        let (timestamp_buf, random_buf) = buf.split_at_mut(TIMESTAMP_CHARS);
+        encode_chars(millis, timestamp_buf);
+        encode_chars(random, random_buf);
+        // The alphabet is ASCII
+        String::from_utf8(buf.to_vec()).unwrap()
+    }
+}
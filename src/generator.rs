@@ -0,0 +1,132 @@
+use crate::{
+    entropy, Clock, DefaultEntropy, EntropySource, Error, KsuidLike, SystemClock, TOTAL_BYTES,
+};
+
+/// A ksuid generator with a configurable clock and entropy source
+///
+/// `Ksuid::new()` and friends always use the system clock and the global entropy source. A
+/// `Generator` makes both of them pluggable, which is mostly useful for controlling time in
+/// tests, or for using a dedicated entropy source for some of the ksuids.
+///
+/// # Examples
+/// ```
+/// use svix_ksuid::*;
+///
+/// let mut generator = Generator::new().with_entropy(OsEntropy);
+/// let ksuid: Ksuid = generator.generate();
+/// let ksuidms = generator.generate::<KsuidMs>();
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Generator<C = SystemClock, E = DefaultEntropy> {
+    clock: C,
+    entropy: E,
+    region: Option<u8>,
+}
+
+impl Generator {
+    /// Create a new generator using the system clock and the global entropy source
+    #[cfg(feature = "getrandom")]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<E: EntropySource> Generator<SystemClock, E> {
+    /// Create a new generator using the system clock and `entropy`
+    ///
+    /// Unlike [`Generator::new`], this is also available without the `getrandom` feature.
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    ///
+    /// struct Fixed;
+    ///
+    /// impl EntropySource for Fixed {
+    ///     fn fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+    ///         dest.fill(7);
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// let ksuid: Ksuid = Generator::from_entropy(Fixed).generate();
+    /// assert_eq!(ksuid.payload(), &[7u8; 16]);
+    /// ```
+    pub fn from_entropy(entropy: E) -> Self {
+        Generator {
+            clock: SystemClock,
+            entropy,
+            region: None,
+        }
+    }
+}
+
+impl<C: Clock, E: EntropySource> Generator<C, E> {
+    /// Use `clock` as the time source
+    pub fn with_clock<C2: Clock>(self, clock: C2) -> Generator<C2, E> {
+        Generator {
+            clock,
+            entropy: self.entropy,
+            region: self.region,
+        }
+    }
+
+    /// Use `entropy` as the source of the random payloads
+    pub fn with_entropy<E2: EntropySource>(self, entropy: E2) -> Generator<C, E2> {
+        Generator {
+            clock: self.clock,
+            entropy,
+            region: self.region,
+        }
+    }
+
+    /// Reserve the first payload byte for `region`, e.g. the region or datacenter minting the
+    /// ksuids, which can be read back with [`KsuidLike::region`]
+    ///
+    /// This leaves one byte less of randomness in the payload (15 bytes for `Ksuid`, 14 for
+    /// `KsuidMs`). Ksuids with the same timestamp sort by region.
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    ///
+    /// const EU_WEST: u8 = 3;
+    ///
+    /// let mut generator = Generator::new().with_region(EU_WEST);
+    /// let ksuid: Ksuid = generator.generate();
+    /// assert_eq!(ksuid.region(), EU_WEST);
+    /// ```
+    pub fn with_region(mut self, region: u8) -> Self {
+        self.region = Some(region);
+        self
+    }
+
+    /// Get the clock used by the generator
+    pub fn clock(&self) -> &C {
+        &self.clock
+    }
+
+    /// Generate a new ksuid, panicking if the entropy source fails
+    pub fn generate<K: KsuidLike<Type = K>>(&mut self) -> K {
+        self.try_generate().unwrap()
+    }
+
+    /// Generate a new ksuid
+    pub fn try_generate<K: KsuidLike<Type = K>>(&mut self) -> Result<K, Error> {
+        #[cfg(feature = "metrics")]
+        let start = std::time::Instant::now();
+        let mut payload = [0u8; TOTAL_BYTES];
+        let payload = &mut payload[..K::PAYLOAD_BYTES];
+        match self.region {
+            Some(region) => {
+                payload[0] = region;
+                entropy::fill(&mut self.entropy, &mut payload[1..])?;
+            }
+            None => entropy::fill(&mut self.entropy, payload)?,
+        }
+        let ksuid = K::from_system_time_and_payload(self.clock.now(), payload);
+        #[cfg(feature = "metrics")]
+        crate::integrations::metrics::record_generated::<K>(start);
+        Ok(ksuid)
+    }
+}
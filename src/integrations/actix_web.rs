@@ -0,0 +1,115 @@
+use std::future::{ready, Ready};
+use std::str::FromStr;
+
+use ::actix_web::dev::Payload;
+use ::actix_web::error::{ErrorInternalServerError, InternalError, PathError, QueryPayloadError};
+use ::actix_web::http::StatusCode;
+use ::actix_web::{FromRequest, HttpRequest, HttpResponse, ResponseError};
+
+use super::rejection::{problem_json, KsuidRejection, PROBLEM_CONTENT_TYPE};
+use crate::{Error, Ksuid, KsuidMs};
+
+impl ResponseError for KsuidRejection {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::BAD_REQUEST
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(self.status_code())
+            .content_type(PROBLEM_CONTENT_TYPE)
+            .body(self.to_problem_json())
+    }
+}
+
+/// Extracts the ksuid from the only dynamic segment of the path
+///
+/// Invalid ids are rejected with a [`KsuidRejection`]. Routes that don't have exactly one
+/// dynamic segment are a server bug, so they get a `500 Internal Server Error` instead. Use
+/// `web::Path` with the `serde` feature for routes with more than one segment.
+///
+/// # Examples
+/// ```
+/// use actix_web::{web, App, HttpResponse};
+/// use svix_ksuid::*;
+///
+/// async fn get_item(id: Ksuid) -> HttpResponse {
+///     HttpResponse::Ok().body(id.to_string())
+/// }
+///
+/// let app = App::new().route("/items/{id}", web::get().to(get_item));
+/// ```
+impl FromRequest for Ksuid {
+    type Error = ::actix_web::Error;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
+        ready(from_path(req))
+    }
+}
+
+/// Extracts the ksuid from the only dynamic segment of the path
+impl FromRequest for KsuidMs {
+    type Error = ::actix_web::Error;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
+        ready(from_path(req))
+    }
+}
+
+fn from_path<K: FromStr<Err = Error>>(req: &HttpRequest) -> Result<K, ::actix_web::Error> {
+    let mut params = req.match_info().iter();
+    match (params.next(), params.next()) {
+        (Some((_, value)), None) => value
+            .parse()
+            .map_err(|e: Error| KsuidRejection::new(e.to_string()).into()),
+        _ => Err(ErrorInternalServerError(
+            "Expected a single path parameter for the ksuid",
+        )),
+    }
+}
+
+/// An error handler for `web::PathConfig` rendering errors as problem details
+///
+/// This makes `web::Path` extractors respond with a `400` problem details body like the one of
+/// the `Ksuid` extractor, for any path parameter that fails to deserialize.
+///
+/// # Examples
+/// ```
+/// use actix_web::{web, App};
+/// use svix_ksuid::*;
+///
+/// let app = App::new().app_data(web::PathConfig::default().error_handler(actix_path_error_handler));
+/// ```
+pub fn actix_path_error_handler(err: PathError, _req: &HttpRequest) -> ::actix_web::Error {
+    let body = problem_json("Invalid path parameters", 400, &err.to_string());
+    bad_request(err, body)
+}
+
+/// An error handler for `web::QueryConfig` rendering errors as problem details
+///
+/// This makes `web::Query` extractors respond with a `400` problem details body like the one
+/// of the `Ksuid` extractor, for any query parameter that fails to deserialize.
+///
+/// # Examples
+/// ```
+/// use actix_web::{web, App};
+/// use svix_ksuid::*;
+///
+/// let app =
+///     App::new().app_data(web::QueryConfig::default().error_handler(actix_query_error_handler));
+/// ```
+pub fn actix_query_error_handler(err: QueryPayloadError, _req: &HttpRequest) -> ::actix_web::Error {
+    let body = problem_json("Invalid query string", 400, &err.to_string());
+    bad_request(err, body)
+}
+
+fn bad_request<E: std::fmt::Debug + std::fmt::Display + 'static>(
+    err: E,
+    body: String,
+) -> ::actix_web::Error {
+    let response = HttpResponse::BadRequest()
+        .content_type(PROBLEM_CONTENT_TYPE)
+        .body(body);
+    InternalError::from_response(err, response).into()
+}
@@ -0,0 +1,110 @@
+use ::apache_avro::types::Value;
+use ::apache_avro::Schema;
+
+use crate::{Error, Ksuid, KsuidLike, KsuidMs, TOTAL_BYTES};
+
+/// The canonical Avro schema for ksuids, a `fixed` of their 20 raw bytes
+///
+/// Readers that don't know the `ksuid` logical type fall back to the plain `fixed`, which
+/// compares the same way as the ksuids. Both `Ksuid` and `KsuidMs` use this schema.
+///
+/// # Examples
+/// ```
+/// use svix_ksuid::*;
+///
+/// assert_eq!(
+///     KSUID_AVRO_SCHEMA,
+///     r#"{"type":"fixed","name":"Ksuid","namespace":"com.svix","size":20,"logicalType":"ksuid"}"#
+/// );
+/// ```
+pub const KSUID_AVRO_SCHEMA: &str =
+    r#"{"type":"fixed","name":"Ksuid","namespace":"com.svix","size":20,"logicalType":"ksuid"}"#;
+
+/// Get the parsed [`KSUID_AVRO_SCHEMA`]
+///
+/// Note that the serde implementations of ksuids use base62 strings, so values written with
+/// this schema should be converted to and from [`Value`] directly.
+///
+/// # Examples
+/// ```
+/// use apache_avro::types::Value;
+/// use svix_ksuid::*;
+///
+/// let ksuid = Ksuid::new(None, None);
+/// let value = Value::from(ksuid);
+/// assert!(value.validate(&ksuid_avro_schema()));
+/// ```
+pub fn ksuid_avro_schema() -> Schema {
+    // Parsing a constant, so this can only fail if the schema itself is wrong
+    Schema::parse_str(KSUID_AVRO_SCHEMA).unwrap()
+}
+
+fn value_bytes(value: Value) -> Result<[u8; TOTAL_BYTES], Error> {
+    match value {
+        Value::Fixed(_, bytes) | Value::Bytes(bytes) => bytes
+            .as_slice()
+            .try_into()
+            .map_err(|_| Error::new(format!("Got ksuid of unexpected length {}", bytes.len()))),
+        // Accepted for schemas that store ksuids as base62 strings
+        Value::String(s) => Ksuid::from_base62(&s).map(Ksuid::into_bytes),
+        Value::Union(_, value) => value_bytes(*value),
+        other => Err(Error::new(format!(
+            "Expected an Avro fixed, bytes or string ksuid, got {:?}",
+            other
+        ))),
+    }
+}
+
+/// Converts the ksuid to an Avro `fixed` of its 20 raw bytes, matching [`KSUID_AVRO_SCHEMA`]
+///
+/// # Examples
+/// ```
+/// use apache_avro::types::Value;
+/// use svix_ksuid::*;
+///
+/// let ksuid = Ksuid::new(None, None);
+/// assert_eq!(Value::from(ksuid), Value::Fixed(20, ksuid.bytes().to_vec()));
+/// ```
+impl From<Ksuid> for Value {
+    fn from(ksuid: Ksuid) -> Self {
+        Value::Fixed(TOTAL_BYTES, ksuid.bytes().to_vec())
+    }
+}
+
+/// Loads a ksuid from an Avro `fixed` or `bytes` of its 20 raw bytes, or from a base62
+/// `string`, unwrapping unions (e.g. nullable fields) along the way
+///
+/// # Examples
+/// ```
+/// use apache_avro::types::Value;
+/// use svix_ksuid::*;
+///
+/// let ksuid = Ksuid::new(None, None);
+/// assert_eq!(Ksuid::try_from(Value::from(ksuid)).unwrap(), ksuid);
+/// assert_eq!(Ksuid::try_from(Value::String(ksuid.to_string())).unwrap(), ksuid);
+/// assert!(Ksuid::try_from(Value::Long(1)).is_err());
+/// ```
+impl TryFrom<Value> for Ksuid {
+    type Error = Error;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        value_bytes(value).map(Self::from_bytes)
+    }
+}
+
+/// Converts the ksuid to an Avro `fixed` of its 20 raw bytes, matching [`KSUID_AVRO_SCHEMA`]
+impl From<KsuidMs> for Value {
+    fn from(ksuid: KsuidMs) -> Self {
+        Value::Fixed(TOTAL_BYTES, ksuid.bytes().to_vec())
+    }
+}
+
+/// Loads a ksuid from an Avro `fixed` or `bytes` of its 20 raw bytes, or from a base62
+/// `string`, unwrapping unions (e.g. nullable fields) along the way
+impl TryFrom<Value> for KsuidMs {
+    type Error = Error;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        value_bytes(value).map(Self::from_bytes)
+    }
+}
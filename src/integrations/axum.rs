@@ -0,0 +1,107 @@
+use std::str::FromStr;
+use std::{error, fmt};
+
+use ::axum::extract::rejection::PathRejection;
+use ::axum::extract::{FromRequestParts, Path};
+use ::axum::http::header::CONTENT_TYPE;
+use ::axum::http::request::Parts;
+use ::axum::http::StatusCode;
+use ::axum::response::{IntoResponse, Response};
+
+use super::rejection::{KsuidRejection, PROBLEM_CONTENT_TYPE};
+use crate::{Error, Ksuid};
+
+impl IntoResponse for KsuidRejection {
+    fn into_response(self) -> Response {
+        (
+            StatusCode::BAD_REQUEST,
+            [(CONTENT_TYPE, PROBLEM_CONTENT_TYPE)],
+            self.to_problem_json(),
+        )
+            .into_response()
+    }
+}
+
+/// An axum extractor for a ksuid in the only dynamic segment of the path
+///
+/// Invalid ids are rejected with a [`KsuidRejection`], while routes that don't have exactly
+/// one dynamic segment keep axum's own `PathRejection`, which is a server error (see
+/// [`KsuidParamRejection`]). To respond differently, wrap the extractor in
+/// `axum_extra::extract::WithRejection` with your own error type implementing
+/// `From<KsuidParamRejection>`.
+///
+/// # Examples
+/// ```
+/// use axum::{routing::get, Router};
+/// use svix_ksuid::*;
+///
+/// async fn get_item(KsuidParam(id): KsuidParam) -> String {
+///     id.to_string()
+/// }
+///
+/// let app: Router = Router::new().route("/items/{id}", get(get_item));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct KsuidParam<K = Ksuid>(pub K);
+
+impl<K> KsuidParam<K> {
+    /// Get the extracted ksuid
+    pub fn into_inner(self) -> K {
+        self.0
+    }
+}
+
+/// The rejection of the [`KsuidParam`] extractor
+#[derive(Debug)]
+pub enum KsuidParamRejection {
+    /// The path couldn't be extracted, e.g. because the route doesn't have exactly one dynamic
+    /// segment, responding with the status axum uses for it
+    Path(PathRejection),
+    /// The path segment isn't a valid ksuid, responding with a `400 Bad Request`
+    Invalid(KsuidRejection),
+}
+
+impl fmt::Display for KsuidParamRejection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Path(rejection) => rejection.fmt(f),
+            Self::Invalid(rejection) => rejection.fmt(f),
+        }
+    }
+}
+
+impl error::Error for KsuidParamRejection {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::Path(rejection) => Some(rejection),
+            Self::Invalid(rejection) => Some(rejection),
+        }
+    }
+}
+
+impl IntoResponse for KsuidParamRejection {
+    fn into_response(self) -> Response {
+        match self {
+            Self::Path(rejection) => rejection.into_response(),
+            Self::Invalid(rejection) => rejection.into_response(),
+        }
+    }
+}
+
+impl<K, S> FromRequestParts<S> for KsuidParam<K>
+where
+    K: FromStr<Err = Error> + Send,
+    S: Send + Sync,
+{
+    type Rejection = KsuidParamRejection;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let Path(value) = Path::<String>::from_request_parts(parts, state)
+            .await
+            .map_err(KsuidParamRejection::Path)?;
+        value
+            .parse()
+            .map(Self)
+            .map_err(|e: Error| KsuidParamRejection::Invalid(KsuidRejection::new(e.to_string())))
+    }
+}
@@ -0,0 +1,16 @@
+//! Both ksuid types are `#[repr(transparent)]` wrappers of `[u8; 20]`, so they have no padding,
+//! an alignment of one and every bit pattern is a valid ksuid.
+
+use ::bytemuck::{Pod, Zeroable};
+
+use crate::{Ksuid, KsuidMs};
+
+// SAFETY: `Ksuid` is a `#[repr(transparent)]` `[u8; 20]`, and all zeros is the nil ksuid
+unsafe impl Zeroable for Ksuid {}
+// SAFETY: `Ksuid` is a `#[repr(transparent)]` `[u8; 20]`, which is `Pod`
+unsafe impl Pod for Ksuid {}
+
+// SAFETY: `KsuidMs` is a `#[repr(transparent)]` `[u8; 20]`, and all zeros is the nil ksuid
+unsafe impl Zeroable for KsuidMs {}
+// SAFETY: `KsuidMs` is a `#[repr(transparent)]` `[u8; 20]`, which is `Pod`
+unsafe impl Pod for KsuidMs {}
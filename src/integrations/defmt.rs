@@ -0,0 +1,17 @@
+use ::defmt::{Format, Formatter};
+
+use crate::{base62, Ksuid, KsuidLike, KsuidMs};
+
+/// Prints the base62 ksuid, encoded into a stack buffer, e.g. `defmt::info!("id={}", ksuid)`
+impl Format for Ksuid {
+    fn format(&self, f: Formatter<'_>) {
+        defmt::write!(f, "{=str}", base62::as_str(&base62::encode(self.bytes())));
+    }
+}
+
+/// Prints the base62 ksuid, encoded into a stack buffer, e.g. `defmt::info!("id={}", ksuid)`
+impl Format for KsuidMs {
+    fn format(&self, f: Formatter<'_>) {
+        defmt::write!(f, "{=str}", base62::as_str(&base62::encode(self.bytes())));
+    }
+}
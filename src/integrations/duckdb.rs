@@ -0,0 +1,70 @@
+//! Ksuids are bound as base62 `VARCHAR`s, and can be loaded from `VARCHAR` and 20 byte `BLOB`
+//! columns, e.g. from Parquet exports storing ksuids either way.
+
+use ::duckdb::types::{FromSql, FromSqlError, FromSqlResult, ToSql, ToSqlOutput, Value, ValueRef};
+
+use crate::{Ksuid, KsuidLike, KsuidMs, TOTAL_BYTES};
+
+fn value_bytes(value: ValueRef<'_>) -> FromSqlResult<[u8; TOTAL_BYTES]> {
+    match value {
+        ValueRef::Text(text) => std::str::from_utf8(text)
+            .map_err(|e| FromSqlError::Other(Box::new(e)))
+            .and_then(|s| Ksuid::from_base62(s).map_err(|e| FromSqlError::Other(Box::new(e))))
+            .map(Ksuid::into_bytes),
+        ValueRef::Blob(bytes) => bytes.try_into().map_err(|_| {
+            FromSqlError::Other(format!("Got ksuid of unexpected length {}", bytes.len()).into())
+        }),
+        _ => Err(FromSqlError::InvalidType),
+    }
+}
+
+/// Binds the ksuid as a base62 `VARCHAR`
+///
+/// # Examples
+/// ```
+/// use duckdb::Connection;
+/// use svix_ksuid::*;
+///
+/// let conn = Connection::open_in_memory().unwrap();
+/// let ksuid = Ksuid::new(None, None);
+/// let s: String = conn.query_row("SELECT ?", [ksuid], |row| row.get(0)).unwrap();
+/// assert_eq!(s, ksuid.to_string());
+/// ```
+impl ToSql for Ksuid {
+    fn to_sql(&self) -> duckdb::Result<ToSqlOutput<'_>> {
+        Ok(ToSqlOutput::Owned(Value::Text(self.to_base62())))
+    }
+}
+
+/// Loads a ksuid from a base62 `VARCHAR` or a 20 byte `BLOB`
+///
+/// # Examples
+/// ```
+/// use duckdb::Connection;
+/// use svix_ksuid::*;
+///
+/// let conn = Connection::open_in_memory().unwrap();
+/// let ksuid: Ksuid = conn
+///     .query_row("SELECT '1srOrx2ZWZBpBUvZwXKQmoEYga2'", [], |row| row.get(0))
+///     .unwrap();
+/// assert_eq!(ksuid.to_string(), "1srOrx2ZWZBpBUvZwXKQmoEYga2");
+/// ```
+impl FromSql for Ksuid {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        value_bytes(value).map(Self::from_bytes)
+    }
+}
+
+/// Binds the ksuid as a base62 `VARCHAR`
+impl ToSql for KsuidMs {
+    fn to_sql(&self) -> duckdb::Result<ToSqlOutput<'_>> {
+        Ok(ToSqlOutput::Owned(Value::Text(self.to_base62())))
+    }
+}
+
+/// Loads a ksuid from a base62 `VARCHAR` or a 20 byte `BLOB`
+impl FromSql for KsuidMs {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        value_bytes(value).map(Self::from_bytes)
+    }
+}
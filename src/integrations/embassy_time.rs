@@ -0,0 +1,68 @@
+//! Embassy only has a monotonic tick counter, so the wall-clock time is the time of a known
+//! reference point (e.g. boot, or the last RTC or NTP sync) plus the ticks since.
+
+use std::time::{Duration, SystemTime};
+
+use ::embassy_time::Instant;
+
+use crate::Clock;
+
+/// A [`Clock`] on top of the `embassy_time` tick counter, for generating ksuids in firmware
+///
+/// The clock is anchored to a wall-clock time at a known [`Instant`], and adds the time elapsed
+/// on the tick counter since. Ksuids need the time since the unix epoch, so an anchor has to
+/// come from an RTC, NTP, GPS or similar at least once.
+///
+/// # Examples
+/// ```
+/// use svix_ksuid::*;
+/// use std::time::{Duration, UNIX_EPOCH};
+///
+/// // E.g. read from an RTC during boot
+/// let boot_time = UNIX_EPOCH + Duration::from_secs(1_621_627_443);
+/// let mut generator = Generator::new().with_clock(EmbassyClock::new(boot_time));
+/// let ksuid: Ksuid = generator.generate();
+/// assert!(ksuid.timestamp_seconds() >= 1_621_627_443);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EmbassyClock {
+    anchor: SystemTime,
+    at: Instant,
+}
+
+impl EmbassyClock {
+    /// Create a clock from the wall-clock time at boot, when the tick counter was zero
+    pub fn new(boot_time: SystemTime) -> Self {
+        Self::anchored(boot_time, Instant::from_ticks(0))
+    }
+
+    /// Create a clock from the wall-clock time `time` at the instant `at`
+    ///
+    /// Use this when the wall-clock time only becomes known after boot, e.g. from a network
+    /// sync. Anchoring again later corrects the drift of the tick counter.
+    ///
+    /// # Examples
+    /// ```
+    /// use embassy_time::Instant;
+    /// use svix_ksuid::*;
+    /// use std::time::{Duration, UNIX_EPOCH};
+    ///
+    /// let synced = UNIX_EPOCH + Duration::from_secs(1_621_627_443);
+    /// let clock = EmbassyClock::anchored(synced, Instant::now());
+    /// assert!(clock.now() >= synced);
+    /// ```
+    pub fn anchored(time: SystemTime, at: Instant) -> Self {
+        Self { anchor: time, at }
+    }
+}
+
+impl Clock for EmbassyClock {
+    fn now(&self) -> SystemTime {
+        let now = Instant::now();
+        match now.checked_duration_since(self.at) {
+            Some(elapsed) => self.anchor + Duration::from(elapsed),
+            // Anchored at an instant that hasn't happened yet
+            None => self.anchor - Duration::from(self.at.duration_since(now)),
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use std::io;
+
+use ::foundationdb_tuple::{Bytes, PackError, PackResult, TupleDepth};
+use ::foundationdb_tuple::{TuplePack, TupleUnpack, VersionstampOffset};
+
+use crate::{Ksuid, KsuidLike, KsuidMs, TOTAL_BYTES};
+
+// Ksuids are packed as byte string elements of their raw bytes. The byte string encoding
+// preserves the lexicographic order of the bytes, so packed ksuids sort the same as ksuids.
+
+fn pack<W: io::Write>(
+    bytes: &[u8; TOTAL_BYTES],
+    w: &mut W,
+    tuple_depth: TupleDepth,
+) -> io::Result<VersionstampOffset> {
+    Bytes::from(&bytes[..]).pack(w, tuple_depth)
+}
+
+fn unpack(input: &[u8], tuple_depth: TupleDepth) -> PackResult<(&[u8], [u8; TOTAL_BYTES])> {
+    let (input, bytes) = Bytes::unpack(input, tuple_depth)?;
+    let bytes = bytes[..].try_into().map_err(|_| {
+        PackError::Message(format!("Got ksuid of unexpected length {}", bytes.len()).into())
+    })?;
+    Ok((input, bytes))
+}
+
+/// Packs the ksuid as a byte string, preserving the order of ksuids within tuples
+///
+/// # Examples
+/// ```
+/// use svix_ksuid::{Ksuid, KsuidLike};
+///
+/// let ksuid = Ksuid::from_seconds(None, None);
+/// let packed = foundationdb_tuple::pack(&("items", ksuid));
+/// let unpacked: (String, Ksuid) = foundationdb_tuple::unpack(&packed).unwrap();
+/// assert_eq!(unpacked.1, ksuid);
+/// ```
+impl TuplePack for Ksuid {
+    fn pack<W: io::Write>(
+        &self,
+        w: &mut W,
+        tuple_depth: TupleDepth,
+    ) -> io::Result<VersionstampOffset> {
+        pack(self.bytes(), w, tuple_depth)
+    }
+}
+
+impl TupleUnpack<'_> for Ksuid {
+    fn unpack(input: &[u8], tuple_depth: TupleDepth) -> PackResult<(&[u8], Self)> {
+        let (input, bytes) = unpack(input, tuple_depth)?;
+        Ok((input, Self::from_bytes(bytes)))
+    }
+}
+
+/// Packs the ksuid as a byte string, preserving the order of ksuids within tuples
+impl TuplePack for KsuidMs {
+    fn pack<W: io::Write>(
+        &self,
+        w: &mut W,
+        tuple_depth: TupleDepth,
+    ) -> io::Result<VersionstampOffset> {
+        pack(self.bytes(), w, tuple_depth)
+    }
+}
+
+impl TupleUnpack<'_> for KsuidMs {
+    fn unpack(input: &[u8], tuple_depth: TupleDepth) -> PackResult<(&[u8], Self)> {
+        let (input, bytes) = unpack(input, tuple_depth)?;
+        Ok((input, Self::from_bytes(bytes)))
+    }
+}
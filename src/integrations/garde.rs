@@ -0,0 +1,44 @@
+use ::garde::error::{Error, Path, Report};
+use ::garde::Validate;
+
+use crate::{is_valid_ksuid, Ksuid, KsuidMs};
+
+/// A `garde` rule validating that a string is a base62 ksuid
+///
+/// # Examples
+/// ```
+/// use svix_ksuid::*;
+/// use garde::Validate;
+///
+/// #[derive(Validate)]
+/// struct Request {
+///     #[garde(custom(garde_ksuid))]
+///     id: String,
+/// }
+///
+/// let request = Request { id: "1srOrx2ZWZBpBUvZwXKQmoEYga2".to_owned() };
+/// assert!(request.validate().is_ok());
+///
+/// let request = Request { id: "not-a-ksuid".to_owned() };
+/// assert!(request.validate().is_err());
+/// ```
+pub fn garde_ksuid<T: AsRef<str> + ?Sized, C>(value: &T, _ctx: &C) -> ::garde::Result {
+    if is_valid_ksuid(value.as_ref()) {
+        Ok(())
+    } else {
+        Err(Error::new("invalid ksuid"))
+    }
+}
+
+// Ksuids are always valid once parsed, this allows using `#[garde(dive)]` on them.
+impl Validate for Ksuid {
+    type Context = ();
+
+    fn validate_into(&self, _: &(), _: &mut dyn FnMut() -> Path, _: &mut Report) {}
+}
+
+impl Validate for KsuidMs {
+    type Context = ();
+
+    fn validate_into(&self, _: &(), _: &mut dyn FnMut() -> Path, _: &mut Report) {}
+}
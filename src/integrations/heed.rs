@@ -0,0 +1,71 @@
+//! Ksuids are stored as their 20 raw bytes, so LMDB's default lexicographic key comparison
+//! orders them the same as the ksuids, i.e. by creation time.
+//!
+//! `Ksuid` and `KsuidMs` are their own codecs, e.g. `Database<Ksuid, Str>`.
+
+use std::borrow::Cow;
+
+use ::heed_traits::{BoxedError, BytesDecode, BytesEncode};
+
+use crate::{Error, Ksuid, KsuidLike, KsuidMs, TOTAL_BYTES};
+
+fn decode_bytes(bytes: &[u8]) -> Result<[u8; TOTAL_BYTES], BoxedError> {
+    bytes
+        .try_into()
+        .map_err(|_| Error::new(format!("Got ksuid of unexpected length {}", bytes.len())).into())
+}
+
+/// Encodes the ksuid as its 20 raw bytes, without copying
+///
+/// # Examples
+/// ```
+/// use heed_traits::BytesEncode;
+/// use svix_ksuid::*;
+///
+/// let ksuid = Ksuid::new(None, None);
+/// assert_eq!(Ksuid::bytes_encode(&ksuid).unwrap(), &ksuid.bytes()[..]);
+/// ```
+impl<'a> BytesEncode<'a> for Ksuid {
+    type EItem = Ksuid;
+
+    fn bytes_encode(item: &'a Self::EItem) -> Result<Cow<'a, [u8]>, BoxedError> {
+        Ok(Cow::Borrowed(item.bytes()))
+    }
+}
+
+/// Decodes the ksuid from its 20 raw bytes
+///
+/// # Examples
+/// ```
+/// use heed_traits::BytesDecode;
+/// use svix_ksuid::*;
+///
+/// let ksuid = Ksuid::new(None, None);
+/// assert_eq!(Ksuid::bytes_decode(ksuid.bytes()).unwrap(), ksuid);
+/// assert!(Ksuid::bytes_decode(&ksuid.bytes()[1..]).is_err());
+/// ```
+impl<'a> BytesDecode<'a> for Ksuid {
+    type DItem = Ksuid;
+
+    fn bytes_decode(bytes: &'a [u8]) -> Result<Self::DItem, BoxedError> {
+        decode_bytes(bytes).map(Self::from_bytes)
+    }
+}
+
+/// Encodes the ksuid as its 20 raw bytes, without copying
+impl<'a> BytesEncode<'a> for KsuidMs {
+    type EItem = KsuidMs;
+
+    fn bytes_encode(item: &'a Self::EItem) -> Result<Cow<'a, [u8]>, BoxedError> {
+        Ok(Cow::Borrowed(item.bytes()))
+    }
+}
+
+/// Decodes the ksuid from its 20 raw bytes
+impl<'a> BytesDecode<'a> for KsuidMs {
+    type DItem = KsuidMs;
+
+    fn bytes_decode(bytes: &'a [u8]) -> Result<Self::DItem, BoxedError> {
+        decode_bytes(bytes).map(Self::from_bytes)
+    }
+}
@@ -0,0 +1,24 @@
+//! `hex::ToHex` is implemented for all `AsRef<[u8]>` types, so only `FromHex` is needed here.
+//!
+//! Note that `KsuidLike::from_hex` has the same name, so call this one as
+//! `<Ksuid as FromHex>::from_hex` when both traits are in scope.
+
+use ::hex::{FromHex, FromHexError};
+
+use crate::{Ksuid, KsuidLike, KsuidMs, TOTAL_BYTES};
+
+impl FromHex for Ksuid {
+    type Error = FromHexError;
+
+    fn from_hex<T: AsRef<[u8]>>(hex: T) -> Result<Self, Self::Error> {
+        <[u8; TOTAL_BYTES]>::from_hex(hex).map(Self::from_bytes)
+    }
+}
+
+impl FromHex for KsuidMs {
+    type Error = FromHexError;
+
+    fn from_hex<T: AsRef<[u8]>>(hex: T) -> Result<Self, Self::Error> {
+        <[u8; TOTAL_BYTES]>::from_hex(hex).map(Self::from_bytes)
+    }
+}
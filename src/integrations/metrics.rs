@@ -0,0 +1,25 @@
+use std::time::Instant;
+
+use crate::{KsuidLike, TimestampPrecision};
+
+/// Counter of the ksuids generated by a [`Generator`](crate::Generator)
+pub const KSUID_GENERATED_METRIC: &str = "ksuid_generated_total";
+/// Histogram of the time it took a [`Generator`](crate::Generator) to generate a ksuid, in
+/// seconds
+pub const KSUID_GENERATION_DURATION_METRIC: &str = "ksuid_generation_duration_seconds";
+
+/// The `variant` label of the metrics
+fn variant<K: KsuidLike>() -> &'static str {
+    match K::PRECISION {
+        TimestampPrecision::Seconds => "ksuid",
+        TimestampPrecision::FourMillis => "ksuid_ms",
+    }
+}
+
+/// Record a ksuid generated since `start`
+pub(crate) fn record_generated<K: KsuidLike>(start: Instant) {
+    let variant = variant::<K>();
+    ::metrics::counter!(KSUID_GENERATED_METRIC, "variant" => variant).increment(1);
+    ::metrics::histogram!(KSUID_GENERATION_DURATION_METRIC, "variant" => variant)
+        .record(start.elapsed());
+}
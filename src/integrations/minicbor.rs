@@ -0,0 +1,88 @@
+//! Ksuids are encoded as an untagged 20 byte CBOR byte string, see `to_cbor` for the tagged
+//! encoding instead.
+
+use ::minicbor::decode::{self, Decoder};
+use ::minicbor::encode::{self, CborLen, Encoder, Write};
+use ::minicbor::{Decode, Encode};
+
+use crate::{Ksuid, KsuidLike, KsuidMs, TOTAL_BYTES};
+
+/// A 1 byte header and the bytes
+const ENCODED_LEN: usize = 1 + TOTAL_BYTES;
+
+fn decode_bytes(d: &mut Decoder<'_>) -> Result<[u8; TOTAL_BYTES], decode::Error> {
+    let position = d.position();
+    d.bytes()?
+        .try_into()
+        .map_err(|_| decode::Error::message("expected a 20 byte ksuid").at(position))
+}
+
+/// Encodes the ksuid as a 20 byte CBOR byte string
+///
+/// # Examples
+/// ```
+/// use svix_ksuid::*;
+///
+/// let ksuid = Ksuid::new(None, None);
+/// let mut buf = [0u8; 21];
+/// minicbor::encode(ksuid, buf.as_mut_slice()).unwrap();
+/// assert_eq!(buf[0], 0x54);
+/// assert_eq!(buf[1..], ksuid.bytes()[..]);
+/// ```
+impl<C> Encode<C> for Ksuid {
+    fn encode<W: Write>(
+        &self,
+        e: &mut Encoder<W>,
+        _ctx: &mut C,
+    ) -> Result<(), encode::Error<W::Error>> {
+        e.bytes(self.bytes())?.ok()
+    }
+}
+
+impl<C> CborLen<C> for Ksuid {
+    fn cbor_len(&self, _ctx: &mut C) -> usize {
+        ENCODED_LEN
+    }
+}
+
+/// Decodes the ksuid from a 20 byte CBOR byte string
+///
+/// # Examples
+/// ```
+/// use svix_ksuid::*;
+///
+/// let ksuid = Ksuid::new(None, None);
+/// let mut buf = [0u8; 21];
+/// minicbor::encode(ksuid, buf.as_mut_slice()).unwrap();
+/// assert_eq!(minicbor::decode::<Ksuid>(&buf).unwrap(), ksuid);
+/// assert!(minicbor::decode::<Ksuid>(&buf[..20]).is_err());
+/// ```
+impl<'b, C> Decode<'b, C> for Ksuid {
+    fn decode(d: &mut Decoder<'b>, _ctx: &mut C) -> Result<Self, decode::Error> {
+        decode_bytes(d).map(Self::from_bytes)
+    }
+}
+
+/// Encodes the ksuid as a 20 byte CBOR byte string
+impl<C> Encode<C> for KsuidMs {
+    fn encode<W: Write>(
+        &self,
+        e: &mut Encoder<W>,
+        _ctx: &mut C,
+    ) -> Result<(), encode::Error<W::Error>> {
+        e.bytes(self.bytes())?.ok()
+    }
+}
+
+impl<C> CborLen<C> for KsuidMs {
+    fn cbor_len(&self, _ctx: &mut C) -> usize {
+        ENCODED_LEN
+    }
+}
+
+/// Decodes the ksuid from a 20 byte CBOR byte string
+impl<'b, C> Decode<'b, C> for KsuidMs {
+    fn decode(d: &mut Decoder<'b>, _ctx: &mut C) -> Result<Self, decode::Error> {
+        decode_bytes(d).map(Self::from_bytes)
+    }
+}
@@ -0,0 +1,62 @@
+//! Integrations with third-party crates, each behind its own feature
+
+#[cfg(feature = "actix-web")]
+pub(crate) mod actix_web;
+#[cfg(feature = "avro")]
+pub(crate) mod avro;
+#[cfg(feature = "axum")]
+pub(crate) mod axum;
+#[cfg(feature = "bytemuck")]
+mod bytemuck;
+#[cfg(feature = "defmt")]
+mod defmt;
+#[cfg(feature = "duckdb")]
+mod duckdb;
+#[cfg(feature = "embassy-time")]
+pub(crate) mod embassy_time;
+#[cfg(feature = "foundationdb-tuple")]
+mod foundationdb_tuple;
+#[cfg(feature = "garde")]
+pub(crate) mod garde;
+#[cfg(feature = "heed")]
+mod heed;
+#[cfg(feature = "hex")]
+mod hex;
+#[cfg(feature = "metrics")]
+pub(crate) mod metrics;
+#[cfg(feature = "minicbor")]
+mod minicbor;
+#[cfg(feature = "neo4rs")]
+mod neo4rs;
+#[cfg(feature = "nohash-hasher")]
+pub(crate) mod nohash_hasher;
+#[cfg(feature = "opentelemetry")]
+mod opentelemetry;
+#[cfg(feature = "poem-openapi")]
+mod poem_openapi;
+#[cfg(all(feature = "rayon", feature = "getrandom"))]
+pub(crate) mod rayon;
+#[cfg(any(feature = "actix-web", feature = "axum", feature = "salvo"))]
+pub(crate) mod rejection;
+#[cfg(feature = "rmpv")]
+pub(crate) mod rmpv;
+#[cfg(feature = "rocket")]
+mod rocket;
+#[cfg(feature = "salvo")]
+mod salvo;
+#[cfg(feature = "subtle")]
+mod subtle;
+#[cfg(feature = "surrealdb")]
+mod surrealdb;
+#[cfg(feature = "tiberius")]
+mod tiberius;
+#[cfg(feature = "tokio")]
+pub(crate) mod tokio;
+#[cfg(all(feature = "tower", feature = "getrandom"))]
+pub(crate) mod tower;
+#[cfg(feature = "tracing")]
+pub(crate) mod tracing;
+#[cfg(feature = "validator")]
+pub(crate) mod validator;
+#[cfg(feature = "zeroize")]
+mod zeroize;
@@ -0,0 +1,69 @@
+//! Ksuids are stored in Neo4j as base62 strings. Reading them from a `Row`, `Node` or
+//! `Relation` goes through their serde implementation, e.g. `row.get::<Ksuid>("id")`.
+
+use ::neo4rs::{BoltString, BoltType};
+
+use crate::{Error, Ksuid, KsuidLike, KsuidMs};
+
+fn bolt_str(value: &BoltType) -> Result<&str, Error> {
+    match value {
+        BoltType::String(s) => Ok(&s.value),
+        other => Err(Error::new(format!(
+            "Expected a Bolt string ksuid, got {:?}",
+            other
+        ))),
+    }
+}
+
+/// Converts the ksuid to a base62 Bolt string, e.g. for query parameters
+///
+/// # Examples
+/// ```
+/// use neo4rs::{query, BoltType};
+/// use svix_ksuid::*;
+///
+/// let ksuid = Ksuid::new(None, None);
+/// assert_eq!(BoltType::from(ksuid), BoltType::from(ksuid.to_string()));
+/// let q = query("MATCH (u:User {id: $id}) RETURN u").param("id", ksuid);
+/// assert!(q.has_param_key("id"));
+/// ```
+impl From<Ksuid> for BoltType {
+    fn from(ksuid: Ksuid) -> Self {
+        BoltType::String(BoltString::from(ksuid.to_base62()))
+    }
+}
+
+/// Loads a ksuid from a base62 Bolt string
+///
+/// # Examples
+/// ```
+/// use neo4rs::BoltType;
+/// use svix_ksuid::*;
+///
+/// let ksuid = Ksuid::new(None, None);
+/// assert_eq!(Ksuid::try_from(BoltType::from(ksuid)).unwrap(), ksuid);
+/// assert!(Ksuid::try_from(BoltType::from(1)).is_err());
+/// ```
+impl TryFrom<BoltType> for Ksuid {
+    type Error = Error;
+
+    fn try_from(value: BoltType) -> Result<Self, Self::Error> {
+        bolt_str(&value).and_then(Self::from_base62)
+    }
+}
+
+/// Converts the ksuid to a base62 Bolt string, e.g. for query parameters
+impl From<KsuidMs> for BoltType {
+    fn from(ksuid: KsuidMs) -> Self {
+        BoltType::String(BoltString::from(ksuid.to_base62()))
+    }
+}
+
+/// Loads a ksuid from a base62 Bolt string
+impl TryFrom<BoltType> for KsuidMs {
+    type Error = Error;
+
+    fn try_from(value: BoltType) -> Result<Self, Self::Error> {
+        bolt_str(&value).and_then(Self::from_base62)
+    }
+}
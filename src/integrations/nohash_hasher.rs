@@ -0,0 +1,42 @@
+use std::hash::{Hash, Hasher};
+
+use ::nohash_hasher::IsEnabled;
+
+use crate::{Ksuid, KsuidLike, TOTAL_BYTES};
+
+/// A ksuid that hashes as a single `u64`, for use with `nohash_hasher::NoHashHasher`
+///
+/// The `u64` is the XOR of the two halves of the last 16 bytes, which are random for ksuids
+/// with a random payload, so there's no need to hash them again. Don't use this for ksuids
+/// with predictable payloads, or for maps with keys chosen by untrusted users.
+///
+/// # Examples
+/// ```
+/// use nohash_hasher::IntMap;
+/// use svix_ksuid::*;
+///
+/// let ksuid = Ksuid::new(None, None);
+/// let mut map: IntMap<NoHashKey, &str> = IntMap::default();
+/// map.insert(NoHashKey(ksuid), "value");
+/// assert_eq!(map.get(&NoHashKey(ksuid)), Some(&"value"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct NoHashKey<K = Ksuid>(pub K);
+
+impl<K: KsuidLike> NoHashKey<K> {
+    /// The `u64` this key hashes as
+    pub fn key(&self) -> u64 {
+        let bytes = self.0.bytes();
+        let high = u64::from_be_bytes(bytes[TOTAL_BYTES - 16..TOTAL_BYTES - 8].try_into().unwrap());
+        let low = u64::from_be_bytes(bytes[TOTAL_BYTES - 8..].try_into().unwrap());
+        high ^ low
+    }
+}
+
+impl<K: KsuidLike> Hash for NoHashKey<K> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u64(self.key());
+    }
+}
+
+impl<K: KsuidLike> IsEnabled for NoHashKey<K> {}
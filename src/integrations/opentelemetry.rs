@@ -0,0 +1,101 @@
+use ::opentelemetry::{SpanId, TraceId};
+
+use crate::{
+    from_millis_and_payload, now_millis, Ksuid, KsuidLike, KsuidMs, TimestampOverflow, TOTAL_BYTES,
+};
+
+const TRACE_ID_BYTES: usize = 16;
+const SPAN_ID_BYTES: usize = 8;
+
+fn trace_id(bytes: &[u8; TOTAL_BYTES]) -> TraceId {
+    TraceId::from_bytes(bytes[TOTAL_BYTES - TRACE_ID_BYTES..].try_into().unwrap())
+}
+
+fn span_id(bytes: &[u8; TOTAL_BYTES]) -> SpanId {
+    SpanId::from_bytes(bytes[TOTAL_BYTES - SPAN_ID_BYTES..].try_into().unwrap())
+}
+
+impl Ksuid {
+    /// Get the OpenTelemetry trace id for this ksuid, which is its payload
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    ///
+    /// let ksuid = Ksuid::new(None, None);
+    /// assert_eq!(&ksuid.trace_id().to_bytes()[..], ksuid.payload());
+    /// ```
+    pub fn trace_id(&self) -> TraceId {
+        trace_id(self.bytes())
+    }
+
+    /// Get the OpenTelemetry span id for this ksuid, which is the last 8 bytes of its payload
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    ///
+    /// let ksuid = Ksuid::new(None, None);
+    /// assert_eq!(&ksuid.span_id().to_bytes()[..], &ksuid.payload()[8..]);
+    /// ```
+    pub fn span_id(&self) -> SpanId {
+        span_id(self.bytes())
+    }
+
+    /// Creates new Ksuid with specified timestamp (in seconds), using an OpenTelemetry trace
+    /// id as the payload
+    ///
+    /// This is the inverse of [`Ksuid::trace_id`].
+    ///
+    /// # Examples
+    /// ```
+    /// use opentelemetry::TraceId;
+    /// use svix_ksuid::*;
+    ///
+    /// let trace_id = TraceId::from_bytes([7; 16]);
+    /// let ksuid = Ksuid::from_trace_id(None, trace_id);
+    /// assert_eq!(ksuid.trace_id(), trace_id);
+    /// ```
+    pub fn from_trace_id(timestamp: Option<i64>, trace_id: TraceId) -> Self {
+        let millis = timestamp.map_or_else(now_millis, |seconds| seconds.saturating_mul(1_000));
+        from_millis_and_payload::<Self>(millis, &trace_id.to_bytes(), TimestampOverflow::Wrap)
+            .unwrap()
+    }
+}
+
+impl KsuidMs {
+    /// Get the OpenTelemetry trace id for this ksuid
+    ///
+    /// This is the last 16 bytes of the ksuid, i.e. the payload and the sub-second part of the
+    /// timestamp, the same bytes that are used for a [`Ksuid`].
+    pub fn trace_id(&self) -> TraceId {
+        trace_id(self.bytes())
+    }
+
+    /// Get the OpenTelemetry span id for this ksuid, which is the last 8 bytes of its payload
+    pub fn span_id(&self) -> SpanId {
+        span_id(self.bytes())
+    }
+
+    /// Creates new KsuidMs with specified timestamp (in seconds), using an OpenTelemetry trace
+    /// id as the sub-second part of the timestamp and the payload
+    ///
+    /// This is the inverse of [`KsuidMs::trace_id`], and has the same bytes as
+    /// [`Ksuid::from_trace_id`]. The first byte of the trace id becomes the sub-second part of
+    /// the timestamp, in units of 4 milliseconds, so the timestamp of the ksuid is up to a
+    /// second after `timestamp`, depending on the trace id.
+    ///
+    /// # Examples
+    /// ```
+    /// use opentelemetry::TraceId;
+    /// use svix_ksuid::*;
+    ///
+    /// let trace_id = TraceId::from_bytes([7; 16]);
+    /// let ksuid = KsuidMs::from_trace_id(Some(1_621_627_443), trace_id);
+    /// assert_eq!(ksuid.trace_id(), trace_id);
+    /// assert_eq!(ksuid.timestamp_millis(), 1_621_627_443_028);
+    /// ```
+    pub fn from_trace_id(timestamp: Option<i64>, trace_id: TraceId) -> Self {
+        Self::from_bytes(*Ksuid::from_trace_id(timestamp, trace_id).bytes())
+    }
+}
@@ -0,0 +1,73 @@
+use std::borrow::Cow;
+
+use ::poem_openapi::registry::{MetaSchema, MetaSchemaRef};
+use ::poem_openapi::types::{
+    ParseError, ParseFromJSON, ParseFromParameter, ParseResult, ToJSON, Type,
+};
+use serde_json::Value;
+
+use crate::{Ksuid, KsuidMs, KSUID_PATTERN, TOTAL_BYTES_BASE62};
+
+fn schema() -> MetaSchemaRef {
+    MetaSchemaRef::Inline(Box::new(MetaSchema {
+        min_length: Some(TOTAL_BYTES_BASE62),
+        max_length: Some(TOTAL_BYTES_BASE62),
+        pattern: Some(KSUID_PATTERN.to_owned()),
+        ..MetaSchema::new_with_format("string", "ksuid")
+    }))
+}
+
+// Both types are represented the same way, as base62 strings
+macro_rules! impl_poem_openapi {
+    ($ty:ty, $name:literal) => {
+        impl Type for $ty {
+            const IS_REQUIRED: bool = true;
+
+            type RawValueType = Self;
+
+            type RawElementValueType = Self;
+
+            fn name() -> Cow<'static, str> {
+                $name.into()
+            }
+
+            fn schema_ref() -> MetaSchemaRef {
+                schema()
+            }
+
+            fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+                Some(self)
+            }
+
+            fn raw_element_iter<'a>(
+                &'a self,
+            ) -> Box<dyn Iterator<Item = &'a Self::RawElementValueType> + 'a> {
+                Box::new(self.as_raw_value().into_iter())
+            }
+        }
+
+        impl ParseFromJSON for $ty {
+            fn parse_from_json(value: Option<Value>) -> ParseResult<Self> {
+                match value.unwrap_or_default() {
+                    Value::String(value) => Ok(value.parse()?),
+                    value => Err(ParseError::expected_type(value)),
+                }
+            }
+        }
+
+        impl ParseFromParameter for $ty {
+            fn parse_from_parameter(value: &str) -> ParseResult<Self> {
+                Ok(value.parse()?)
+            }
+        }
+
+        impl ToJSON for $ty {
+            fn to_json(&self) -> Option<Value> {
+                Some(Value::String(self.to_string()))
+            }
+        }
+    };
+}
+
+impl_poem_openapi!(Ksuid, "string_ksuid");
+impl_poem_openapi!(KsuidMs, "string_ksuid_ms");
@@ -0,0 +1,42 @@
+use std::time::SystemTime;
+
+use ::rayon::prelude::*;
+
+use crate::{entropy, KsuidLike};
+
+/// The number of ksuids generated from each batch of entropy
+const CHUNK_LEN: usize = 4096;
+
+/// Generate `n` ksuids in parallel on the rayon thread pool
+///
+/// The ksuids are generated in chunks, each of which fills one reused per-thread buffer from
+/// the global entropy source and reads the clock once. The ksuids are returned in chunk order,
+/// which is not necessarily the order of their timestamps, so sort them with
+/// [`slice::sort`](crate::slice::sort) if needed.
+///
+/// Panics if the entropy source fails, like [`KsuidLike::new`].
+///
+/// # Examples
+/// ```
+/// use svix_ksuid::*;
+///
+/// let ids: Vec<Ksuid> = generate_many_parallel(10_000);
+/// assert_eq!(ids.len(), 10_000);
+/// assert_eq!(ids.into_iter().collect::<KsuidSet<_>>().len(), 10_000);
+/// ```
+pub fn generate_many_parallel<K: KsuidLike<Type = K> + Send>(n: usize) -> Vec<K> {
+    (0..n.div_ceil(CHUNK_LEN))
+        .into_par_iter()
+        .map_init(Vec::new, |payloads: &mut Vec<u8>, chunk| {
+            let len = CHUNK_LEN.min(n - chunk * CHUNK_LEN);
+            payloads.resize(len * K::PAYLOAD_BYTES, 0);
+            entropy::fill_random(payloads);
+            let now = SystemTime::now();
+            payloads
+                .chunks_exact(K::PAYLOAD_BYTES)
+                .map(|payload| K::from_system_time(now, Some(payload)))
+                .collect::<Vec<_>>()
+        })
+        .flatten_iter()
+        .collect()
+}
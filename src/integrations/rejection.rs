@@ -0,0 +1,82 @@
+use std::{error, fmt};
+
+pub(crate) const PROBLEM_CONTENT_TYPE: &str = "application/problem+json";
+
+/// The error returned by the web framework extractors when a ksuid is missing or invalid
+///
+/// It's rendered as a `400 Bad Request` with an RFC 9457 problem details body, so that they're
+/// consistent across all of the supported frameworks.
+///
+/// # Examples
+/// ```
+/// use svix_ksuid::*;
+///
+/// let rejection = KsuidRejection::new("Got ksuid of unexpected length 3");
+/// assert_eq!(rejection.status(), 400);
+/// assert_eq!(
+///     rejection.to_problem_json(),
+///     r#"{"type":"about:blank","title":"Invalid ksuid","status":400,"detail":"Got ksuid of unexpected length 3"}"#
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KsuidRejection {
+    detail: String,
+}
+
+impl KsuidRejection {
+    /// Create a new rejection with a human readable explanation of what went wrong
+    pub fn new<D: Into<String>>(detail: D) -> Self {
+        Self {
+            detail: detail.into(),
+        }
+    }
+
+    /// The human readable explanation of what went wrong
+    pub fn detail(&self) -> &str {
+        &self.detail
+    }
+
+    /// The HTTP status code of the response
+    pub fn status(&self) -> u16 {
+        400
+    }
+
+    /// The problem details (`application/problem+json`) body of the response
+    pub fn to_problem_json(&self) -> String {
+        problem_json("Invalid ksuid", self.status(), &self.detail)
+    }
+}
+
+impl fmt::Display for KsuidRejection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Invalid ksuid: {}", self.detail)
+    }
+}
+
+impl error::Error for KsuidRejection {}
+
+/// A problem details (`application/problem+json`) body with the given title, status and detail
+pub(crate) fn problem_json(title: &str, status: u16, detail: &str) -> String {
+    format!(
+        r#"{{"type":"about:blank","title":"{}","status":{},"detail":"{}"}}"#,
+        escape_json(title),
+        status,
+        escape_json(detail)
+    )
+}
+
+fn escape_json(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out
+}
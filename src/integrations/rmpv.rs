@@ -0,0 +1,130 @@
+use ::rmpv::{Value, ValueRef};
+
+use crate::{Error, Ksuid, KsuidLike, KsuidMs, TOTAL_BYTES};
+
+/// The MessagePack extension type used for ksuids
+///
+/// Ksuids are encoded as this extension type with their 20 raw bytes as the body, so that
+/// consumers in other languages can tell them apart from other binary data. `Ksuid` and
+/// `KsuidMs` share the same type, as they have the same layout.
+///
+/// # Examples
+/// ```
+/// use svix_ksuid::*;
+///
+/// let ksuid = Ksuid::new(None, None);
+/// let mut buf = Vec::new();
+/// rmpv::encode::write_value(&mut buf, &ksuid.into()).unwrap();
+/// // ext 8, the body length, the type and then the bytes
+/// assert_eq!(buf[..3], [0xc7, 20, KSUID_MSGPACK_EXT_TYPE as u8]);
+/// assert_eq!(buf[3..], ksuid.bytes()[..]);
+/// ```
+pub const KSUID_MSGPACK_EXT_TYPE: i8 = 75;
+
+fn ext_bytes(ty: i8, data: &[u8]) -> Result<[u8; TOTAL_BYTES], Error> {
+    if ty != KSUID_MSGPACK_EXT_TYPE {
+        return Err(Error::new(format!(
+            "Expected MessagePack ext type {}, got {}",
+            KSUID_MSGPACK_EXT_TYPE, ty
+        )));
+    }
+    data.try_into()
+        .map_err(|_| Error::new(format!("Got ksuid of unexpected length {}", data.len())))
+}
+
+fn value_bytes(value: ValueRef<'_>) -> Result<[u8; TOTAL_BYTES], Error> {
+    match value {
+        ValueRef::Ext(ty, data) => ext_bytes(ty, data),
+        other => Err(Error::new(format!(
+            "Expected a MessagePack ext ksuid, got {}",
+            other
+        ))),
+    }
+}
+
+/// Converts the ksuid to a MessagePack ext value of type [`KSUID_MSGPACK_EXT_TYPE`]
+///
+/// # Examples
+/// ```
+/// use rmpv::Value;
+/// use svix_ksuid::*;
+///
+/// let ksuid = Ksuid::new(None, None);
+/// assert_eq!(
+///     Value::from(ksuid),
+///     Value::Ext(KSUID_MSGPACK_EXT_TYPE, ksuid.bytes().to_vec())
+/// );
+/// ```
+impl From<Ksuid> for Value {
+    fn from(ksuid: Ksuid) -> Self {
+        Value::Ext(KSUID_MSGPACK_EXT_TYPE, ksuid.bytes().to_vec())
+    }
+}
+
+/// Borrows the ksuid as a MessagePack ext value of type [`KSUID_MSGPACK_EXT_TYPE`]
+impl<'a> From<&'a Ksuid> for ValueRef<'a> {
+    fn from(ksuid: &'a Ksuid) -> Self {
+        ValueRef::Ext(KSUID_MSGPACK_EXT_TYPE, ksuid.bytes())
+    }
+}
+
+/// Loads a ksuid from a MessagePack ext value of type [`KSUID_MSGPACK_EXT_TYPE`]
+///
+/// # Examples
+/// ```
+/// use rmpv::Value;
+/// use svix_ksuid::*;
+///
+/// let ksuid = Ksuid::new(None, None);
+/// assert_eq!(Ksuid::try_from(Value::from(ksuid)).unwrap(), ksuid);
+/// assert!(Ksuid::try_from(Value::Ext(1, ksuid.bytes().to_vec())).is_err());
+/// assert!(Ksuid::try_from(Value::Binary(ksuid.bytes().to_vec())).is_err());
+/// ```
+impl TryFrom<Value> for Ksuid {
+    type Error = Error;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        value_bytes(value.as_ref()).map(Self::from_bytes)
+    }
+}
+
+/// Loads a ksuid from a MessagePack ext value of type [`KSUID_MSGPACK_EXT_TYPE`]
+impl TryFrom<ValueRef<'_>> for Ksuid {
+    type Error = Error;
+
+    fn try_from(value: ValueRef<'_>) -> Result<Self, Self::Error> {
+        value_bytes(value).map(Self::from_bytes)
+    }
+}
+
+/// Converts the ksuid to a MessagePack ext value of type [`KSUID_MSGPACK_EXT_TYPE`]
+impl From<KsuidMs> for Value {
+    fn from(ksuid: KsuidMs) -> Self {
+        Value::Ext(KSUID_MSGPACK_EXT_TYPE, ksuid.bytes().to_vec())
+    }
+}
+
+/// Borrows the ksuid as a MessagePack ext value of type [`KSUID_MSGPACK_EXT_TYPE`]
+impl<'a> From<&'a KsuidMs> for ValueRef<'a> {
+    fn from(ksuid: &'a KsuidMs) -> Self {
+        ValueRef::Ext(KSUID_MSGPACK_EXT_TYPE, ksuid.bytes())
+    }
+}
+
+/// Loads a ksuid from a MessagePack ext value of type [`KSUID_MSGPACK_EXT_TYPE`]
+impl TryFrom<Value> for KsuidMs {
+    type Error = Error;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        value_bytes(value.as_ref()).map(Self::from_bytes)
+    }
+}
+
+/// Loads a ksuid from a MessagePack ext value of type [`KSUID_MSGPACK_EXT_TYPE`]
+impl TryFrom<ValueRef<'_>> for KsuidMs {
+    type Error = Error;
+
+    fn try_from(value: ValueRef<'_>) -> Result<Self, Self::Error> {
+        value_bytes(value).map(Self::from_bytes)
+    }
+}
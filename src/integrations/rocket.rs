@@ -0,0 +1,72 @@
+use ::rocket::form::{self, FromFormField, ValueField};
+use ::rocket::request::FromParam;
+
+use crate::{Error, Ksuid, KsuidMs};
+
+/// Parses a base62 ksuid from a route segment
+///
+/// Requests with an invalid ksuid are forwarded without running the handler, unless the
+/// parameter is a `Result<Ksuid, Error>`.
+///
+/// # Examples
+/// ```
+/// use rocket::get;
+/// use svix_ksuid::*;
+///
+/// #[get("/items/<id>")]
+/// fn get_item(id: Ksuid) -> String {
+///     id.to_string()
+/// }
+/// ```
+impl<'a> FromParam<'a> for Ksuid {
+    type Error = Error;
+
+    fn from_param(param: &'a str) -> Result<Self, Self::Error> {
+        param.parse()
+    }
+}
+
+/// Parses a base62 ksuid from a route segment
+impl<'a> FromParam<'a> for KsuidMs {
+    type Error = Error;
+
+    fn from_param(param: &'a str) -> Result<Self, Self::Error> {
+        param.parse()
+    }
+}
+
+/// Parses a base62 ksuid from a form field or query parameter
+///
+/// # Examples
+/// ```
+/// use rocket::{get, FromForm};
+/// use svix_ksuid::*;
+///
+/// #[derive(FromForm)]
+/// struct Query {
+///     after: Option<Ksuid>,
+/// }
+///
+/// #[get("/items?<query..>")]
+/// fn list_items(query: Query) -> String {
+///     format!("{:?}", query.after)
+/// }
+/// ```
+impl<'v> FromFormField<'v> for Ksuid {
+    fn from_value(field: ValueField<'v>) -> form::Result<'v, Self> {
+        field
+            .value
+            .parse()
+            .map_err(|e: Error| form::Error::validation(e.to_string()).into())
+    }
+}
+
+/// Parses a base62 ksuid from a form field or query parameter
+impl<'v> FromFormField<'v> for KsuidMs {
+    fn from_value(field: ValueField<'v>) -> form::Result<'v, Self> {
+        field
+            .value
+            .parse()
+            .map_err(|e: Error| form::Error::validation(e.to_string()).into())
+    }
+}
@@ -0,0 +1,124 @@
+use std::fmt::Debug;
+use std::future::{ready, Future};
+use std::str::FromStr;
+
+use ::salvo_core::extract::{Extractible, Metadata};
+use ::salvo_core::http::header::CONTENT_TYPE;
+use ::salvo_core::http::{StatusCode, StatusError};
+use ::salvo_core::writing::Scribe;
+use ::salvo_core::{Request, Response, Writer};
+
+use super::rejection::{KsuidRejection, PROBLEM_CONTENT_TYPE};
+use crate::{Error, Ksuid, KsuidMs};
+
+impl Scribe for KsuidRejection {
+    fn render(self, res: &mut Response) {
+        res.status_code(StatusCode::BAD_REQUEST);
+        let _ = res.add_header(CONTENT_TYPE, PROBLEM_CONTENT_TYPE, true);
+        let _ = res.write_body(self.to_problem_json());
+    }
+}
+
+/// Extracts the ksuid from the path parameter, or failing that the query parameter, named like
+/// the handler argument
+///
+/// When extracted with `req.extract()` it comes from the only path parameter instead, and
+/// routes that don't have exactly one are rendered as a `500 Internal Server Error`, since
+/// they're a server bug rather than an invalid id.
+///
+/// # Examples
+/// ```
+/// use salvo_core::prelude::*;
+/// use svix_ksuid::*;
+///
+/// #[handler]
+/// async fn get_item(id: Ksuid) -> String {
+///     id.to_string()
+/// }
+///
+/// let router = Router::with_path("items/{id}").get(get_item);
+/// ```
+impl<'ex> Extractible<'ex> for Ksuid {
+    fn metadata() -> &'ex Metadata {
+        static METADATA: Metadata = Metadata::new("Ksuid");
+        &METADATA
+    }
+
+    fn extract(
+        req: &'ex mut Request,
+    ) -> impl Future<Output = Result<Self, impl Writer + Send + Debug + 'static>> + Send {
+        ready(from_path::<Self>(req))
+    }
+
+    fn extract_with_arg(
+        req: &'ex mut Request,
+        arg: &str,
+    ) -> impl Future<Output = Result<Self, impl Writer + Send + Debug + 'static>> + Send {
+        ready(from_param::<Self>(req, arg))
+    }
+}
+
+/// Extracts the ksuid from the path parameter, or failing that the query parameter, named like
+/// the handler argument
+impl<'ex> Extractible<'ex> for KsuidMs {
+    fn metadata() -> &'ex Metadata {
+        static METADATA: Metadata = Metadata::new("KsuidMs");
+        &METADATA
+    }
+
+    fn extract(
+        req: &'ex mut Request,
+    ) -> impl Future<Output = Result<Self, impl Writer + Send + Debug + 'static>> + Send {
+        ready(from_path::<Self>(req))
+    }
+
+    fn extract_with_arg(
+        req: &'ex mut Request,
+        arg: &str,
+    ) -> impl Future<Output = Result<Self, impl Writer + Send + Debug + 'static>> + Send {
+        ready(from_param::<Self>(req, arg))
+    }
+}
+
+/// The rejection of the path extractor, which also fails on misconfigured routes
+#[derive(Debug)]
+enum PathRejection {
+    Invalid(KsuidRejection),
+    Route(StatusError),
+}
+
+impl Scribe for PathRejection {
+    fn render(self, res: &mut Response) {
+        match self {
+            Self::Invalid(rejection) => rejection.render(res),
+            Self::Route(error) => error.render(res),
+        }
+    }
+}
+
+fn parse<K: FromStr<Err = Error>>(value: &str) -> Result<K, KsuidRejection> {
+    value
+        .parse()
+        .map_err(|e: Error| KsuidRejection::new(e.to_string()))
+}
+
+fn from_path<K: FromStr<Err = Error>>(req: &Request) -> Result<K, PathRejection> {
+    let mut params = req.params().values();
+    match (params.next(), params.next()) {
+        (Some(value), None) => parse(value).map_err(PathRejection::Invalid),
+        _ => Err(PathRejection::Route(
+            StatusError::internal_server_error()
+                .brief("Expected a single path parameter for the ksuid"),
+        )),
+    }
+}
+
+fn from_param<K: FromStr<Err = Error>>(req: &Request, name: &str) -> Result<K, KsuidRejection> {
+    match req.params().get(name) {
+        Some(value) => parse(value),
+        None => match req.queries().get(name) {
+            Some(value) => parse(value),
+            None => Err(KsuidRejection::new(format!("Missing parameter {:?}", name))),
+        },
+    }
+}
@@ -0,0 +1,15 @@
+use ::subtle::{Choice, ConstantTimeEq};
+
+use crate::{Ksuid, KsuidMs};
+
+impl ConstantTimeEq for Ksuid {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl ConstantTimeEq for KsuidMs {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
@@ -0,0 +1,162 @@
+//! Ksuids are stored in SurrealDB as base62 strings, including as record id keys
+//! (`table:ksuid`). Base62 ksuids all have the same length and sort the same as their bytes,
+//! so records keyed by ksuid are ordered by creation time.
+
+use ::surrealdb_types::{
+    Error as SurrealError, Kind, RecordId, RecordIdKey, SurrealValue, ToSql, Value,
+};
+
+use crate::{Error, Ksuid, KsuidLike, KsuidMs};
+
+fn key_str(key: &RecordIdKey) -> Result<&str, Error> {
+    match key {
+        RecordIdKey::String(s) => Ok(s),
+        other => Err(Error::new(format!(
+            "Expected a string record id key, got {}",
+            other.to_sql()
+        ))),
+    }
+}
+
+fn value_str(value: &Value) -> Result<&str, Error> {
+    match value {
+        Value::String(s) => Ok(s),
+        Value::RecordId(id) => key_str(&id.key),
+        other => Err(Error::new(format!(
+            "Expected a string or record id ksuid, got {}",
+            other.to_sql()
+        ))),
+    }
+}
+
+fn surreal_error(e: Error) -> SurrealError {
+    SurrealError::validation(e.to_string(), None)
+}
+
+/// Stores the ksuid as a base62 string
+///
+/// Loading also accepts record ids, taking the ksuid from the key and ignoring the table.
+///
+/// # Examples
+/// ```
+/// use surrealdb_types::{RecordId, SurrealValue, Value};
+/// use svix_ksuid::*;
+///
+/// let ksuid = Ksuid::new(None, None);
+/// assert_eq!(ksuid.into_value(), Value::String(ksuid.to_string()));
+/// assert_eq!(Ksuid::from_value(ksuid.into_value()).unwrap(), ksuid);
+///
+/// let id = RecordId::new("user", ksuid);
+/// assert_eq!(Ksuid::from_value(Value::RecordId(id)).unwrap(), ksuid);
+/// ```
+impl SurrealValue for Ksuid {
+    fn kind_of() -> Kind {
+        Kind::String
+    }
+
+    fn is_value(value: &Value) -> bool {
+        value_str(value).is_ok_and(|s| Self::from_base62(s).is_ok())
+    }
+
+    fn into_value(self) -> Value {
+        Value::String(self.to_base62())
+    }
+
+    fn from_value(value: Value) -> Result<Self, SurrealError> {
+        value_str(&value)
+            .and_then(Self::from_base62)
+            .map_err(surreal_error)
+    }
+}
+
+/// Uses the base62 ksuid as a string record id key, e.g. for `RecordId::new("user", ksuid)`
+impl From<Ksuid> for RecordIdKey {
+    fn from(ksuid: Ksuid) -> Self {
+        RecordIdKey::String(ksuid.to_base62())
+    }
+}
+
+/// Loads a ksuid from a string record id key
+///
+/// # Examples
+/// ```
+/// use surrealdb_types::RecordIdKey;
+/// use svix_ksuid::*;
+///
+/// let ksuid = Ksuid::new(None, None);
+/// assert_eq!(Ksuid::try_from(RecordIdKey::from(ksuid)).unwrap(), ksuid);
+/// assert!(Ksuid::try_from(RecordIdKey::Number(1)).is_err());
+/// ```
+impl TryFrom<RecordIdKey> for Ksuid {
+    type Error = Error;
+
+    fn try_from(key: RecordIdKey) -> Result<Self, Self::Error> {
+        key_str(&key).and_then(Self::from_base62)
+    }
+}
+
+/// Loads a ksuid from the key of a record id, ignoring the table
+///
+/// # Examples
+/// ```
+/// use surrealdb_types::RecordId;
+/// use svix_ksuid::*;
+///
+/// let ksuid = Ksuid::new(None, None);
+/// assert_eq!(Ksuid::try_from(RecordId::new("user", ksuid)).unwrap(), ksuid);
+/// ```
+impl TryFrom<RecordId> for Ksuid {
+    type Error = Error;
+
+    fn try_from(id: RecordId) -> Result<Self, Self::Error> {
+        key_str(&id.key).and_then(Self::from_base62)
+    }
+}
+
+/// Stores the ksuid as a base62 string
+///
+/// Loading also accepts record ids, taking the ksuid from the key and ignoring the table.
+impl SurrealValue for KsuidMs {
+    fn kind_of() -> Kind {
+        Kind::String
+    }
+
+    fn is_value(value: &Value) -> bool {
+        value_str(value).is_ok_and(|s| Self::from_base62(s).is_ok())
+    }
+
+    fn into_value(self) -> Value {
+        Value::String(self.to_base62())
+    }
+
+    fn from_value(value: Value) -> Result<Self, SurrealError> {
+        value_str(&value)
+            .and_then(Self::from_base62)
+            .map_err(surreal_error)
+    }
+}
+
+/// Uses the base62 ksuid as a string record id key, e.g. for `RecordId::new("user", ksuid)`
+impl From<KsuidMs> for RecordIdKey {
+    fn from(ksuid: KsuidMs) -> Self {
+        RecordIdKey::String(ksuid.to_base62())
+    }
+}
+
+/// Loads a ksuid from a string record id key
+impl TryFrom<RecordIdKey> for KsuidMs {
+    type Error = Error;
+
+    fn try_from(key: RecordIdKey) -> Result<Self, Self::Error> {
+        key_str(&key).and_then(Self::from_base62)
+    }
+}
+
+/// Loads a ksuid from the key of a record id, ignoring the table
+impl TryFrom<RecordId> for KsuidMs {
+    type Error = Error;
+
+    fn try_from(id: RecordId) -> Result<Self, Self::Error> {
+        key_str(&id.key).and_then(Self::from_base62)
+    }
+}
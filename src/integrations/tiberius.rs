@@ -0,0 +1,109 @@
+//! Ksuids are sent to SQL Server as base62 strings, for `CHAR(27)` columns, and can be loaded
+//! from both `CHAR(27)` and `BINARY(20)` columns. Bind `ksuid.bytes()` to write a `BINARY(20)`
+//! column instead.
+//!
+//! Note that base62 ksuids are case sensitive, so `CHAR(27)` columns need a binary collation
+//! (e.g. `Latin1_General_BIN2`) for them to be unique and sorted correctly.
+
+use std::borrow::Cow;
+
+use ::tiberius::error::Error as TiberiusError;
+use ::tiberius::{ColumnData, FromSql, FromSqlOwned, IntoSql, ToSql};
+
+use crate::{Ksuid, KsuidLike, KsuidMs, TOTAL_BYTES};
+
+fn column_bytes(value: &ColumnData<'_>) -> Result<Option<[u8; TOTAL_BYTES]>, TiberiusError> {
+    let conversion = |e: crate::Error| TiberiusError::Conversion(e.to_string().into());
+    match value {
+        ColumnData::String(None) | ColumnData::Binary(None) => Ok(None),
+        ColumnData::String(Some(s)) => Ksuid::from_base62(s)
+            .map(|ksuid| Some(ksuid.into_bytes()))
+            .map_err(conversion),
+        ColumnData::Binary(Some(bytes)) => bytes.as_ref().try_into().map(Some).map_err(|_| {
+            TiberiusError::Conversion(
+                format!("Got ksuid of unexpected length {}", bytes.len()).into(),
+            )
+        }),
+        other => Err(TiberiusError::Conversion(
+            format!("Can't convert {:?} to a ksuid", other).into(),
+        )),
+    }
+}
+
+/// Sends the ksuid as a base62 string
+///
+/// # Examples
+/// ```
+/// use tiberius::{ColumnData, ToSql};
+/// use svix_ksuid::*;
+///
+/// let ksuid = Ksuid::new(None, None);
+/// assert_eq!(ksuid.to_sql(), ColumnData::String(Some(ksuid.to_string().into())));
+/// ```
+impl ToSql for Ksuid {
+    fn to_sql(&self) -> ColumnData<'_> {
+        ColumnData::String(Some(Cow::Owned(self.to_base62())))
+    }
+}
+
+/// Sends the ksuid as a base62 string, e.g. for `Query::bind`
+impl<'a> IntoSql<'a> for Ksuid {
+    fn into_sql(self) -> ColumnData<'a> {
+        ColumnData::String(Some(Cow::Owned(self.to_base62())))
+    }
+}
+
+/// Loads a ksuid from a base62 string or 20 raw bytes
+///
+/// # Examples
+/// ```
+/// use tiberius::{ColumnData, FromSql};
+/// use svix_ksuid::*;
+///
+/// let ksuid = Ksuid::new(None, None);
+/// let binary = ColumnData::Binary(Some(ksuid.bytes().to_vec().into()));
+/// assert_eq!(Ksuid::from_sql(&binary).unwrap(), Some(ksuid));
+/// let string = ColumnData::String(Some(ksuid.to_string().into()));
+/// assert_eq!(Ksuid::from_sql(&string).unwrap(), Some(ksuid));
+/// assert_eq!(Ksuid::from_sql(&ColumnData::String(None)).unwrap(), None);
+/// ```
+impl<'a> FromSql<'a> for Ksuid {
+    fn from_sql(value: &'a ColumnData<'static>) -> tiberius::Result<Option<Self>> {
+        column_bytes(value).map(|bytes| bytes.map(Self::from_bytes))
+    }
+}
+
+/// Loads a ksuid from a base62 string or 20 raw bytes
+impl FromSqlOwned for Ksuid {
+    fn from_sql_owned(value: ColumnData<'static>) -> tiberius::Result<Option<Self>> {
+        column_bytes(&value).map(|bytes| bytes.map(Self::from_bytes))
+    }
+}
+
+/// Sends the ksuid as a base62 string
+impl ToSql for KsuidMs {
+    fn to_sql(&self) -> ColumnData<'_> {
+        ColumnData::String(Some(Cow::Owned(self.to_base62())))
+    }
+}
+
+/// Sends the ksuid as a base62 string, e.g. for `Query::bind`
+impl<'a> IntoSql<'a> for KsuidMs {
+    fn into_sql(self) -> ColumnData<'a> {
+        ColumnData::String(Some(Cow::Owned(self.to_base62())))
+    }
+}
+
+/// Loads a ksuid from a base62 string or 20 raw bytes
+impl<'a> FromSql<'a> for KsuidMs {
+    fn from_sql(value: &'a ColumnData<'static>) -> tiberius::Result<Option<Self>> {
+        column_bytes(value).map(|bytes| bytes.map(Self::from_bytes))
+    }
+}
+
+/// Loads a ksuid from a base62 string or 20 raw bytes
+impl FromSqlOwned for KsuidMs {
+    fn from_sql_owned(value: ColumnData<'static>) -> tiberius::Result<Option<Self>> {
+        column_bytes(&value).map(|bytes| bytes.map(Self::from_bytes))
+    }
+}
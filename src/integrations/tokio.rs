@@ -0,0 +1,205 @@
+use std::collections::VecDeque;
+use std::fmt;
+use std::pin::pin;
+use std::sync::{Arc, Mutex, MutexGuard, OnceLock};
+use std::time::{Duration, Instant};
+
+use ::tokio::sync::Notify;
+use ::tokio::task::JoinHandle;
+
+use crate::{Clock, EntropySource, Error, Generator, KsuidLike};
+
+struct Shared<K> {
+    queue: Mutex<VecDeque<(Instant, K)>>,
+    capacity: usize,
+    max_age: Duration,
+    /// Wakes the refill task
+    refill: Notify,
+    /// Wakes callers waiting on an empty queue
+    refilled: Notify,
+    /// Set when the entropy source failed, which stops the refill task
+    error: OnceLock<Error>,
+}
+
+impl<K> Shared<K> {
+    fn queue(&self) -> MutexGuard<'_, VecDeque<(Instant, K)>> {
+        self.queue.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn pop(&self) -> Option<K> {
+        let mut queue = self.queue();
+        let now = Instant::now();
+        let ksuid = loop {
+            match queue.pop_front() {
+                Some((created, ksuid)) if now.duration_since(created) <= self.max_age => {
+                    break Some(ksuid)
+                }
+                // Too old for its timestamp to still be accurate
+                Some(_) => continue,
+                None => break None,
+            }
+        };
+        if queue.len() <= self.capacity / 2 {
+            self.refill.notify_one();
+        }
+        ksuid
+    }
+}
+
+/// A pool of pre-generated ksuids, refilled by a background tokio task
+///
+/// Taking a ksuid from the pool doesn't touch the entropy source or the clock, which makes it
+/// cheap for latency-sensitive handlers. The pool is refilled in batches whenever it's down
+/// to half of its capacity. Ksuids that have been in the pool for longer than the maximum age
+/// are dropped instead of handed out, so their timestamps stay close to the time they're used.
+///
+/// The refill task is aborted when the `PrefetchGenerator` is dropped, and stops if the
+/// entropy source fails, after which the pool is closed and [`PrefetchGenerator::next`]
+/// returns the error once the remaining ksuids are taken.
+///
+/// # Examples
+/// ```
+/// use svix_ksuid::*;
+///
+/// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+/// let pool = PrefetchGenerator::<KsuidMs>::new(1024);
+/// let a = pool.next().await.unwrap();
+/// let b = pool.next().await.unwrap();
+/// assert_ne!(a, b);
+/// # });
+/// ```
+pub struct PrefetchGenerator<K> {
+    shared: Arc<Shared<K>>,
+    task: JoinHandle<()>,
+}
+
+impl<K: KsuidLike<Type = K> + Send + 'static> PrefetchGenerator<K> {
+    /// The default maximum age of the ksuids in the pool
+    pub const DEFAULT_MAX_AGE: Duration = Duration::from_secs(1);
+
+    /// Create a pool of up to `capacity` ksuids using the system clock and the global entropy
+    /// source
+    ///
+    /// Must be called from within a tokio runtime, which the refill task is spawned on.
+    #[cfg(feature = "getrandom")]
+    pub fn new(capacity: usize) -> Self {
+        Self::spawn(Generator::new(), capacity, Self::DEFAULT_MAX_AGE)
+    }
+
+    /// Create a pool of up to `capacity` ksuids from `generator`, dropping the ones that are
+    /// older than `max_age`
+    ///
+    /// Must be called from within a tokio runtime, which the refill task is spawned on. The
+    /// age is measured in real time, regardless of the generator's clock.
+    pub fn spawn<C, E>(mut generator: Generator<C, E>, capacity: usize, max_age: Duration) -> Self
+    where
+        C: Clock + Send + 'static,
+        E: EntropySource + Send + 'static,
+    {
+        let capacity = capacity.max(1);
+        let shared = Arc::new(Shared {
+            queue: Mutex::new(VecDeque::with_capacity(capacity)),
+            capacity,
+            max_age,
+            refill: Notify::new(),
+            refilled: Notify::new(),
+            error: OnceLock::new(),
+        });
+        // Fill the pool right away
+        shared.refill.notify_one();
+
+        let task = ::tokio::spawn({
+            let shared = shared.clone();
+            async move {
+                loop {
+                    shared.refill.notified().await;
+                    let missing = capacity.saturating_sub(shared.queue().len());
+                    // Generate outside of the lock, so that callers aren't blocked meanwhile
+                    let batch: Result<Vec<K>, Error> =
+                        (0..missing).map(|_| generator.try_generate()).collect();
+                    let batch = match batch {
+                        Ok(batch) => batch,
+                        Err(err) => {
+                            // Close the pool, waking the callers waiting on it
+                            let _ = shared.error.set(err);
+                            shared.refilled.notify_waiters();
+                            return;
+                        }
+                    };
+                    let created = Instant::now();
+                    let mut queue = shared.queue();
+                    queue.extend(batch.into_iter().map(|ksuid| (created, ksuid)));
+                    queue.truncate(capacity);
+                    drop(queue);
+                    shared.refilled.notify_waiters();
+                }
+            }
+        });
+        Self { shared, task }
+    }
+
+    /// Take a ksuid from the pool, waiting for the refill task if it's empty
+    ///
+    /// Fails with the error of the entropy source once the pool is closed and empty.
+    pub async fn next(&self) -> Result<K, Error> {
+        loop {
+            let mut refilled = pin!(self.shared.refilled.notified());
+            // Register before checking the queue, so that a refill in between isn't missed
+            refilled.as_mut().enable();
+            if let Some(ksuid) = self.shared.pop() {
+                return Ok(ksuid);
+            }
+            if let Some(err) = self.shared.error.get() {
+                return Err(err.clone());
+            }
+            refilled.await;
+        }
+    }
+
+    /// Take a ksuid from the pool if there is one, without waiting
+    ///
+    /// Once the pool is closed (see [`PrefetchGenerator::next`]) this returns `None` as soon as
+    /// the remaining ksuids are taken.
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    ///
+    /// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+    /// let pool = PrefetchGenerator::<Ksuid>::new(16);
+    /// let ksuid = match pool.try_next() {
+    ///     Some(ksuid) => ksuid,
+    ///     None => Ksuid::new(None, None),
+    /// };
+    /// # });
+    /// ```
+    pub fn try_next(&self) -> Option<K> {
+        self.shared.pop()
+    }
+
+    /// The number of ksuids currently in the pool, including ones that are too old
+    pub fn len(&self) -> usize {
+        self.shared.queue().len()
+    }
+
+    /// Whether the pool is currently empty
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<K> fmt::Debug for PrefetchGenerator<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PrefetchGenerator")
+            .field("len", &self.shared.queue().len())
+            .field("capacity", &self.shared.capacity)
+            .field("max_age", &self.shared.max_age)
+            .finish()
+    }
+}
+
+impl<K> Drop for PrefetchGenerator<K> {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
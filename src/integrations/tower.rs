@@ -0,0 +1,108 @@
+use std::task::{Context, Poll};
+
+use ::http::{HeaderName, HeaderValue, Request};
+use ::tower_layer::Layer;
+use ::tower_service::Service;
+
+use crate::{KsuidLike, KsuidMs};
+
+/// The header used for request ids by default
+pub const REQUEST_ID_HEADER: HeaderName = HeaderName::from_static("x-request-id");
+
+/// The id of the current request, stored in the request extensions by [`RequestIdLayer`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RequestId(pub KsuidMs);
+
+/// A tower layer stamping every request with a ksuid request id
+///
+/// Requests that already have a valid base62 ksuid in the request id header keep it, the rest
+/// get a newly generated `KsuidMs`. Either way the id is set on the header and stored in the
+/// request extensions as a [`RequestId`].
+///
+/// # Examples
+/// ```
+/// use http::Request;
+/// use svix_ksuid::*;
+/// use tower::{service_fn, Layer, Service, ServiceExt};
+///
+/// let service = RequestIdLayer::new().layer(service_fn(|req: Request<()>| async move {
+///     let RequestId(id) = *req.extensions().get::<RequestId>().unwrap();
+///     Ok::<_, std::convert::Infallible>(id)
+/// }));
+///
+/// let req = Request::get("/")
+///     .header("x-request-id", "1srOrx2ZWZBpBUvZwXKQmoEYga2")
+///     .body(())
+///     .unwrap();
+/// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+/// let id = service.oneshot(req).await.unwrap();
+/// assert_eq!(id.to_string(), "1srOrx2ZWZBpBUvZwXKQmoEYga2");
+/// # });
+/// ```
+#[derive(Debug, Clone)]
+pub struct RequestIdLayer {
+    header: HeaderName,
+}
+
+impl RequestIdLayer {
+    /// Create a new layer using the `x-request-id` header
+    pub fn new() -> Self {
+        Self::with_header(REQUEST_ID_HEADER)
+    }
+
+    /// Create a new layer using a custom header
+    pub fn with_header(header: HeaderName) -> Self {
+        Self { header }
+    }
+}
+
+impl Default for RequestIdLayer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S> Layer<S> for RequestIdLayer {
+    type Service = RequestIdService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RequestIdService {
+            inner,
+            header: self.header.clone(),
+        }
+    }
+}
+
+/// The service created by [`RequestIdLayer`]
+#[derive(Debug, Clone)]
+pub struct RequestIdService<S> {
+    inner: S,
+    header: HeaderName,
+}
+
+impl<S, B> Service<Request<B>> for RequestIdService<S>
+where
+    S: Service<Request<B>>,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = S::Future;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut req: Request<B>) -> Self::Future {
+        let id = req
+            .headers()
+            .get(&self.header)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| KsuidMs::from_base62(value).ok())
+            .unwrap_or_else(|| KsuidMs::from_millis(None, None));
+        // Base62 is always a valid header value
+        let value = HeaderValue::from_str(&id.to_base62()).unwrap();
+        req.headers_mut().insert(self.header.clone(), value);
+        req.extensions_mut().insert(RequestId(id));
+        self.inner.call(req)
+    }
+}
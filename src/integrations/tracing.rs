@@ -0,0 +1,21 @@
+use ::tracing::Span;
+
+use crate::{base62, KsuidLike};
+
+/// Record a ksuid on a span as a base62 string field
+///
+/// Unlike recording it with `%ksuid` (`Display`), the value is recorded as a string, so
+/// subscribers that emit structured output (e.g. JSON) can treat it as such. The field has to
+/// be declared when the span is created, e.g. with `id = tracing::field::Empty`.
+///
+/// # Examples
+/// ```
+/// use svix_ksuid::*;
+///
+/// let span = tracing::info_span!("request", id = tracing::field::Empty);
+/// record_ksuid(&span, "id", &Ksuid::new(None, None));
+/// ```
+pub fn record_ksuid<K: KsuidLike>(span: &Span, field: &str, ksuid: &K) {
+    let encoded = base62::encode(ksuid.bytes());
+    span.record(field, base62::as_str(&encoded));
+}
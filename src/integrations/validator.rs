@@ -0,0 +1,45 @@
+use std::borrow::Cow;
+
+use ::validator::{Validate, ValidationError, ValidationErrors};
+
+use crate::{is_valid_ksuid, Ksuid, KsuidMs};
+
+/// Validate that a string is a base62 ksuid, for use with the `validator` crate
+///
+/// # Examples
+/// ```
+/// use svix_ksuid::*;
+/// use validator::Validate;
+///
+/// #[derive(Validate)]
+/// struct Request {
+///     #[validate(custom(function = "validate_ksuid"))]
+///     id: String,
+/// }
+///
+/// let request = Request { id: "1srOrx2ZWZBpBUvZwXKQmoEYga2".to_owned() };
+/// assert!(request.validate().is_ok());
+///
+/// let request = Request { id: "not-a-ksuid".to_owned() };
+/// assert!(request.validate().is_err());
+/// ```
+pub fn validate_ksuid(value: &str) -> Result<(), ValidationError> {
+    if is_valid_ksuid(value) {
+        Ok(())
+    } else {
+        Err(ValidationError::new("ksuid").with_message(Cow::Borrowed("invalid ksuid")))
+    }
+}
+
+// Ksuids are always valid once parsed, this allows using `#[validate(nested)]` on them.
+impl Validate for Ksuid {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        Ok(())
+    }
+}
+
+impl Validate for KsuidMs {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        Ok(())
+    }
+}
@@ -0,0 +1,18 @@
+//! Ksuids are `Copy`, so they can't implement `ZeroizeOnDrop`. Wrap them in
+//! `zeroize::Zeroizing` to have them zeroed when dropped.
+
+use ::zeroize::Zeroize;
+
+use crate::{Ksuid, KsuidMs};
+
+impl Zeroize for Ksuid {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl Zeroize for KsuidMs {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
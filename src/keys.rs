@@ -0,0 +1,113 @@
+//! Helpers for using ksuids as keys in ordered key-value stores (e.g. sled, RocksDB or redb)
+//!
+//! The key bytes of a ksuid are its 20 raw bytes: a big-endian timestamp followed by the
+//! payload. Comparing them lexicographically, the way these stores order keys, is the same as
+//! comparing the ksuids themselves, so iterating over a range of keys yields ksuids in order.
+//!
+//! Prefixed keys (e.g. `tenant_id ++ ksuid`) keep that ordering within each prefix. Use fixed
+//! length prefixes, or prefixes that are never a prefix of one another, so that keys from
+//! different prefixes don't interleave.
+
+use crate::{Error, KsuidLike, TOTAL_BYTES};
+
+/// The number of bytes in the key of a ksuid
+pub const KEY_BYTES: usize = TOTAL_BYTES;
+
+/// Get the key bytes of a ksuid
+///
+/// These are guaranteed to always be the 20 raw bytes of the ksuid, so they sort the same as
+/// the ksuids do.
+///
+/// # Examples
+/// ```
+/// use svix_ksuid::keys;
+/// use svix_ksuid::{Ksuid, KsuidLike};
+///
+/// let a = Ksuid::from_seconds(Some(1_621_627_443), None);
+/// let b = Ksuid::from_seconds(Some(1_621_627_444), None);
+/// assert!(keys::to_key_bytes(&a) < keys::to_key_bytes(&b));
+/// ```
+pub fn to_key_bytes<K: KsuidLike>(ksuid: &K) -> [u8; KEY_BYTES] {
+    *ksuid.bytes()
+}
+
+/// Create a ksuid from its key bytes
+///
+/// # Examples
+/// ```
+/// use svix_ksuid::keys;
+/// use svix_ksuid::{Ksuid, KsuidLike};
+///
+/// let ksuid = Ksuid::from_seconds(None, None);
+/// let key = keys::to_key_bytes(&ksuid);
+/// assert_eq!(keys::from_key_bytes::<Ksuid>(&key).unwrap(), ksuid);
+/// assert!(keys::from_key_bytes::<Ksuid>(&key[1..]).is_err());
+/// ```
+pub fn from_key_bytes<K: KsuidLike<Type = K>>(key: &[u8]) -> Result<K, Error> {
+    let bytes: [u8; KEY_BYTES] = key
+        .try_into()
+        .map_err(|_| Error::new(format!("Got key of unexpected length {}", key.len())))?;
+    Ok(K::from_bytes(bytes))
+}
+
+/// Create a key made of `prefix` followed by the key bytes of a ksuid
+///
+/// # Examples
+/// ```
+/// use svix_ksuid::keys;
+/// use svix_ksuid::{Ksuid, KsuidLike};
+///
+/// let tenant_id = 42u64.to_be_bytes();
+/// let ksuid = Ksuid::from_seconds(None, None);
+/// let key = keys::prefixed(&tenant_id, &ksuid);
+/// assert_eq!(&key[..8], &tenant_id);
+/// assert_eq!(&key[8..], ksuid.bytes());
+/// ```
+pub fn prefixed<K: KsuidLike>(prefix: &[u8], ksuid: &K) -> Vec<u8> {
+    let mut key = Vec::with_capacity(prefix.len() + KEY_BYTES);
+    key.extend_from_slice(prefix);
+    key.extend_from_slice(ksuid.bytes());
+    key
+}
+
+/// Split a key created with [`prefixed`] into its prefix and ksuid
+///
+/// # Examples
+/// ```
+/// use svix_ksuid::keys;
+/// use svix_ksuid::{Ksuid, KsuidLike};
+///
+/// let ksuid = Ksuid::from_seconds(None, None);
+/// let key = keys::prefixed(b"tenant:", &ksuid);
+/// let (prefix, parsed) = keys::split_prefixed::<Ksuid>(&key).unwrap();
+/// assert_eq!(prefix, b"tenant:");
+/// assert_eq!(parsed, ksuid);
+/// ```
+pub fn split_prefixed<K: KsuidLike<Type = K>>(key: &[u8]) -> Result<(&[u8], K), Error> {
+    let split = key
+        .len()
+        .checked_sub(KEY_BYTES)
+        .ok_or_else(|| Error::new(format!("Got key of unexpected length {}", key.len())))?;
+    let (prefix, ksuid) = key.split_at(split);
+    Ok((prefix, from_key_bytes(ksuid)?))
+}
+
+/// Get the first and last keys (both inclusive) of all ksuids with the provided prefix
+///
+/// This is useful for range scans over everything under a prefix.
+///
+/// # Examples
+/// ```
+/// use svix_ksuid::keys;
+/// use svix_ksuid::{Ksuid, KsuidLike};
+///
+/// let (start, end) = keys::prefix_range::<Ksuid>(b"tenant:");
+/// let key = keys::prefixed(b"tenant:", &Ksuid::from_seconds(None, None));
+/// assert!(start <= key && key <= end);
+/// ```
+pub fn prefix_range<K: KsuidLike<Type = K>>(prefix: &[u8]) -> (Vec<u8>, Vec<u8>) {
+    (
+        prefixed(prefix, &K::from_bytes([0; KEY_BYTES])),
+        prefixed(prefix, &K::from_bytes([0xff; KEY_BYTES])),
+    )
+}
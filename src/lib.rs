@@ -43,9 +43,114 @@
 //!
 //! And they both implement the same `KsuidLike` trait.
 //!
+//! ### Default features
+//! * `time` - adds the `time::OffsetDateTime` based APIs, e.g. `new()` and `timestamp()`.
+//!   Disable it with `default-features = false` to drop the dependency, and use the unix
+//!   timestamp or `SystemTime` based APIs instead, e.g. `from_seconds()` and `system_time()`.
+//! * `getrandom` - uses the operating system's random number generator for random payloads,
+//!   through `OsEntropy`. Disable it where the OS RNG must not be used: the constructors that
+//!   create a random payload implicitly (e.g. `new()`, `from_seconds()` and `Generator::new()`)
+//!   are then not available, and neither are the `rayon` and `tower` integrations. Pass an
+//!   `EntropySource` explicitly instead, with `new_with_entropy()` or
+//!   `Generator::from_entropy()`, or pass the payload itself with
+//!   `from_system_time_and_payload()`.
+//!
+//! ### FIPS
+//! The `fips` feature is for deployments that must only use a FIPS validated random number
+//! generator. It removes the generators built into this crate, by failing the build if
+//! `getrandom`, `insecure-fast-rng` or `chacha` are enabled, but it doesn't validate anything
+//! itself: the application must supply an `EntropySource` backed by a validated module (e.g.
+//! `aws-lc-rs` in FIPS mode), with `new_with_entropy()` or `Generator::from_entropy()`, or set
+//! it with `set_entropy_source()`, which `DefaultEntropy` and `SecureKsuid` then use. Disable
+//! the default features:
+//!
+//! ```toml
+//! [dependencies]
+//! svix-ksuid = { version = "^0.6.0", default-features = false, features = ["time", "fips"] }
+//! ```
+//!
 //! ### Opt-in features
 //! * `serde` - adds the ability to serialize and deserialize `Ksuid` and `KsuidMs`
-//!   using serde.
+//!   using serde, and the `serde` module with alternative representations.
+//! * `regex` - adds `ksuid_regex()` returning a compiled regex of `KSUID_PATTERN`.
+//! * `validator` - adds `validate_ksuid()` for use with the `validator` crate, and implements
+//!   `Validate` for `Ksuid` and `KsuidMs`.
+//! * `garde` - adds the `garde_ksuid` rule for use with the `garde` crate, and implements
+//!   `Validate` for `Ksuid` and `KsuidMs`.
+//! * `zeroize` - implements `Zeroize` for `Ksuid` and `KsuidMs`. Wrap them in
+//!   `zeroize::Zeroizing` to have them zeroed on drop.
+//! * `insecure-fast-rng` - adds `new_insecure()`, which generates the payload using a fast
+//!   but **predictable** random number generator. Never use it for secrets.
+//! * `chacha` - adds `SeededRng` and `new_seeded()`, for reproducible streams of ksuids
+//!   backed by a seeded ChaCha20 generator.
+//! * `chrono` - adds `new_chrono()` and `timestamp_chrono()` for working with
+//!   `chrono::DateTime<Utc>` instead of `time::OffsetDateTime`.
+//! * `jiff` - adds `from_jiff()` and `timestamp_jiff()` for working with `jiff::Timestamp`.
+//! * `test-util` - adds the `testing` module with helpers for generating test data, and a
+//!   `MockClock` for controlling time in tests.
+//! * `tracing` - adds `record_ksuid()` for recording ksuids on spans as string fields.
+//! * `opentelemetry` - adds `trace_id()`, `span_id()` and `from_trace_id()` for
+//!   mapping ksuids to OpenTelemetry trace and span ids.
+//! * `actix-web` - implements `FromRequest` for `Ksuid` and `KsuidMs`, rejecting invalid ids
+//!   with a `400` problem details response (`KsuidRejection`), and adds error handlers for
+//!   `web::PathConfig` and `web::QueryConfig` that render any parameter error as problem
+//!   details.
+//! * `axum` - adds the `KsuidParam` extractor, rejecting invalid ids with the same `400`
+//!   problem details response (`KsuidRejection`) as `actix-web`, and misconfigured routes
+//!   with axum's own `500` (`KsuidParamRejection`).
+//! * `rocket` - implements `FromParam` and `FromFormField` for `Ksuid` and `KsuidMs`, so they can
+//!   be used directly as route, query and form parameters.
+//! * `poem-openapi` - implements the `poem-openapi` type traits for `Ksuid` and `KsuidMs`, so they
+//!   can be used in request bodies and parameters, and are documented as base62 strings.
+//! * `salvo` - implements `Extractible` for `Ksuid` and `KsuidMs`, so they can be used directly as
+//!   handler arguments, rejecting invalid ids with the same `400` problem details response.
+//! * `tower` - adds `RequestIdLayer`, a tower layer stamping each request with a `KsuidMs` in
+//!   the `x-request-id` header and the request extensions, reusing valid inbound ids.
+//! * `foundationdb-tuple` - implements `TuplePack` and `TupleUnpack` for `Ksuid` and `KsuidMs`, so
+//!   they can be packed into FoundationDB tuples while preserving their order.
+//! * `nohash-hasher` - adds `NoHashKey`, a ksuid wrapper that hashes as a single `u64` for use
+//!   with `nohash_hasher::IntMap`, skipping hashing of the already random payload.
+//! * `avro` - adds `KSUID_AVRO_SCHEMA`, the canonical Avro schema for ksuids (a 20 byte `fixed`),
+//!   and conversions to and from `apache_avro::types::Value`.
+//! * `clickhouse` - adds `serde::clickhouse`, for storing ksuids in ClickHouse `FixedString(20)`
+//!   columns with the `clickhouse` crate, preserving their sort order.
+//! * `rmpv` - converts ksuids to and from `rmpv::Value` as a MessagePack extension type (see
+//!   `KSUID_MSGPACK_EXT_TYPE`), telling them apart from other binary data.
+//! * `subtle` - implements `subtle::ConstantTimeEq` for `Ksuid` and `KsuidMs`, and makes
+//!   `ct_eq()` use it instead of a best effort comparison.
+//! * `bytemuck` - implements `bytemuck::Pod` and `Zeroable` for `Ksuid` and `KsuidMs`, so
+//!   slices of ksuids can be cast to and from bytes without copying.
+//! * `zerocopy` - derives the `zerocopy` traits (`FromBytes`, `IntoBytes`, `KnownLayout`,
+//!   `Immutable` and `Unaligned`) for `Ksuid` and `KsuidMs`, so they can be used in structs
+//!   read directly from packets or files.
+//! * `hex` - implements `hex::FromHex` for `Ksuid` and `KsuidMs`. `hex::ToHex` and
+//!   `hex::encode` already work through `AsRef<[u8]>`.
+//! * `surrealdb` - implements `SurrealValue` for `Ksuid` and `KsuidMs` and converts them to and
+//!   from record id keys, so they can be used as SurrealDB record ids (`table:ksuid`).
+//! * `neo4rs` - converts ksuids to and from `neo4rs::BoltType` as base62 strings. Reading them
+//!   from rows and nodes (e.g. `row.get::<Ksuid>("id")`) uses serde, so this enables `serde`.
+//! * `tiberius` - implements `tiberius::ToSql` and `FromSql` for `Ksuid` and `KsuidMs`, for SQL
+//!   Server `CHAR(27)` (with a binary collation) and `BINARY(20)` columns.
+//! * `duckdb` - implements `duckdb::ToSql` and `FromSql` for `Ksuid` and `KsuidMs`, binding
+//!   them as base62 strings and loading them from strings or 20 byte blobs.
+//!   `duckdb-bundled` also enables `duckdb/bundled`, to build DuckDB instead of linking it.
+//! * `heed` - implements the `heed` codec traits for `Ksuid` and `KsuidMs`, storing their raw
+//!   bytes so LMDB keys sort by creation time, e.g. `Database<Ksuid, Str>`.
+//! * `minicbor` - implements `minicbor::Encode`, `Decode` and `CborLen` for `Ksuid` and
+//!   `KsuidMs`, as a 20 byte byte string. This works without `std` in `minicbor`.
+//! * `defmt` - implements `defmt::Format` for `Ksuid` and `KsuidMs`, printing them as base62
+//!   without allocating.
+//! * `embassy-time` - adds `EmbassyClock`, a `Clock` for firmware that takes the wall-clock
+//!   time from the `embassy_time` tick counter and a known boot or sync time.
+//! * `rayon` - adds `generate_many_parallel()`, which generates large batches of ksuids on the
+//!   rayon thread pool.
+//! * `tokio` - adds `PrefetchGenerator`, a pool of pre-generated ksuids refilled by a
+//!   background tokio task, for taking ksuids without touching the clock or entropy source.
+//! * `metrics` - records the ksuids generated by `Generator` (`KSUID_GENERATED_METRIC`) and how
+//!   long generating them took (`KSUID_GENERATION_DURATION_METRIC`) with the `metrics` crate,
+//!   labeled with their `variant` (`ksuid` or `ksuid_ms`).
+//! * `ffi` - adds the `ffi` module with C bindings (see `include/svix_ksuid.h`). Define
+//!   `SVIX_KSUID_GETRANDOM` in C when `getrandom` is enabled too, to declare `ksuid_new()`.
 //!
 //! Make sure to enable like this:
 //! ```toml
@@ -59,31 +164,258 @@
 //!
 //! All rights reserved to the [Svix webhooks service](https://www.svix.com).
 
+use core::cmp::Ordering;
 use core::fmt;
+use std::borrow::Borrow;
 use std::hash::{Hash, Hasher};
-use std::{error, str::FromStr};
+#[cfg(feature = "time")]
+use std::ops::{Add, AddAssign, Sub, SubAssign};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use std::{error, io, str::FromStr};
 
 use byteorder::{BigEndian, ByteOrder};
+#[cfg(feature = "time")]
 use time::OffsetDateTime;
 
 #[cfg(feature = "serde")]
-use serde::de::{self, Deserialize, Deserializer, Visitor};
+use ::serde::de::{self, Deserialize, Deserializer, Visitor};
+#[cfg(feature = "serde")]
+use ::serde::ser::{Serialize, Serializer};
+
+pub mod analysis;
+mod base62;
+mod cbor;
+mod clock;
+mod delta;
+mod entropy;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+mod firebase;
+mod generator;
+mod hash;
+mod integrations;
+pub mod keys;
+mod redact;
+#[cfg(feature = "insecure-fast-rng")]
+mod rng;
+mod secure;
+#[cfg(feature = "chacha")]
+mod seeded;
 #[cfg(feature = "serde")]
-use serde::ser::{Serialize, Serializer};
+pub mod serde;
+mod set;
+mod short;
+pub mod slice;
+#[cfg(feature = "test-util")]
+pub mod testing;
+mod writer;
+
+#[cfg(all(feature = "fips", feature = "getrandom"))]
+compile_error!(
+    "the `fips` feature can't be combined with `getrandom` (a default feature) or \
+     `insecure-fast-rng`, disable the default features and pass a FIPS validated entropy \
+     source explicitly"
+);
+#[cfg(all(feature = "fips", feature = "chacha"))]
+compile_error!("the `fips` feature can't be combined with `chacha`, ChaCha20 isn't FIPS approved");
+
+pub use base62::Base62Alphabet;
+pub use cbor::KSUID_CBOR_TAG;
+pub use clock::{Clock, SystemClock};
+pub use delta::{DeltaReader, DeltaWriter};
+#[cfg(feature = "getrandom")]
+pub use entropy::OsEntropy;
+pub use entropy::{set_entropy_failure_hook, set_entropy_source, DefaultEntropy, EntropySource};
+pub use generator::Generator;
+#[cfg(feature = "actix-web")]
+pub use integrations::actix_web::{actix_path_error_handler, actix_query_error_handler};
+#[cfg(feature = "avro")]
+pub use integrations::avro::{ksuid_avro_schema, KSUID_AVRO_SCHEMA};
+#[cfg(feature = "axum")]
+pub use integrations::axum::{KsuidParam, KsuidParamRejection};
+#[cfg(feature = "embassy-time")]
+pub use integrations::embassy_time::EmbassyClock;
+#[cfg(feature = "garde")]
+pub use integrations::garde::garde_ksuid;
+#[cfg(feature = "metrics")]
+pub use integrations::metrics::{KSUID_GENERATED_METRIC, KSUID_GENERATION_DURATION_METRIC};
+#[cfg(feature = "nohash-hasher")]
+pub use integrations::nohash_hasher::NoHashKey;
+#[cfg(all(feature = "rayon", feature = "getrandom"))]
+pub use integrations::rayon::generate_many_parallel;
+#[cfg(any(feature = "actix-web", feature = "axum", feature = "salvo"))]
+pub use integrations::rejection::KsuidRejection;
+#[cfg(feature = "rmpv")]
+pub use integrations::rmpv::KSUID_MSGPACK_EXT_TYPE;
+#[cfg(feature = "tokio")]
+pub use integrations::tokio::PrefetchGenerator;
+#[cfg(all(feature = "tower", feature = "getrandom"))]
+pub use integrations::tower::{RequestId, RequestIdLayer, RequestIdService, REQUEST_ID_HEADER};
+#[cfg(feature = "tracing")]
+pub use integrations::tracing::record_ksuid;
+#[cfg(feature = "validator")]
+pub use integrations::validator::validate_ksuid;
+pub use redact::Redacted;
+pub use secure::SecureKsuid;
+#[cfg(feature = "chacha")]
+pub use seeded::SeededRng;
+pub use set::KsuidSet;
+pub use short::ShortKsuid;
+pub use writer::KsuidWriter;
 
 pub const KSUID_EPOCH: i64 = 1_400_000_000;
 
+/// The earliest timestamp (in seconds) that can be stored in a ksuid (`2014-05-13T16:53:20Z`)
+pub const KSUID_MIN_TIMESTAMP: i64 = KSUID_EPOCH;
+/// The latest timestamp (in seconds) that can be stored in a ksuid (`2150-06-19T23:21:35Z`)
+pub const KSUID_MAX_TIMESTAMP: i64 = KSUID_EPOCH + u32::MAX as i64;
+
+/// The number of bytes in a ksuid
+pub const KSUID_BYTES: usize = TOTAL_BYTES;
+/// The length of the base62 string representation of a ksuid
+pub const KSUID_STRING_LENGTH: usize = TOTAL_BYTES_BASE62;
+/// A regex pattern matching base62 ksuid strings
+///
+/// Note that the pattern doesn't check for values overflowing 20 bytes, use [`is_valid_ksuid`]
+/// for a complete check.
+pub const KSUID_PATTERN: &str = "^[0-9A-Za-z]{27}$";
+
 const BASE_62_CHARS: &[u8; 62] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+const MAX_BASE62: &str = "aWgEPTl1tmebfsQzFP4bxwgy80V";
 
 const TOTAL_BYTES: usize = 20;
 const TOTAL_BYTES_BASE62: usize = 27;
+const TOTAL_BYTES_HEX: usize = 40;
+const TOTAL_BYTES_BASE32: usize = 32;
+const TOTAL_BYTES_BASE58: usize = 28;
+
+const HEX_CHARS: &[u8; 16] = b"0123456789abcdef";
+
+const BASE_32_CROCKFORD_CHARS: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+const BASE_58_CHARS: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// How to handle timestamps outside of [`KSUID_MIN_TIMESTAMP`] to [`KSUID_MAX_TIMESTAMP`]
+///
+/// # Examples
+/// ```
+/// use svix_ksuid::*;
+///
+/// let ksuid = Ksuid::from_seconds_with(0, None, TimestampOverflow::Saturate).unwrap();
+/// assert_eq!(ksuid.timestamp_seconds(), KSUID_MIN_TIMESTAMP);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TimestampOverflow {
+    /// Keep the low 32 bits of the seconds since `KSUID_EPOCH`, like the reference
+    /// implementation. This is what [`KsuidLike::from_seconds`] does.
+    #[default]
+    Wrap,
+    /// Clamp to the closest supported timestamp
+    Saturate,
+    /// Fail with [`Error::TimestampOutOfRange`]
+    Error,
+}
+
+/// How to hash a ksuid when assigning it to a partition, see [`KsuidLike::partition_with`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PartitionHash {
+    /// 64-bit FNV-1a of the payload. The timestamp is left out, so that ksuids created around
+    /// the same time don't end up on the same partitions.
+    #[default]
+    Payload,
+    /// The Kafka Java client's default partitioner applied to the base62 string, i.e.
+    /// `toPositive(murmur2(utf8(base62))) % partitions`. Use this to match producers that use
+    /// the base62 ksuid as the record key.
+    Murmur2,
+}
+
+/// How to assign a ksuid to a shard, see [`KsuidLike::shard_id_with`]
+///
+/// Both hash the payload with 64-bit FNV-1a, like [`PartitionHash::Payload`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ShardAlgorithm {
+    /// The hash modulo the number of shards. Changing the number of shards moves almost all
+    /// ksuids to a different shard.
+    #[default]
+    Modulo,
+    /// Jump consistent hash (<https://arxiv.org/abs/1406.2294>). Going from `n` to `n + 1`
+    /// shards only moves `1 / (n + 1)` of the ksuids, all of them to the new shard.
+    JumpConsistent,
+}
+
+/// The resolution of the timestamp of a ksuid, see [`KsuidLike::precision`]
+///
+/// # Examples
+/// ```
+/// use std::time::Duration;
+/// use svix_ksuid::*;
+///
+/// assert_eq!(Ksuid::PRECISION, TimestampPrecision::Seconds);
+/// assert_eq!(KsuidMs::PRECISION.as_duration(), Duration::from_millis(4));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TimestampPrecision {
+    /// Whole seconds, used by [`Ksuid`]
+    Seconds,
+    /// Units of 4 milliseconds, used by [`KsuidMs`]
+    FourMillis,
+}
+
+impl TimestampPrecision {
+    /// Get the precision as the smallest difference between two timestamps
+    pub const fn as_duration(self) -> Duration {
+        match self {
+            Self::Seconds => Duration::from_secs(1),
+            Self::FourMillis => Duration::from_millis(4),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+    /// A generic error, e.g. an invalid ksuid or a failure to get entropy
+    Message(String),
+    /// The timestamp is outside of the range supported by ksuids
+    /// (see [`KSUID_MIN_TIMESTAMP`] and [`KSUID_MAX_TIMESTAMP`])
+    TimestampOutOfRange,
+    /// The timestamp is further in the future than allowed, see
+    /// [`KsuidLike::validate_not_future`]
+    TimestampInFuture {
+        /// How far ahead of the current time the timestamp is
+        ahead: Duration,
+    },
+    /// The timestamp is further in the past than allowed, see [`KsuidLike::validate_within`]
+    TimestampTooOld {
+        /// How far behind the current time the timestamp is
+        age: Duration,
+    },
+}
 
-#[derive(Debug)]
-pub struct Error(String);
+impl Error {
+    /// Create a new error with the provided message
+    ///
+    /// This is useful for returning errors from custom [`EntropySource`] implementations.
+    pub fn new<M: Into<String>>(message: M) -> Self {
+        Self::Message(message.into())
+    }
+}
 
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        self.0.fmt(f)
+        match self {
+            Self::Message(message) => message.fmt(f),
+            Self::TimestampOutOfRange => write!(
+                f,
+                "Timestamp is out of the supported range ({} to {})",
+                KSUID_MIN_TIMESTAMP, KSUID_MAX_TIMESTAMP
+            ),
+            Self::TimestampInFuture { ahead } => {
+                write!(f, "Timestamp is {:?} in the future", ahead)
+            }
+            Self::TimestampTooOld { age } => write!(f, "Timestamp is {:?} old", age),
+        }
     }
 }
 
@@ -93,10 +425,201 @@ impl error::Error for Error {
     }
 }
 
+/// Check whether a string is a valid base62 ksuid, without decoding it
+///
+/// This checks the length, the alphabet, and that the value fits in 20 bytes.
+///
+/// # Examples
+/// ```
+/// use svix_ksuid::*;
+///
+/// assert!(is_valid_ksuid("1srOrx2ZWZBpBUvZwXKQmoEYga2"));
+/// assert!(!is_valid_ksuid("1srOrx2ZWZBpBUvZwXKQmoEYga"));
+/// assert!(!is_valid_ksuid("1srOrx2ZWZBpBUvZwXKQmoEYg-2"));
+/// assert!(!is_valid_ksuid("zzzzzzzzzzzzzzzzzzzzzzzzzzz"));
+/// ```
+pub fn is_valid_ksuid(s: &str) -> bool {
+    // The alphabet is in ascending order, so for fixed length strings comparing the
+    // strings is the same as comparing the values.
+    s.len() == TOTAL_BYTES_BASE62 && s.bytes().all(|c| c.is_ascii_alphanumeric()) && s <= MAX_BASE62
+}
+
+/// Get the sort key of a ksuid by its timestamp alone, for use with `sort_by_key`
+///
+/// The payload is ignored, so with a stable sort ksuids with the same timestamp keep their
+/// original order. See [`KsuidLike::cmp_timestamp`].
+///
+/// # Examples
+/// ```
+/// use svix_ksuid::*;
+///
+/// let first = Ksuid::from_seconds(Some(1_621_627_443), Some(&[0xFF; 16]));
+/// let second = Ksuid::from_seconds(Some(1_621_627_443), Some(&[0; 16]));
+/// let third = Ksuid::from_seconds(Some(1_621_627_444), None);
+/// let mut ids = vec![third, first, second];
+/// ids.sort_by_key(by_timestamp);
+/// assert_eq!(ids, [first, second, third]);
+/// ```
+pub fn by_timestamp<K: KsuidLike>(ksuid: &K) -> u64 {
+    ksuid.timestamp_raw_u64()
+}
+
+/// Get a compiled regex of [`KSUID_PATTERN`]
+///
+/// # Examples
+/// ```
+/// use svix_ksuid::*;
+///
+/// assert!(ksuid_regex().is_match("1srOrx2ZWZBpBUvZwXKQmoEYga2"));
+/// ```
+#[cfg(feature = "regex")]
+pub fn ksuid_regex() -> regex::Regex {
+    static REGEX: std::sync::OnceLock<regex::Regex> = std::sync::OnceLock::new();
+    REGEX
+        .get_or_init(|| regex::Regex::new(KSUID_PATTERN).unwrap())
+        .clone()
+}
+
+/// The representations a ksuid can be loaded from
+///
+/// See [`KsuidLike::parse_any`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KsuidFormat {
+    /// The raw 20 bytes
+    Bytes,
+    /// Base62, the canonical string representation
+    Base62,
+    /// Base58 using the Bitcoin alphabet
+    Base58,
+    /// Crockford's base32
+    Base32Crockford,
+    /// Hex (case-insensitive)
+    Hex,
+}
+
+/// Add two 160 bit big endian integers, wrapping on overflow
+fn add_bytes(a: &[u8; TOTAL_BYTES], b: &[u8; TOTAL_BYTES]) -> [u8; TOTAL_BYTES] {
+    let mut out = [0u8; TOTAL_BYTES];
+    let mut carry = 0u16;
+    for i in (0..TOTAL_BYTES).rev() {
+        let sum = u16::from(a[i]) + u16::from(b[i]) + carry;
+        out[i] = sum as u8;
+        carry = sum >> 8;
+    }
+    out
+}
+
+/// Subtract two 160 bit big endian integers, wrapping on underflow
+fn sub_bytes(a: &[u8; TOTAL_BYTES], b: &[u8; TOTAL_BYTES]) -> [u8; TOTAL_BYTES] {
+    let mut out = [0u8; TOTAL_BYTES];
+    let mut borrow = false;
+    for i in (0..TOTAL_BYTES).rev() {
+        let (difference, overflowed1) = a[i].overflowing_sub(b[i]);
+        let (difference, overflowed2) = difference.overflowing_sub(u8::from(borrow));
+        out[i] = difference;
+        borrow = overflowed1 || overflowed2;
+    }
+    out
+}
+
+/// A uniformly random 160 bit big endian integer in `1..n`, `n` must be larger than one
+#[cfg(feature = "getrandom")]
+fn random_nonzero_below(n: &[u8; TOTAL_BYTES]) -> [u8; TOTAL_BYTES] {
+    let first = n.iter().position(|&b| b != 0).unwrap();
+    let mask = u8::MAX >> n[first].leading_zeros();
+    // Rejection sampling, succeeds with a probability of at least 1/2 on every iteration
+    loop {
+        let mut candidate = [0u8; TOTAL_BYTES];
+        entropy::fill_random(&mut candidate[first..]);
+        candidate[first] &= mask;
+        if candidate < *n && candidate != [0; TOTAL_BYTES] {
+            return candidate;
+        }
+    }
+}
+
+fn fmt_hex(bytes: &[u8; TOTAL_BYTES], f: &mut fmt::Formatter<'_>, upper: bool) -> fmt::Result {
+    let mut buf = [0u8; TOTAL_BYTES_HEX];
+    for (chunk, byte) in buf.chunks_exact_mut(2).zip(bytes) {
+        chunk[0] = HEX_CHARS[(byte >> 4) as usize];
+        chunk[1] = HEX_CHARS[(byte & 0x0F) as usize];
+    }
+    if upper {
+        buf.make_ascii_uppercase();
+    }
+    // Handles the `0x` prefix of `{:#x}`, as well as width, fill, alignment and zero padding
+    f.pad_integral(true, "0x", std::str::from_utf8(&buf).unwrap())
+}
+
+#[cfg(feature = "time")]
 fn timestamp_millis(dt: &OffsetDateTime) -> i64 {
     (dt.unix_timestamp_nanos() / 1_000_000) as i64
 }
 
+#[cfg(feature = "getrandom")]
+fn check_timestamp(seconds: i64) -> Result<(), Error> {
+    raw_seconds(seconds, TimestampOverflow::Error).map(|_| ())
+}
+
+/// Convert a unix timestamp (in seconds) to seconds since `KSUID_EPOCH`
+fn raw_seconds(seconds: i64, overflow: TimestampOverflow) -> Result<u32, Error> {
+    let seconds = match overflow {
+        TimestampOverflow::Saturate => seconds.clamp(KSUID_MIN_TIMESTAMP, KSUID_MAX_TIMESTAMP),
+        _ => seconds,
+    };
+    // Only wraps for timestamps that are out of range anyway
+    let offset = seconds.wrapping_sub(KSUID_EPOCH);
+    match (u32::try_from(offset), overflow) {
+        (Ok(raw), _) => Ok(raw),
+        // Keep the low 32 bits, like the reference implementation
+        (Err(_), TimestampOverflow::Wrap) => Ok(offset as u32),
+        (Err(_), _) => Err(Error::TimestampOutOfRange),
+    }
+}
+
+/// Convert a unix timestamp in milliseconds to the raw `KsuidMs` timestamp
+fn raw_millis(millis: i64, overflow: TimestampOverflow) -> Result<u64, Error> {
+    let millis = match overflow {
+        TimestampOverflow::Saturate => millis.clamp(
+            KSUID_MIN_TIMESTAMP * 1_000,
+            KSUID_MAX_TIMESTAMP * 1_000 + 999,
+        ),
+        _ => millis,
+    };
+    let seconds = raw_seconds(millis.div_euclid(1_000), overflow)?;
+    // Always in 0..250
+    let fraction = (millis.rem_euclid(1_000) >> 2) as u64;
+    Ok((u64::from(seconds) << 8) | fraction)
+}
+
+/// Create a ksuid from a unix timestamp in milliseconds, truncated to the precision of `K`,
+/// and an explicit payload
+fn from_millis_and_payload<K: KsuidLike + ?Sized>(
+    millis: i64,
+    payload: &[u8],
+    overflow: TimestampOverflow,
+) -> Result<K::Type, Error> {
+    let raw = match K::PRECISION {
+        TimestampPrecision::Seconds => u64::from(raw_seconds(millis.div_euclid(1_000), overflow)?),
+        TimestampPrecision::FourMillis => raw_millis(millis, overflow)?,
+    };
+    let mut buf = [0u8; TOTAL_BYTES];
+    BigEndian::write_uint(&mut buf[..K::TIMESTAMP_BYTES], raw, K::TIMESTAMP_BYTES);
+    buf[K::TIMESTAMP_BYTES..].copy_from_slice(payload);
+    Ok(K::from_bytes(buf))
+}
+
+fn now_millis() -> i64 {
+    system_time_millis(SystemTime::now())
+}
+
+fn system_time_millis(t: SystemTime) -> i64 {
+    match t.duration_since(UNIX_EPOCH) {
+        Ok(d) => i64::try_from(d.as_millis()).unwrap_or(i64::MAX),
+        Err(e) => i64::try_from(e.duration().as_millis()).map_or(i64::MIN, |ms| -ms),
+    }
+}
+
 /// K-Sortable Unique ID Trait
 ///
 /// This trait is implemented by all of the ksuid variants
@@ -119,6 +642,8 @@ pub trait KsuidLike {
     const TIMESTAMP_BYTES: usize;
     /// The number of bytes used for payload (`TIMESTAMP_BYTES + PAYLOAD_BYTES == 20`)
     const PAYLOAD_BYTES: usize;
+    /// The resolution of the timestamp
+    const PRECISION: TimestampPrecision;
 
     /// Creates new Ksuid with specified timestamp (DateTime) and optional payload
     ///
@@ -128,18 +653,229 @@ pub trait KsuidLike {
     ///
     /// let ksuid = Ksuid::new(None, None);
     /// ```
+    #[cfg(all(feature = "time", feature = "getrandom"))]
     fn new(timestamp: Option<OffsetDateTime>, payload: Option<&[u8]>) -> Self::Type;
 
     /// Creates new Ksuid with specified timestamp (in seconds) and optional payload
     ///
+    /// Timestamps outside of [`KSUID_MIN_TIMESTAMP`] to [`KSUID_MAX_TIMESTAMP`] wrap around,
+    /// use [`try_from_seconds`](KsuidLike::try_from_seconds) to reject them instead.
+    ///
     /// # Examples
     /// ```
     /// use svix_ksuid::*;
     ///
     /// let ksuid = Ksuid::from_seconds(Some(1_621_627_443), None);
     /// ```
+    #[cfg(feature = "getrandom")]
     fn from_seconds(timestamp: Option<i64>, payload: Option<&[u8]>) -> Self::Type;
 
+    /// Creates new Ksuid with specified timestamp (in seconds) and optional payload, failing
+    /// with [`Error::TimestampOutOfRange`] if the timestamp is outside of
+    /// [`KSUID_MIN_TIMESTAMP`] to [`KSUID_MAX_TIMESTAMP`]
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    ///
+    /// assert!(Ksuid::try_from_seconds(1_621_627_443, None).is_ok());
+    /// assert_eq!(
+    ///     Ksuid::try_from_seconds(0, None).unwrap_err(),
+    ///     Error::TimestampOutOfRange
+    /// );
+    /// ```
+    #[cfg(feature = "getrandom")]
+    fn try_from_seconds(timestamp: i64, payload: Option<&[u8]>) -> Result<Self::Type, Error> {
+        Self::from_seconds_with(timestamp, payload, TimestampOverflow::Error)
+    }
+
+    /// Creates new Ksuid with specified timestamp (in seconds) and optional payload, handling
+    /// timestamps outside of [`KSUID_MIN_TIMESTAMP`] to [`KSUID_MAX_TIMESTAMP`] as specified
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    ///
+    /// let ksuid = Ksuid::from_seconds_with(i64::MAX, None, TimestampOverflow::Saturate).unwrap();
+    /// assert_eq!(ksuid.timestamp_seconds(), KSUID_MAX_TIMESTAMP);
+    /// assert!(Ksuid::from_seconds_with(i64::MAX, None, TimestampOverflow::Error).is_err());
+    /// ```
+    #[cfg(feature = "getrandom")]
+    fn from_seconds_with(
+        timestamp: i64,
+        payload: Option<&[u8]>,
+        overflow: TimestampOverflow,
+    ) -> Result<Self::Type, Error>;
+
+    /// Creates new Ksuid with specified timestamp (DateTime) and optional payload, failing
+    /// with [`Error::TimestampOutOfRange`] if the timestamp is outside of
+    /// [`KSUID_MIN_TIMESTAMP`] to [`KSUID_MAX_TIMESTAMP`]
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    /// use time::OffsetDateTime;
+    ///
+    /// assert!(Ksuid::try_new(OffsetDateTime::now_utc(), None).is_ok());
+    /// assert!(Ksuid::try_new(OffsetDateTime::UNIX_EPOCH, None).is_err());
+    /// ```
+    #[cfg(all(feature = "time", feature = "getrandom"))]
+    fn try_new(timestamp: OffsetDateTime, payload: Option<&[u8]>) -> Result<Self::Type, Error> {
+        check_timestamp(timestamp.unix_timestamp())?;
+        Ok(Self::new(Some(timestamp), payload))
+    }
+
+    /// Creates new Ksuid with specified timestamp (`SystemTime`) and optional payload
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    /// use std::time::{Duration, UNIX_EPOCH};
+    ///
+    /// let timestamp = UNIX_EPOCH + Duration::from_secs(1_621_627_443);
+    /// let ksuid = Ksuid::from_system_time(timestamp, None);
+    /// assert_eq!(ksuid.system_time(), timestamp);
+    /// ```
+    #[cfg(feature = "getrandom")]
+    fn from_system_time(timestamp: SystemTime, payload: Option<&[u8]>) -> Self::Type;
+
+    /// Creates new Ksuid with specified timestamp (`SystemTime`) and optional payload, failing
+    /// with [`Error::TimestampOutOfRange`] if the timestamp is outside of
+    /// [`KSUID_MIN_TIMESTAMP`] to [`KSUID_MAX_TIMESTAMP`]
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    /// use std::time::{SystemTime, UNIX_EPOCH};
+    ///
+    /// assert!(Ksuid::try_from_system_time(SystemTime::now(), None).is_ok());
+    /// assert!(Ksuid::try_from_system_time(UNIX_EPOCH, None).is_err());
+    /// ```
+    #[cfg(feature = "getrandom")]
+    fn try_from_system_time(
+        timestamp: SystemTime,
+        payload: Option<&[u8]>,
+    ) -> Result<Self::Type, Error> {
+        check_timestamp(system_time_millis(timestamp).div_euclid(1_000))?;
+        Ok(Self::from_system_time(timestamp, payload))
+    }
+
+    /// Creates new Ksuid with specified timestamp (`SystemTime`) and payload
+    ///
+    /// Unlike `from_system_time()` the payload is required, so this is also available without
+    /// the `getrandom` feature. Timestamps that are out of range wrap around.
+    ///
+    /// # Panics
+    /// If `payload` isn't [`KsuidLike::PAYLOAD_BYTES`] long.
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    /// use std::time::{Duration, UNIX_EPOCH};
+    ///
+    /// let timestamp = UNIX_EPOCH + Duration::from_secs(1_621_627_443);
+    /// let ksuid = Ksuid::from_system_time_and_payload(timestamp, &[7; 16]);
+    /// assert_eq!(ksuid.system_time(), timestamp);
+    /// assert_eq!(ksuid.payload(), &[7; 16]);
+    /// ```
+    fn from_system_time_and_payload(timestamp: SystemTime, payload: &[u8]) -> Self::Type {
+        let millis = system_time_millis(timestamp);
+        from_millis_and_payload::<Self>(millis, payload, TimestampOverflow::Wrap).unwrap()
+    }
+
+    /// Creates new Ksuid with specified timestamp (DateTime) and an **insecure** random payload
+    ///
+    /// The payload comes from a fast, non-cryptographic random number generator that is seeded
+    /// once per thread. This is useful for generating large amounts of ksuids (e.g. test data
+    /// or trace ids), but the payload is predictable, so never use this for anything that
+    /// needs to be secret or unguessable.
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    ///
+    /// let ksuid = Ksuid::new_insecure(None);
+    /// ```
+    #[cfg(feature = "insecure-fast-rng")]
+    fn new_insecure(timestamp: Option<OffsetDateTime>) -> Self::Type {
+        let mut payload = [0u8; TOTAL_BYTES];
+        let payload = &mut payload[..Self::PAYLOAD_BYTES];
+        rng::fill_insecure(payload);
+        Self::new(timestamp, Some(payload))
+    }
+
+    /// Creates new Ksuid with specified timestamp (DateTime) and a payload from a [`SeededRng`]
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    ///
+    /// let mut rng = SeededRng::from_seed([7u8; 32]);
+    /// let ksuid = Ksuid::new_seeded(None, &mut rng);
+    /// ```
+    #[cfg(feature = "chacha")]
+    fn new_seeded(timestamp: Option<OffsetDateTime>, rng: &mut SeededRng) -> Self::Type {
+        // Seeded generators never fail
+        Self::new_with_entropy(timestamp.map(SystemTime::from), rng).unwrap()
+    }
+
+    /// Creates new Ksuid with specified timestamp (`SystemTime`, now if `None`) and a payload
+    /// from the provided entropy source
+    ///
+    /// This is available without the `getrandom` feature.
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    ///
+    /// let ksuid = Ksuid::new_with_entropy(None, &mut OsEntropy).unwrap();
+    /// ```
+    fn new_with_entropy<E: EntropySource + ?Sized>(
+        timestamp: Option<SystemTime>,
+        source: &mut E,
+    ) -> Result<Self::Type, Error> {
+        let mut payload = [0u8; TOTAL_BYTES];
+        let payload = &mut payload[..Self::PAYLOAD_BYTES];
+        entropy::fill(source, payload)?;
+        let timestamp = timestamp.unwrap_or_else(SystemTime::now);
+        Ok(Self::from_system_time_and_payload(timestamp, payload))
+    }
+
+    /// Creates new Ksuid with specified timestamp (chrono `DateTime<Utc>`) and optional payload
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let timestamp = Utc.timestamp_opt(1_621_627_443, 0).unwrap();
+    /// let ksuid = Ksuid::new_chrono(Some(timestamp), None);
+    /// assert_eq!(ksuid.timestamp_chrono(), timestamp);
+    /// ```
+    #[cfg(all(feature = "chrono", feature = "getrandom"))]
+    fn new_chrono(
+        timestamp: Option<chrono::DateTime<chrono::Utc>>,
+        payload: Option<&[u8]>,
+    ) -> Self::Type {
+        let timestamp = timestamp.map_or_else(SystemTime::now, SystemTime::from);
+        Self::from_system_time(timestamp, payload)
+    }
+
+    /// Creates new Ksuid with specified timestamp (jiff `Timestamp`) and optional payload
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    ///
+    /// let timestamp = jiff::Timestamp::from_second(1_621_627_443).unwrap();
+    /// let ksuid = Ksuid::from_jiff(timestamp, None);
+    /// assert_eq!(ksuid.timestamp_jiff(), timestamp);
+    /// ```
+    #[cfg(all(feature = "jiff", feature = "getrandom"))]
+    fn from_jiff(timestamp: jiff::Timestamp, payload: Option<&[u8]>) -> Self::Type {
+        Self::from_system_time(timestamp.into(), payload)
+    }
+
     /// Get the timestamp portion of the ksuid
     ///
     /// # Examples
@@ -151,8 +887,21 @@ pub trait KsuidLike {
     /// let ksuid = Ksuid::new(Some(now), None);
     /// assert_eq!(now.unix_timestamp(), ksuid.timestamp().unix_timestamp());
     /// ```
+    #[cfg(feature = "time")]
     fn timestamp(&self) -> OffsetDateTime;
 
+    /// Get the timestamp portion of the ksuid as a `SystemTime`
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    /// use std::time::{Duration, UNIX_EPOCH};
+    ///
+    /// let ksuid = KsuidMs::from_millis(Some(1_621_627_443_124), None);
+    /// assert_eq!(ksuid.system_time(), UNIX_EPOCH + Duration::from_millis(1_621_627_443_124));
+    /// ```
+    fn system_time(&self) -> SystemTime;
+
     /// Get the timestamp portion of the ksuid in seconds
     ///
     /// # Examples
@@ -163,106 +912,1003 @@ pub trait KsuidLike {
     /// let ksuid = Ksuid::from_seconds(Some(timestamp), None);
     /// assert_eq!(ksuid.timestamp_seconds(), timestamp);
     /// ```
-    fn timestamp_seconds(&self) -> i64 {
-        self.timestamp().unix_timestamp()
+    fn timestamp_seconds(&self) -> i64;
+
+    /// Get the raw timestamp value of the ksuid, widened to a `u64`
+    ///
+    /// This is the same as the inherent `timestamp_raw()` of [`Ksuid`] and [`KsuidMs`], for
+    /// code that is generic over the ksuid type. It's the first [`KsuidLike::TIMESTAMP_BYTES`]
+    /// bytes read as a big-endian integer.
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    ///
+    /// fn raw<K: KsuidLike>(ksuid: &K) -> u64 {
+    ///     ksuid.timestamp_raw_u64()
+    /// }
+    ///
+    /// let ksuid = Ksuid::new(None, None);
+    /// assert_eq!(raw(&ksuid), ksuid.timestamp_raw() as u64);
+    /// let ksuid = KsuidMs::new(None, None);
+    /// assert_eq!(raw(&ksuid), ksuid.timestamp_raw());
+    /// ```
+    fn timestamp_raw_u64(&self) -> u64 {
+        BigEndian::read_uint(self.bytes(), Self::TIMESTAMP_BYTES)
     }
 
-    /// Get the payload portion of the ksuid
+    /// Get the resolution of the timestamp, i.e. [`KsuidLike::PRECISION`]
+    ///
+    /// Ksuids created within the same unit of precision aren't ordered by time, only by their
+    /// payload.
     ///
     /// # Examples
     /// ```
+    /// use std::time::Duration;
     /// use svix_ksuid::*;
     ///
-    /// let buf = b"1234567890ABCDEF";
-    /// let ksuid = Ksuid::new(None, Some(&buf[..]));
-    /// assert_eq!(ksuid.payload(), &buf[..]);
+    /// fn precise_enough<K: KsuidLike>(ksuid: &K) -> bool {
+    ///     ksuid.precision().as_duration() <= Duration::from_millis(10)
+    /// }
+    ///
+    /// assert!(!precise_enough(&Ksuid::new(None, None)));
+    /// assert!(precise_enough(&KsuidMs::new(None, None)));
     /// ```
-    fn payload(&self) -> &[u8] {
-        &self.bytes()[Self::TIMESTAMP_BYTES..]
+    fn precision(&self) -> TimestampPrecision {
+        Self::PRECISION
     }
 
-    /// Create a new ksuid from bytes
+    /// Compare the timestamps of two ksuids, ignoring their payloads
+    ///
+    /// This orders ksuids the same way as `cmp` does, except that ksuids with the same
+    /// timestamp are equal.
     ///
     /// # Examples
     /// ```
+    /// use std::cmp::Ordering;
     /// use svix_ksuid::*;
     ///
-    /// let bytes = [12u8; 20];
-    /// let ksuid = Ksuid::from_bytes(bytes.clone());
-    /// assert_eq!(&bytes, ksuid.bytes());
+    /// let a = Ksuid::from_seconds(Some(1_621_627_443), Some(&[0xFF; 16]));
+    /// let b = Ksuid::from_seconds(Some(1_621_627_443), Some(&[0; 16]));
+    /// assert_eq!(a.cmp_timestamp(&b), Ordering::Equal);
+    /// assert_eq!(a.cmp(&b), Ordering::Greater);
     /// ```
-    fn from_bytes(bytes: [u8; TOTAL_BYTES]) -> Self::Type;
+    fn cmp_timestamp(&self, other: &Self) -> Ordering {
+        self.bytes()[..Self::TIMESTAMP_BYTES].cmp(&other.bytes()[..Self::TIMESTAMP_BYTES])
+    }
 
-    /// Get the ksuid as bytes
+    /// Get the time between the timestamps of `earlier` and this ksuid
+    ///
+    /// The duration is negative if `earlier` is actually later. Like the timestamps, it's only
+    /// accurate to [`KsuidLike::PRECISION`].
     ///
     /// # Examples
     /// ```
     /// use svix_ksuid::*;
+    /// use time::Duration;
     ///
-    /// let bytes = [12u8; 20];
-    /// let ksuid = Ksuid::from_bytes(bytes.clone());
-    /// assert_eq!(&bytes, ksuid.bytes());
+    /// let event = KsuidMs::from_millis(Some(1_621_627_443_124), None);
+    /// let ack = KsuidMs::from_millis(Some(1_621_627_443_624), None);
+    /// assert_eq!(ack.duration_since(&event), Duration::milliseconds(500));
+    /// assert_eq!(event.duration_since(&ack), Duration::milliseconds(-500));
     /// ```
-    fn bytes(&self) -> &[u8; TOTAL_BYTES];
+    #[cfg(feature = "time")]
+    fn duration_since(&self, earlier: &Self) -> time::Duration {
+        self.timestamp() - earlier.timestamp()
+    }
 
-    /// Convert the Ksuid to base62
+    /// Get the time since the timestamp of the ksuid, e.g. the age of a queued item
+    ///
+    /// The duration is negative if the timestamp is in the future, e.g. because of clock skew
+    /// between the machine that created the ksuid and this one.
     ///
     /// # Examples
     /// ```
     /// use svix_ksuid::*;
-    /// use byteorder::{ByteOrder, BigEndian};
+    /// use time::{Duration, OffsetDateTime};
     ///
-    /// let mut buf = [0u8; 16];
-    /// BigEndian::write_u128(&mut buf, 45419194335837378647185401984346151808);
-    /// let ksuid = Ksuid::new_raw(1643290698, Some(&buf));
-    /// let base62 = ksuid.to_string();
-    /// assert_eq!(base62, "DyU8bFOBPZ4LjvsfN0qywt2LjmK");
+    /// let ksuid = KsuidMs::new(Some(OffsetDateTime::now_utc() - Duration::minutes(5)), None);
+    /// assert!(ksuid.elapsed() >= Duration::minutes(5));
     ///
+    /// let ksuid = KsuidMs::new(Some(OffsetDateTime::now_utc() + Duration::minutes(5)), None);
+    /// assert!(ksuid.elapsed().is_negative());
     /// ```
-    fn to_base62(&self) -> String {
-        format!(
-            "{:0>27}",
-            base_encode::to_string(self.bytes(), 62, BASE_62_CHARS).unwrap()
-        )
+    #[cfg(feature = "time")]
+    fn elapsed(&self) -> time::Duration {
+        OffsetDateTime::now_utc() - self.timestamp()
     }
 
-    /// Load a base62 representation to a Ksuid
+    /// Check that the timestamp of the ksuid is at most `tolerance` ahead of the current time
+    ///
+    /// Fails with [`Error::TimestampInFuture`] otherwise, e.g. for forged ksuids or ones minted
+    /// on a machine with a badly skewed clock.
     ///
     /// # Examples
     /// ```
+    /// use std::time::{Duration, SystemTime};
     /// use svix_ksuid::*;
-    /// use std::str::FromStr;
     ///
-    /// let ksuid = Ksuid::from_str("24CtFf3hyVZHdSkQy0nMBa1OjOA").unwrap();
-    /// assert_eq!(ksuid.to_string(), "24CtFf3hyVZHdSkQy0nMBa1OjOA");
+    /// let ksuid = Ksuid::new(None, None);
+    /// assert!(ksuid.validate_not_future(Duration::from_secs(5)).is_ok());
     ///
+    /// let ksuid = Ksuid::from_system_time(SystemTime::now() + Duration::from_secs(60), None);
+    /// assert!(matches!(
+    ///     ksuid.validate_not_future(Duration::from_secs(5)),
+    ///     Err(Error::TimestampInFuture { .. })
+    /// ));
     /// ```
-    fn from_base62(s: &str) -> Result<Self::Type, Error> {
-        if s.len() != TOTAL_BYTES_BASE62 {
-            return Err(Error(format!(
+    fn validate_not_future(&self, tolerance: Duration) -> Result<(), Error> {
+        match self.system_time().duration_since(SystemTime::now()) {
+            Ok(ahead) if ahead > tolerance => Err(Error::TimestampInFuture { ahead }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Check that the timestamp of the ksuid is within `window` of the current time, in either
+    /// direction
+    ///
+    /// Fails with [`Error::TimestampTooOld`] or [`Error::TimestampInFuture`] otherwise. This is
+    /// useful for rejecting replayed messages. Combine it with
+    /// [`KsuidLike::validate_not_future`] to allow less skew into the future than into the past.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::time::{Duration, SystemTime};
+    /// use svix_ksuid::*;
+    ///
+    /// let window = Duration::from_secs(5 * 60);
+    /// let ksuid = Ksuid::new(None, None);
+    /// assert!(ksuid.validate_within(window).is_ok());
+    ///
+    /// let ksuid = Ksuid::from_system_time(SystemTime::now() - Duration::from_secs(3600), None);
+    /// assert!(matches!(
+    ///     ksuid.validate_within(window),
+    ///     Err(Error::TimestampTooOld { .. })
+    /// ));
+    /// ```
+    fn validate_within(&self, window: Duration) -> Result<(), Error> {
+        match SystemTime::now().duration_since(self.system_time()) {
+            Ok(age) if age > window => Err(Error::TimestampTooOld { age }),
+            Ok(_) => Ok(()),
+            Err(err) if err.duration() > window => Err(Error::TimestampInFuture {
+                ahead: err.duration(),
+            }),
+            Err(_) => Ok(()),
+        }
+    }
+
+    /// Get the timestamp portion of the ksuid as a chrono `DateTime<Utc>`
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    ///
+    /// let ksuid = KsuidMs::from_millis(Some(1_621_627_443_124), None);
+    /// assert_eq!(ksuid.timestamp_chrono().timestamp_millis(), 1_621_627_443_124);
+    /// ```
+    #[cfg(feature = "chrono")]
+    fn timestamp_chrono(&self) -> chrono::DateTime<chrono::Utc> {
+        self.system_time().into()
+    }
+
+    /// Get the timestamp portion of the ksuid as a jiff `Timestamp`
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    ///
+    /// let ksuid = KsuidMs::from_millis(Some(1_621_627_443_124), None);
+    /// assert_eq!(ksuid.timestamp_jiff().as_millisecond(), 1_621_627_443_124);
+    /// ```
+    #[cfg(feature = "jiff")]
+    fn timestamp_jiff(&self) -> jiff::Timestamp {
+        // Ksuid timestamps are always within the range supported by jiff
+        self.system_time().try_into().unwrap()
+    }
+
+    /// Get the payload portion of the ksuid
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    ///
+    /// let buf = b"1234567890ABCDEF";
+    /// let ksuid = Ksuid::new(None, Some(&buf[..]));
+    /// assert_eq!(ksuid.payload(), &buf[..]);
+    /// ```
+    fn payload(&self) -> &[u8] {
+        &self.bytes()[Self::TIMESTAMP_BYTES..]
+    }
+
+    /// Get the region byte of a ksuid created by a [`Generator::with_region`] generator
+    ///
+    /// This is the first byte of the payload. For other ksuids it's just a random byte.
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    ///
+    /// let ksuid = Ksuid::from_seconds(None, Some(&[7; 16]));
+    /// assert_eq!(ksuid.region(), 7);
+    /// ```
+    fn region(&self) -> u8 {
+        self.payload()[0]
+    }
+
+    /// Create a new ksuid from bytes
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    ///
+    /// let bytes = [12u8; 20];
+    /// let ksuid = Ksuid::from_bytes(bytes.clone());
+    /// assert_eq!(&bytes, ksuid.bytes());
+    /// ```
+    fn from_bytes(bytes: [u8; TOTAL_BYTES]) -> Self::Type;
+
+    /// Get the ksuid as bytes
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    ///
+    /// let bytes = [12u8; 20];
+    /// let ksuid = Ksuid::from_bytes(bytes.clone());
+    /// assert_eq!(&bytes, ksuid.bytes());
+    /// ```
+    fn bytes(&self) -> &[u8; TOTAL_BYTES];
+
+    /// Consume the ksuid and return its bytes
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    ///
+    /// let bytes = [12u8; 20];
+    /// let ksuid = Ksuid::from_bytes(bytes);
+    /// assert_eq!(bytes, ksuid.into_bytes());
+    /// ```
+    fn into_bytes(self) -> [u8; TOTAL_BYTES]
+    where
+        Self: Sized,
+    {
+        *self.bytes()
+    }
+
+    /// Creates the nil ksuid, with all bytes set to zero
+    ///
+    /// This is useful as a sentinel for an "unset" ksuid.
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    ///
+    /// let ksuid = Ksuid::nil();
+    /// assert_eq!(ksuid.bytes(), &[0u8; 20]);
+    /// assert!(ksuid.is_nil());
+    /// ```
+    fn nil() -> Self::Type {
+        Self::from_bytes([0; TOTAL_BYTES])
+    }
+
+    /// Check whether this is the nil ksuid, i.e. all of its bytes are zero
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    ///
+    /// assert!(KsuidMs::nil().is_nil());
+    /// assert!(!KsuidMs::new(None, None).is_nil());
+    /// ```
+    fn is_nil(&self) -> bool {
+        self.bytes() == &[0; TOTAL_BYTES]
+    }
+
+    /// Add `offset` to the ksuid, treating its bytes as a 160 bit big endian integer
+    ///
+    /// Returns `None` on overflow. Note that large offsets carry over into the timestamp.
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    ///
+    /// let ksuid = Ksuid::from_bytes([0u8; 20]);
+    /// let next = ksuid.checked_add(256).unwrap();
+    /// assert_eq!(&next.bytes()[18..], &[1, 0]);
+    /// assert!(Ksuid::MAX.checked_add(1).is_none());
+    /// ```
+    fn checked_add(&self, offset: u64) -> Option<Self::Type> {
+        let mut bytes = *self.bytes();
+        let mut carry = offset;
+        for byte in bytes.iter_mut().rev() {
+            if carry == 0 {
+                break;
+            }
+            let sum = u64::from(*byte) + (carry & 0xFF);
+            *byte = sum as u8;
+            carry = (carry >> 8) + (sum >> 8);
+        }
+        (carry == 0).then(|| Self::from_bytes(bytes))
+    }
+
+    /// Subtract `offset` from the ksuid, treating its bytes as a 160 bit big endian integer
+    ///
+    /// Returns `None` on underflow. Note that large offsets borrow from the timestamp.
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    ///
+    /// let ksuid = Ksuid::MAX.checked_sub(255).unwrap();
+    /// assert_eq!(&ksuid.bytes()[18..], &[0xFF, 0]);
+    /// assert!(Ksuid::nil().checked_sub(1).is_none());
+    /// ```
+    fn checked_sub(&self, offset: u64) -> Option<Self::Type> {
+        let mut bytes = *self.bytes();
+        let mut borrow = offset;
+        for byte in bytes.iter_mut().rev() {
+            if borrow == 0 {
+                break;
+            }
+            let (difference, overflowed) = byte.overflowing_sub((borrow & 0xFF) as u8);
+            *byte = difference;
+            borrow = (borrow >> 8) + u64::from(overflowed);
+        }
+        (borrow == 0).then(|| Self::from_bytes(bytes))
+    }
+
+    /// Creates a uniformly random ksuid strictly between `a` and `b`
+    ///
+    /// The order of `a` and `b` doesn't matter. Returns `None` if there's no ksuid between
+    /// them, i.e. if they're equal or adjacent.
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    ///
+    /// let a = Ksuid::from_seconds(Some(1_600_000_000), None);
+    /// let b = Ksuid::from_seconds(Some(1_700_000_000), None);
+    /// let ksuid = Ksuid::random_between(&a, &b).unwrap();
+    /// assert!(a < ksuid && ksuid < b);
+    /// ```
+    #[cfg(feature = "getrandom")]
+    fn random_between(a: &Self, b: &Self) -> Option<Self::Type> {
+        let (low, high) = if a.bytes() <= b.bytes() {
+            (a.bytes(), b.bytes())
+        } else {
+            (b.bytes(), a.bytes())
+        };
+        let difference = sub_bytes(high, low);
+        let mut one = [0u8; TOTAL_BYTES];
+        one[TOTAL_BYTES - 1] = 1;
+        if difference <= one {
+            return None;
+        }
+        let offset = random_nonzero_below(&difference);
+        Some(Self::from_bytes(add_bytes(low, &offset)))
+    }
+
+    /// Get a stable 64-bit fingerprint of the ksuid
+    ///
+    /// This is the 64-bit FNV-1a hash (offset basis `0xcbf29ce484222325`, prime
+    /// `0x100000001b3`) of the 20 raw bytes, and is guaranteed to stay the same across
+    /// versions, so it can be computed the same way in other languages. It's meant for bloom
+    /// filters, HyperLogLogs and deduplication, not for security.
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    ///
+    /// let ksuid = Ksuid::from_base62("1srOrx2ZWZBpBUvZwXKQmoEYga2").unwrap();
+    /// assert_eq!(ksuid.fingerprint64(), 0x7b69_b960_7a80_6196);
+    /// ```
+    fn fingerprint64(&self) -> u64 {
+        hash::fnv1a_64(self.bytes())
+    }
+
+    /// Split the ksuid into its raw timestamp word and payload as integers
+    ///
+    /// These are the first 4 and last 16 bytes read as big-endian integers, so comparing the
+    /// tuples orders them the same way as the ksuids. For [`KsuidMs`] the most significant
+    /// byte of the payload word is the sub-second part of the timestamp.
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    ///
+    /// let ksuid = Ksuid::new_raw(42, Some(&[1u8; 16]));
+    /// assert_eq!(ksuid.to_raw_parts(), (42, u128::from_be_bytes([1u8; 16])));
+    /// ```
+    fn to_raw_parts(&self) -> (u32, u128) {
+        let (timestamp, payload) = self.bytes().split_at(4);
+        (
+            BigEndian::read_u32(timestamp),
+            BigEndian::read_u128(payload),
+        )
+    }
+
+    /// Creates new ksuid from its raw timestamp word and payload as integers
+    ///
+    /// This is the inverse of [`KsuidLike::to_raw_parts`].
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    ///
+    /// let ksuid = Ksuid::new(None, None);
+    /// let (timestamp, payload) = ksuid.to_raw_parts();
+    /// assert_eq!(Ksuid::from_raw_parts(timestamp, payload), ksuid);
+    /// ```
+    fn from_raw_parts(timestamp: u32, payload: u128) -> Self::Type {
+        let mut buf = [0u8; TOTAL_BYTES];
+        let (timestamp_buf, payload_buf) = buf.split_at_mut(4);
+        BigEndian::write_u32(timestamp_buf, timestamp);
+        BigEndian::write_u128(payload_buf, payload);
+        Self::from_bytes(buf)
+    }
+
+    /// Get the partition, out of `partitions`, of the ksuid
+    ///
+    /// This is the 64-bit FNV-1a hash of the payload modulo `partitions`, and is guaranteed to
+    /// stay the same across versions. See [`KsuidLike::partition_with`] for matching the Kafka
+    /// Java client instead.
+    ///
+    /// # Panics
+    /// Panics if `partitions` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    ///
+    /// let ksuid = Ksuid::new(None, None);
+    /// assert!(ksuid.partition(12) < 12);
+    /// // Only depends on the payload
+    /// let later = Ksuid::new(Some(ksuid.timestamp() + time::Duration::days(1)), Some(ksuid.payload()));
+    /// assert_eq!(ksuid.partition(12), later.partition(12));
+    /// ```
+    fn partition(&self, partitions: u32) -> u32 {
+        self.partition_with(partitions, PartitionHash::Payload)
+    }
+
+    /// Get the partition, out of `partitions`, of the ksuid using the provided hash
+    ///
+    /// # Panics
+    /// Panics if `partitions` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    ///
+    /// let ksuid = Ksuid::from_base62("1srOrx2ZWZBpBUvZwXKQmoEYga2").unwrap();
+    /// assert!(ksuid.partition_with(12, PartitionHash::Murmur2) < 12);
+    /// ```
+    fn partition_with(&self, partitions: u32, hash: PartitionHash) -> u32 {
+        assert!(partitions > 0, "partitions must be greater than zero");
+        match hash {
+            PartitionHash::Payload => (hash::fnv1a_64(self.payload()) % partitions as u64) as u32,
+            PartitionHash::Murmur2 => {
+                let base62 = base62::encode(self.bytes());
+                (hash::murmur2(&base62) & 0x7fff_ffff) % partitions
+            }
+        }
+    }
+
+    /// Get the shard, out of `shards`, of the ksuid
+    ///
+    /// This uses [`ShardAlgorithm::Modulo`], and is guaranteed to stay the same across
+    /// versions, so all services derive the same shard from the same id.
+    ///
+    /// # Panics
+    /// Panics if `shards` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    ///
+    /// let ksuid = Ksuid::new(None, None);
+    /// assert!(ksuid.shard_id(16) < 16);
+    /// ```
+    fn shard_id(&self, shards: u16) -> u16 {
+        self.shard_id_with(shards, ShardAlgorithm::Modulo)
+    }
+
+    /// Get the shard, out of `shards`, of the ksuid using the provided algorithm
+    ///
+    /// # Panics
+    /// Panics if `shards` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    ///
+    /// let ksuid = Ksuid::new(None, None);
+    /// let before = ksuid.shard_id_with(16, ShardAlgorithm::JumpConsistent);
+    /// let after = ksuid.shard_id_with(17, ShardAlgorithm::JumpConsistent);
+    /// assert!(after == before || after == 16);
+    /// ```
+    fn shard_id_with(&self, shards: u16, algorithm: ShardAlgorithm) -> u16 {
+        assert!(shards > 0, "shards must be greater than zero");
+        let key = hash::fnv1a_64(self.payload());
+        match algorithm {
+            ShardAlgorithm::Modulo => (key % shards as u64) as u16,
+            ShardAlgorithm::JumpConsistent => hash::jump_consistent(key, shards as u32) as u16,
+        }
+    }
+
+    /// Compare two ksuids in constant time
+    ///
+    /// Use this instead of `==` when the ksuids are secrets (e.g. part of API keys), to avoid
+    /// leaking information through timing side channels.
+    ///
+    /// With the `subtle` feature the comparison is done by `subtle::ConstantTimeEq`. Without it
+    /// this is only best effort: the bytes are compared without branching, but nothing stops
+    /// the compiler from optimizing that into a short-circuiting comparison.
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    ///
+    /// let ksuid = Ksuid::new(None, None);
+    /// assert!(ksuid.ct_eq(&ksuid));
+    /// assert!(!ksuid.ct_eq(&Ksuid::new(None, None)));
+    /// ```
+    fn ct_eq(&self, other: &Self) -> bool {
+        #[cfg(feature = "subtle")]
+        {
+            ::subtle::ConstantTimeEq::ct_eq(&self.bytes()[..], &other.bytes()[..]).into()
+        }
+        #[cfg(not(feature = "subtle"))]
+        {
+            let diff = self
+                .bytes()
+                .iter()
+                .zip(other.bytes())
+                .fold(0u8, |acc, (a, b)| acc | (a ^ b));
+            // Makes short-circuiting less likely, but `black_box` guarantees nothing
+            std::hint::black_box(diff) == 0
+        }
+    }
+
+    /// Convert the Ksuid to base62
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    /// use byteorder::{ByteOrder, BigEndian};
+    ///
+    /// let mut buf = [0u8; 16];
+    /// BigEndian::write_u128(&mut buf, 45419194335837378647185401984346151808);
+    /// let ksuid = Ksuid::new_raw(1643290698, Some(&buf));
+    /// let base62 = ksuid.to_string();
+    /// assert_eq!(base62, "DyU8bFOBPZ4LjvsfN0qywt2LjmK");
+    ///
+    /// ```
+    fn to_base62(&self) -> String {
+        base62::as_str(&base62::encode(self.bytes())).to_owned()
+    }
+
+    /// Wrap the Ksuid so that it only shows its timestamp when formatted, see [`Redacted`]
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    ///
+    /// let ksuid = Ksuid::new(None, None);
+    /// assert!(format!("{:?}", ksuid.redacted()).ends_with("Z/****"));
+    /// assert!(!format!("{:?}", ksuid.redacted()).contains(&ksuid.to_string()));
+    /// ```
+    fn redacted(&self) -> Redacted<Self::Type> {
+        Redacted(Self::from_bytes(*self.bytes()))
+    }
+
+    /// Get an abbreviated display of the Ksuid for log lines, see [`ShortKsuid`]
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    ///
+    /// let ksuid = Ksuid::from_base62("1srOrx2ZWZBpBUvZwXKQmoEYga2").unwrap();
+    /// assert_eq!(format!("id={}", ksuid.short()), "id=1srOrx2Z");
+    /// ```
+    fn short(&self) -> ShortKsuid {
+        ShortKsuid::new(self.bytes())
+    }
+
+    /// Check whether the base62 representation of the Ksuid starts with `short`
+    ///
+    /// This matches the output of [`KsuidLike::short`] of any length against the full id.
+    /// Empty strings never match.
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    ///
+    /// let ksuid = Ksuid::from_base62("1srOrx2ZWZBpBUvZwXKQmoEYga2").unwrap();
+    /// assert!(ksuid.matches_short("1srOrx2Z"));
+    /// assert!(ksuid.matches_short(ksuid.short().with_len(12).as_str()));
+    /// assert!(!ksuid.matches_short("1srOrx2z"));
+    /// assert!(!ksuid.matches_short(""));
+    /// ```
+    fn matches_short(&self, short: &str) -> bool {
+        !short.is_empty() && base62::as_str(&base62::encode(self.bytes())).starts_with(short)
+    }
+
+    /// Append the base62 representation of the Ksuid to a `fmt::Write`, such as a `String`
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    ///
+    /// let ksuid = Ksuid::from_base62("1srOrx2ZWZBpBUvZwXKQmoEYga2").unwrap();
+    /// let mut line = String::from("id=");
+    /// ksuid.write_to(&mut line).unwrap();
+    /// assert_eq!(line, "id=1srOrx2ZWZBpBUvZwXKQmoEYga2");
+    /// ```
+    fn write_to<W: fmt::Write + ?Sized>(&self, w: &mut W) -> fmt::Result {
+        w.write_str(base62::as_str(&base62::encode(self.bytes())))
+    }
+
+    /// Write the base62 representation of the Ksuid to an `io::Write`
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    ///
+    /// let ksuid = Ksuid::from_base62("1srOrx2ZWZBpBUvZwXKQmoEYga2").unwrap();
+    /// let mut buf = Vec::new();
+    /// ksuid.write_to_io(&mut buf).unwrap();
+    /// assert_eq!(buf, b"1srOrx2ZWZBpBUvZwXKQmoEYga2");
+    /// ```
+    fn write_to_io<W: io::Write + ?Sized>(&self, w: &mut W) -> io::Result<()> {
+        w.write_all(&base62::encode(self.bytes()))
+    }
+
+    /// Load a base62 representation to a Ksuid
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    /// use std::str::FromStr;
+    ///
+    /// let ksuid = Ksuid::from_str("24CtFf3hyVZHdSkQy0nMBa1OjOA").unwrap();
+    /// assert_eq!(ksuid.to_string(), "24CtFf3hyVZHdSkQy0nMBa1OjOA");
+    ///
+    /// ```
+    fn from_base62(s: &str) -> Result<Self::Type, Error> {
+        Self::from_base62_with(s, &Base62Alphabet::STANDARD)
+    }
+
+    /// Load a base62 representation to a Ksuid, ignoring surrounding whitespace
+    ///
+    /// This is useful for ids copied from logs or CSV files, which often have a trailing
+    /// newline. Whitespace inside the ksuid is still rejected.
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    ///
+    /// let ksuid = Ksuid::from_str_trimmed(" 24CtFf3hyVZHdSkQy0nMBa1OjOA\r\n").unwrap();
+    /// assert_eq!(ksuid.to_string(), "24CtFf3hyVZHdSkQy0nMBa1OjOA");
+    /// ```
+    fn from_str_trimmed(s: &str) -> Result<Self::Type, Error> {
+        Self::from_base62(s.trim())
+    }
+
+    /// Derive a Ksuid from a [cuid2](https://github.com/paralleldrive/cuid2) id
+    ///
+    /// This is a lossy, one-way mapping for deduplicating records that are identified by cuid2
+    /// elsewhere. The payload is the first payload bytes of the big-endian 128-bit FNV-1a hash
+    /// of the cuid2 string, and the timestamp is set like in [`KsuidLike::from_seconds`]. Other
+    /// systems can compute the same id as long as they use the same timestamp, so pass the time
+    /// of the record rather than `None` when the ids have to match.
+    ///
+    /// The cuid2 must be 2 to 32 characters of lowercase letters and digits, starting with a
+    /// letter.
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    ///
+    /// let ksuid = Ksuid::from_cuid2("tz4a98xxat96iws9zmbrgj3a", Some(1_621_627_443)).unwrap();
+    /// assert_eq!(ksuid.timestamp_seconds(), 1_621_627_443);
+    /// assert_eq!(ksuid.payload()[..4], [0x7e, 0xfb, 0x41, 0x36]);
+    /// assert_eq!(ksuid, Ksuid::from_cuid2("tz4a98xxat96iws9zmbrgj3a", Some(1_621_627_443)).unwrap());
+    /// assert!(Ksuid::from_cuid2("Tz4a98xxat96iws9zmbrgj3a", None).is_err());
+    /// ```
+    fn from_cuid2(cuid: &str, timestamp: Option<i64>) -> Result<Self::Type, Error> {
+        let bytes = cuid.as_bytes();
+        let valid = (2..=32).contains(&bytes.len())
+            && bytes[0].is_ascii_lowercase()
+            && bytes
+                .iter()
+                .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit());
+        if !valid {
+            return Err(Error::new(format!("Got invalid cuid2 {:?}", cuid)));
+        }
+        let hash = hash::fnv1a_128(bytes).to_be_bytes();
+        let millis = timestamp.map_or_else(now_millis, |seconds| seconds.saturating_mul(1_000));
+        from_millis_and_payload::<Self>(
+            millis,
+            &hash[..Self::PAYLOAD_BYTES],
+            TimestampOverflow::Wrap,
+        )
+    }
+
+    /// Convert the Ksuid to base62 using a custom alphabet
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    ///
+    /// let ksuid = Ksuid::new(None, None);
+    /// assert_eq!(ksuid.to_base62_with(&Base62Alphabet::STANDARD), ksuid.to_base62());
+    /// ```
+    fn to_base62_with(&self, alphabet: &Base62Alphabet) -> String {
+        base62::as_str(&base62::encode_with(self.bytes(), alphabet)).to_owned()
+    }
+
+    /// Load a base62 representation, encoded with a custom alphabet, to a Ksuid
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    ///
+    /// let alphabet =
+    ///     Base62Alphabet::new("0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ").unwrap();
+    /// let ksuid = Ksuid::from_base62_with("0UJTSyCGVstL8paUaDQwysmNloV", &alphabet).unwrap();
+    /// assert_eq!(ksuid.to_string(), "0ujtsYcgvSTl8PAuAdqWYSMnLOv");
+    /// ```
+    fn from_base62_with(s: &str, alphabet: &Base62Alphabet) -> Result<Self::Type, Error> {
+        if s.len() != TOTAL_BYTES_BASE62 {
+            return Err(Error::new(format!(
                 "Got base62 ksuid of unexpected length {}",
                 s.len()
             )));
         }
-        if let Some(loaded) = base_encode::from_str(s, 62, BASE_62_CHARS) {
-            // Get the last TOTAL_BYTES
-            let loaded = if loaded.len() > TOTAL_BYTES {
-                &loaded[loaded.len() - TOTAL_BYTES..]
-            } else {
-                &loaded[..]
+        // The length is checked above
+        let src = s.as_bytes().try_into().unwrap();
+        base62::decode_with(src, alphabet).map(Self::from_bytes)
+    }
+
+    /// Convert the Ksuid to lowercase hex
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    ///
+    /// let ksuid = Ksuid::from_base62("0ujtsYcgvSTl8PAuAdqWYSMnLOv").unwrap();
+    /// assert_eq!(ksuid.to_hex(), "0669f7efb5a1cd34b5f99d1154fb6853345c9735");
+    /// ```
+    fn to_hex(&self) -> String {
+        let mut hex = String::with_capacity(TOTAL_BYTES_HEX);
+        for byte in self.bytes() {
+            hex.push(HEX_CHARS[(byte >> 4) as usize] as char);
+            hex.push(HEX_CHARS[(byte & 0x0F) as usize] as char);
+        }
+        hex
+    }
+
+    /// Load a hex representation to a Ksuid
+    ///
+    /// Both lowercase and uppercase hex digits are accepted.
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    ///
+    /// let ksuid = Ksuid::from_hex("0669F7EFB5A1CD34B5F99D1154FB6853345C9735").unwrap();
+    /// assert_eq!(ksuid.to_string(), "0ujtsYcgvSTl8PAuAdqWYSMnLOv");
+    /// ```
+    fn from_hex(s: &str) -> Result<Self::Type, Error> {
+        if s.len() != TOTAL_BYTES_HEX {
+            return Err(Error::new(format!(
+                "Got hex ksuid of unexpected length {}",
+                s.len()
+            )));
+        }
+        let mut buf = [0u8; TOTAL_BYTES];
+        for (byte, pair) in buf.iter_mut().zip(s.as_bytes().chunks_exact(2)) {
+            let high = (pair[0] as char).to_digit(16);
+            let low = (pair[1] as char).to_digit(16);
+            match (high, low) {
+                (Some(high), Some(low)) => *byte = ((high << 4) | low) as u8,
+                _ => return Err(Error::new("Failed to decode".to_owned())),
+            }
+        }
+        Ok(Self::from_bytes(buf))
+    }
+
+    /// Convert the Ksuid to Crockford's base32
+    ///
+    /// The output is always 32 uppercase characters and sorts the same as the underlying bytes.
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    ///
+    /// let ksuid = Ksuid::from_base62("0ujtsYcgvSTl8PAuAdqWYSMnLOv").unwrap();
+    /// assert_eq!(ksuid.to_base32_crockford(), "0SMZFVXNM76K9DFSKM8N9YV8ACT5S5SN");
+    /// ```
+    fn to_base32_crockford(&self) -> String {
+        let mut out = String::with_capacity(TOTAL_BYTES_BASE32);
+        for chunk in self.bytes().chunks_exact(5) {
+            let group = chunk.iter().fold(0u64, |acc, &b| (acc << 8) | b as u64);
+            for i in (0..8).rev() {
+                out.push(BASE_32_CROCKFORD_CHARS[((group >> (i * 5)) & 0x1F) as usize] as char);
+            }
+        }
+        out
+    }
+
+    /// Load a Crockford's base32 representation to a Ksuid
+    ///
+    /// Decoding is case-insensitive, hyphens are ignored, and the commonly confused
+    /// `I`, `L` and `O` characters are read as `1`, `1` and `0` respectively.
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    ///
+    /// let ksuid = Ksuid::from_base32_crockford("0smz-fvxn-m76k-9dfs-km8n-9yv8-act5-s5sn").unwrap();
+    /// assert_eq!(ksuid.to_string(), "0ujtsYcgvSTl8PAuAdqWYSMnLOv");
+    /// ```
+    fn from_base32_crockford(s: &str) -> Result<Self::Type, Error> {
+        let mut digits = [0u8; TOTAL_BYTES_BASE32];
+        let mut len = 0;
+        for c in s.bytes().filter(|&c| c != b'-') {
+            let digit = match c.to_ascii_uppercase() {
+                b'O' => 0,
+                b'I' | b'L' => 1,
+                c => match BASE_32_CROCKFORD_CHARS.iter().position(|&a| a == c) {
+                    Some(digit) => digit as u8,
+                    None => return Err(Error::new("Failed to decode".to_owned())),
+                },
             };
-            let mut buf = [0u8; TOTAL_BYTES];
-            if loaded.len() != TOTAL_BYTES {
-                Err(Error(format!(
-                    "Got ksuid of unexpected length {}",
-                    loaded.len()
-                )))
-            } else {
-                buf.copy_from_slice(loaded);
-                Ok(Self::from_bytes(buf))
+            if len == TOTAL_BYTES_BASE32 {
+                return Err(Error::new(
+                    "Got base32 ksuid longer than the expected length".to_owned(),
+                ));
             }
-        } else {
-            Err(Error("Failed to decode".to_owned()))
+            digits[len] = digit;
+            len += 1;
+        }
+        if len != TOTAL_BYTES_BASE32 {
+            return Err(Error::new(format!(
+                "Got base32 ksuid of unexpected length {}",
+                len
+            )));
+        }
+        let mut buf = [0u8; TOTAL_BYTES];
+        for (chunk, group_digits) in buf.chunks_exact_mut(5).zip(digits.chunks_exact(8)) {
+            let group = group_digits
+                .iter()
+                .fold(0u64, |acc, &d| (acc << 5) | d as u64);
+            for (i, byte) in chunk.iter_mut().enumerate() {
+                *byte = (group >> ((4 - i) * 8)) as u8;
+            }
+        }
+        Ok(Self::from_bytes(buf))
+    }
+
+    /// Convert the Ksuid to base58 (using the Bitcoin alphabet)
+    ///
+    /// The output is always 28 characters (left padded with `1`, the zero digit), so it
+    /// sorts the same as the underlying bytes.
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    ///
+    /// let ksuid = Ksuid::from_base62("0ujtsYcgvSTl8PAuAdqWYSMnLOv").unwrap();
+    /// assert_eq!(ksuid.to_base58(), "16BbceYADT9RVmKUNWmQpKX2GvaY");
+    /// ```
+    fn to_base58(&self) -> String {
+        format!(
+            "{:1>28}",
+            base_encode::to_string(self.bytes(), 58, BASE_58_CHARS).unwrap()
+        )
+    }
+
+    /// Load a base58 (Bitcoin alphabet) representation to a Ksuid
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    ///
+    /// let ksuid = Ksuid::from_base58("16BbceYADT9RVmKUNWmQpKX2GvaY").unwrap();
+    /// assert_eq!(ksuid.to_string(), "0ujtsYcgvSTl8PAuAdqWYSMnLOv");
+    /// ```
+    fn from_base58(s: &str) -> Result<Self::Type, Error> {
+        if s.len() != TOTAL_BYTES_BASE58 {
+            return Err(Error::new(format!(
+                "Got base58 ksuid of unexpected length {}",
+                s.len()
+            )));
+        }
+        let loaded = base_encode::from_str(s, 58, BASE_58_CHARS)
+            .ok_or_else(|| Error::new("Failed to decode".to_owned()))?;
+        // Leading zeros are decoded as extra zero bytes, anything else doesn't fit in a ksuid
+        let split = loaded.len().saturating_sub(TOTAL_BYTES);
+        let (overflow, loaded) = loaded.split_at(split);
+        if overflow.iter().any(|&b| b != 0) {
+            return Err(Error::new(
+                "Got base58 ksuid that overflows 20 bytes".to_owned(),
+            ));
+        }
+        let mut buf = [0u8; TOTAL_BYTES];
+        buf[TOTAL_BYTES - loaded.len()..].copy_from_slice(loaded);
+        Ok(Self::from_bytes(buf))
+    }
+
+    /// Encode the Ksuid as CBOR, as a byte string of its raw bytes tagged with
+    /// [`KSUID_CBOR_TAG`]
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    ///
+    /// let ksuid = Ksuid::new(None, None);
+    /// let cbor = ksuid.to_cbor();
+    /// assert_eq!(cbor.len(), 26);
+    /// assert_eq!(cbor[6..], ksuid.bytes()[..]);
+    /// ```
+    fn to_cbor(&self) -> [u8; cbor::CBOR_BYTES] {
+        cbor::encode(self.bytes())
+    }
+
+    /// Load a Ksuid from CBOR, as a byte string of its raw bytes tagged with
+    /// [`KSUID_CBOR_TAG`]
+    ///
+    /// The input must be exactly one data item. Untagged byte strings are rejected.
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    ///
+    /// let ksuid = Ksuid::new(None, None);
+    /// assert_eq!(Ksuid::from_cbor(&ksuid.to_cbor()).unwrap(), ksuid);
+    /// assert!(Ksuid::from_cbor(&ksuid.to_cbor()[5..]).is_err());
+    /// ```
+    fn from_cbor(input: &[u8]) -> Result<Self::Type, Error> {
+        cbor::decode(input).map(Self::from_bytes)
+    }
+
+    /// Load a Ksuid from any of the supported representations, detecting the format used
+    ///
+    /// The format is detected based on the input length: raw bytes (20), base62 (27),
+    /// base58 (28), Crockford's base32 (32) and hex (40).
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    ///
+    /// let (ksuid, format) = Ksuid::parse_any(b"0669f7efb5a1cd34b5f99d1154fb6853345c9735").unwrap();
+    /// assert_eq!(format, KsuidFormat::Hex);
+    /// assert_eq!(ksuid.to_string(), "0ujtsYcgvSTl8PAuAdqWYSMnLOv");
+    ///
+    /// let (ksuid, format) = Ksuid::parse_any(ksuid.bytes()).unwrap();
+    /// assert_eq!(format, KsuidFormat::Bytes);
+    /// assert_eq!(ksuid.to_string(), "0ujtsYcgvSTl8PAuAdqWYSMnLOv");
+    /// ```
+    fn parse_any(input: &[u8]) -> Result<(Self::Type, KsuidFormat), Error> {
+        if let Ok(bytes) = <[u8; TOTAL_BYTES]>::try_from(input) {
+            return Ok((Self::from_bytes(bytes), KsuidFormat::Bytes));
+        }
+        let s =
+            std::str::from_utf8(input).map_err(|_| Error::new("Failed to decode".to_owned()))?;
+        match s.len() {
+            TOTAL_BYTES_BASE62 => Ok((Self::from_base62(s)?, KsuidFormat::Base62)),
+            TOTAL_BYTES_BASE58 => Ok((Self::from_base58(s)?, KsuidFormat::Base58)),
+            TOTAL_BYTES_BASE32 => Ok((
+                Self::from_base32_crockford(s)?,
+                KsuidFormat::Base32Crockford,
+            )),
+            TOTAL_BYTES_HEX => Ok((Self::from_hex(s)?, KsuidFormat::Hex)),
+            len => Err(Error::new(format!(
+                "Got ksuid of unexpected length {}",
+                len
+            ))),
         }
     }
 }
@@ -282,40 +1928,175 @@ pub trait KsuidLike {
 /// assert_eq!(ksuid, ksuid2);
 ///  ```
 #[derive(Debug, PartialOrd, Ord, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::KnownLayout,
+        zerocopy::Immutable,
+        zerocopy::Unaligned
+    )
+)]
+#[repr(transparent)]
 pub struct Ksuid([u8; TOTAL_BYTES]);
 
-impl Ksuid {
-    /// Creates new Ksuid with specified timestamp (in KSUID Epoch) and optional payload
+impl Ksuid {
+    /// The smallest possible Ksuid, with all bytes set to zero
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    ///
+    /// assert!(Ksuid::MIN <= Ksuid::new(None, None));
+    /// assert_eq!(Ksuid::MIN.to_string(), "000000000000000000000000000");
+    /// ```
+    pub const MIN: Self = Self([0; TOTAL_BYTES]);
+
+    /// The largest possible Ksuid, with all bytes set to `0xFF`
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    ///
+    /// assert!(Ksuid::new(None, None) <= Ksuid::MAX);
+    /// assert_eq!(Ksuid::MAX.to_string(), Ksuid::MAX_STRING);
+    /// ```
+    pub const MAX: Self = Self([0xFF; TOTAL_BYTES]);
+
+    /// The base62 representation of [`Ksuid::MAX`], which is also the largest valid base62
+    /// ksuid string
+    pub const MAX_STRING: &'static str = MAX_BASE62;
+
+    /// Creates new Ksuid with specified timestamp (in KSUID Epoch) and optional payload
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    ///
+    /// let ksuid = Ksuid::new_raw(0, None);
+    /// ```
+    #[cfg(feature = "getrandom")]
+    pub fn new_raw(timestamp: u32, payload: Option<&[u8]>) -> Self {
+        let mut buf = [0u8; TOTAL_BYTES];
+        BigEndian::write_u32(&mut buf, timestamp);
+        if let Some(payload) = payload {
+            buf[Self::TIMESTAMP_BYTES..].copy_from_slice(payload);
+        } else {
+            entropy::fill_random(&mut buf[Self::TIMESTAMP_BYTES..]);
+        }
+        Self::from_bytes(buf)
+    }
+
+    /// Get the raw timestamp value of the ksuid
+    /// This is the actual raw value in seconds since `KSUID_EPOCH`
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    ///
+    /// let ksuid = Ksuid::new(None, None);
+    /// let raw = ksuid.timestamp_raw();
+    /// ```
+    pub fn timestamp_raw(&self) -> u32 {
+        BigEndian::read_u32(self.bytes())
+    }
+
+    /// Set the timestamp of the ksuid in place, keeping the payload
+    ///
+    /// Like [`KsuidLike::new`], timestamps that are out of range wrap around.
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    /// use time::OffsetDateTime;
+    ///
+    /// let mut ksuid = Ksuid::new(None, None);
+    /// let payload = ksuid.payload().to_vec();
+    /// let timestamp = OffsetDateTime::from_unix_timestamp(1_621_627_443).unwrap();
+    /// ksuid.set_timestamp(timestamp);
+    /// assert_eq!(ksuid.timestamp(), timestamp);
+    /// assert_eq!(ksuid.payload(), payload);
+    /// ```
+    #[cfg(feature = "time")]
+    pub fn set_timestamp(&mut self, timestamp: OffsetDateTime) {
+        // Wrapping never fails
+        let raw = raw_seconds(timestamp.unix_timestamp(), TimestampOverflow::Wrap).unwrap();
+        self.set_timestamp_raw(raw);
+    }
+
+    /// Set the raw timestamp (in KSUID Epoch) of the ksuid in place, keeping the payload
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    ///
+    /// let mut ksuid = Ksuid::new(None, None);
+    /// ksuid.set_timestamp_raw(42);
+    /// assert_eq!(ksuid.timestamp_raw(), 42);
+    /// ```
+    pub fn set_timestamp_raw(&mut self, timestamp: u32) {
+        BigEndian::write_u32(&mut self.0, timestamp);
+    }
+
+    /// Set the payload of the ksuid in place, keeping the timestamp
+    ///
+    /// # Panics
+    /// If `payload` isn't [`Ksuid::PAYLOAD_BYTES`] long.
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    ///
+    /// let mut ksuid = Ksuid::new(None, None);
+    /// let timestamp = ksuid.timestamp_raw();
+    /// ksuid.set_payload(&[7u8; 16]);
+    /// assert_eq!(ksuid.payload(), &[7u8; 16]);
+    /// assert_eq!(ksuid.timestamp_raw(), timestamp);
+    /// ```
+    pub fn set_payload(&mut self, payload: &[u8]) {
+        debug_assert_eq!(
+            payload.len(),
+            Self::PAYLOAD_BYTES,
+            "Got ksuid payload of unexpected length"
+        );
+        self.0[Self::TIMESTAMP_BYTES..].copy_from_slice(payload);
+    }
+
+    /// Creates new Ksuid from its timestamp and payload parts
+    ///
+    /// This is the inverse of [`Ksuid::to_parts`].
     ///
     /// # Examples
     /// ```
     /// use svix_ksuid::*;
+    /// use time::OffsetDateTime;
     ///
-    /// let ksuid = Ksuid::new_raw(0, None);
+    /// let timestamp = OffsetDateTime::from_unix_timestamp(1_621_627_443).unwrap();
+    /// let ksuid = Ksuid::from_parts(timestamp, [12u8; 16]);
+    /// assert_eq!(ksuid.to_parts(), (timestamp, [12u8; 16]));
     /// ```
-    pub fn new_raw(timestamp: u32, payload: Option<&[u8]>) -> Self {
-        let mut buf = [0u8; TOTAL_BYTES];
-        BigEndian::write_u32(&mut buf, timestamp);
-        if let Some(payload) = payload {
-            buf[Self::TIMESTAMP_BYTES..].copy_from_slice(payload);
-        } else {
-            getrandom::getrandom(&mut buf[Self::TIMESTAMP_BYTES..]).unwrap();
-        }
-        Self::from_bytes(buf)
+    #[cfg(feature = "time")]
+    pub fn from_parts(timestamp: OffsetDateTime, payload: [u8; 16]) -> Self {
+        Self::from_system_time_and_payload(timestamp.into(), &payload)
     }
 
-    /// Get the raw timestamp value of the ksuid
-    /// This is the actual raw value in seconds since `KSUID_EPOCH`
+    /// Split the ksuid into its timestamp and payload parts
     ///
     /// # Examples
     /// ```
     /// use svix_ksuid::*;
     ///
     /// let ksuid = Ksuid::new(None, None);
-    /// let raw = ksuid.timestamp_raw();
+    /// let (timestamp, payload) = ksuid.to_parts();
+    /// assert_eq!(timestamp, ksuid.timestamp());
+    /// assert_eq!(&payload[..], ksuid.payload());
     /// ```
-    pub fn timestamp_raw(&self) -> u32 {
-        BigEndian::read_u32(self.bytes())
+    #[cfg(feature = "time")]
+    pub fn to_parts(&self) -> (OffsetDateTime, [u8; 16]) {
+        let mut payload = [0u8; 16];
+        payload.copy_from_slice(self.payload());
+        (self.timestamp(), payload)
     }
 }
 
@@ -323,16 +2104,28 @@ impl KsuidLike for Ksuid {
     type Type = Ksuid;
     const TIMESTAMP_BYTES: usize = 4;
     const PAYLOAD_BYTES: usize = 16;
+    const PRECISION: TimestampPrecision = TimestampPrecision::Seconds;
 
+    #[cfg(all(feature = "time", feature = "getrandom"))]
     fn new(timestamp: Option<OffsetDateTime>, payload: Option<&[u8]>) -> Self {
         let timestamp = timestamp.map(|x| x.unix_timestamp());
         Self::from_seconds(timestamp, payload)
     }
 
+    #[cfg(feature = "getrandom")]
     fn from_seconds(timestamp: Option<i64>, payload: Option<&[u8]>) -> Self {
-        let timestamp =
-            timestamp.unwrap_or_else(|| OffsetDateTime::now_utc().unix_timestamp()) - KSUID_EPOCH;
-        Self::new_raw(timestamp as u32, payload)
+        let timestamp = timestamp.unwrap_or_else(|| now_millis().div_euclid(1_000));
+        // Wrapping never fails
+        Self::from_seconds_with(timestamp, payload, TimestampOverflow::Wrap).unwrap()
+    }
+
+    #[cfg(feature = "getrandom")]
+    fn from_seconds_with(
+        timestamp: i64,
+        payload: Option<&[u8]>,
+        overflow: TimestampOverflow,
+    ) -> Result<Self, Error> {
+        Ok(Self::new_raw(raw_seconds(timestamp, overflow)?, payload))
     }
 
     fn from_bytes(bytes: [u8; TOTAL_BYTES]) -> Self {
@@ -343,9 +2136,24 @@ impl KsuidLike for Ksuid {
         &self.0
     }
 
+    #[cfg(feature = "getrandom")]
+    fn from_system_time(timestamp: SystemTime, payload: Option<&[u8]>) -> Self {
+        let timestamp = system_time_millis(timestamp).div_euclid(1_000);
+        Self::from_seconds(Some(timestamp), payload)
+    }
+
+    #[cfg(feature = "time")]
     fn timestamp(&self) -> OffsetDateTime {
-        let timestamp = self.timestamp_raw() as i64 + KSUID_EPOCH;
-        OffsetDateTime::from_unix_timestamp(timestamp).unwrap()
+        OffsetDateTime::from_unix_timestamp(self.timestamp_seconds()).unwrap()
+    }
+
+    fn timestamp_seconds(&self) -> i64 {
+        self.timestamp_raw() as i64 + KSUID_EPOCH
+    }
+
+    fn system_time(&self) -> SystemTime {
+        let timestamp = self.timestamp_raw() as u64 + KSUID_EPOCH as u64;
+        UNIX_EPOCH + Duration::from_secs(timestamp)
     }
 }
 
@@ -357,18 +2165,162 @@ impl FromStr for Ksuid {
     }
 }
 
+impl TryFrom<&str> for Ksuid {
+    type Error = Error;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::from_base62(s)
+    }
+}
+
+impl TryFrom<String> for Ksuid {
+    type Error = Error;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Self::from_base62(&s)
+    }
+}
+
+impl From<Ksuid> for String {
+    fn from(ksuid: Ksuid) -> Self {
+        ksuid.to_base62()
+    }
+}
+
+impl PartialEq<str> for Ksuid {
+    fn eq(&self, other: &str) -> bool {
+        base62::encode(self.bytes()) == other.as_bytes()
+    }
+}
+
+impl PartialEq<&str> for Ksuid {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
 impl fmt::Display for Ksuid {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.to_base62())
+        f.pad(base62::as_str(&base62::encode(self.bytes())))
+    }
+}
+
+impl fmt::LowerHex for Ksuid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_hex(self.bytes(), f, false)
+    }
+}
+
+impl fmt::UpperHex for Ksuid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_hex(self.bytes(), f, true)
+    }
+}
+
+/// The default is the nil ksuid (see [`KsuidLike::nil`])
+impl Default for Ksuid {
+    fn default() -> Self {
+        Self::nil()
     }
 }
 
+/// Hashes the 20 raw bytes, never the base62 string
 impl Hash for Ksuid {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.0.hash(state);
     }
 }
 
+impl AsRef<[u8]> for Ksuid {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl Borrow<[u8]> for Ksuid {
+    fn borrow(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl From<[u8; TOTAL_BYTES]> for Ksuid {
+    fn from(bytes: [u8; TOTAL_BYTES]) -> Self {
+        Self::from_bytes(bytes)
+    }
+}
+
+impl TryFrom<&[u8]> for Ksuid {
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        let bytes: [u8; TOTAL_BYTES] = bytes
+            .try_into()
+            .map_err(|_| Error::new(format!("Got ksuid of unexpected length {}", bytes.len())))?;
+        Ok(Self::from_bytes(bytes))
+    }
+}
+
+/// Shifts the timestamp by a duration, keeping the payload
+///
+/// The result is truncated to whole seconds. Panics if the timestamp goes outside of
+/// [`KSUID_MIN_TIMESTAMP`] to [`KSUID_MAX_TIMESTAMP`], like `OffsetDateTime` does on overflow.
+#[cfg(feature = "time")]
+impl Add<time::Duration> for Ksuid {
+    type Output = Self;
+
+    fn add(self, rhs: time::Duration) -> Self {
+        let timestamp = self.timestamp() + rhs;
+        from_millis_and_payload::<Self>(
+            timestamp_millis(&timestamp),
+            self.payload(),
+            TimestampOverflow::Error,
+        )
+        .expect("overflow when adding duration to ksuid")
+    }
+}
+
+#[cfg(feature = "time")]
+impl AddAssign<time::Duration> for Ksuid {
+    fn add_assign(&mut self, rhs: time::Duration) {
+        *self = *self + rhs;
+    }
+}
+
+/// Shifts the timestamp back by a duration, keeping the payload
+///
+/// See the `Add<time::Duration>` implementation for the truncation and overflow behavior.
+#[cfg(feature = "time")]
+impl Sub<time::Duration> for Ksuid {
+    type Output = Self;
+
+    fn sub(self, rhs: time::Duration) -> Self {
+        let timestamp = self.timestamp() - rhs;
+        from_millis_and_payload::<Self>(
+            timestamp_millis(&timestamp),
+            self.payload(),
+            TimestampOverflow::Error,
+        )
+        .expect("overflow when subtracting duration from ksuid")
+    }
+}
+
+#[cfg(feature = "time")]
+impl SubAssign<time::Duration> for Ksuid {
+    fn sub_assign(&mut self, rhs: time::Duration) {
+        *self = *self - rhs;
+    }
+}
+
+/// The time between the timestamps of two ksuids, see [`KsuidLike::duration_since`]
+#[cfg(feature = "time")]
+impl Sub for Ksuid {
+    type Output = time::Duration;
+
+    fn sub(self, rhs: Self) -> time::Duration {
+        self.duration_since(&rhs)
+    }
+}
+
 /// K-Sortable Unique ID (Ms accuracy)
 ///
 /// This one has Ms accuracy compared to the normal one that has second accuracy
@@ -384,11 +2336,48 @@ impl Hash for Ksuid {
 /// assert_eq!(ksuid, ksuid2);
 ///  ```
 #[derive(Debug, PartialOrd, Ord, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::KnownLayout,
+        zerocopy::Immutable,
+        zerocopy::Unaligned
+    )
+)]
+#[repr(transparent)]
 pub struct KsuidMs([u8; TOTAL_BYTES]);
 
 impl KsuidMs {
     const U64_BYTES: usize = 8;
 
+    /// The smallest possible KsuidMs, with all bytes set to zero
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    ///
+    /// assert!(KsuidMs::MIN <= KsuidMs::new(None, None));
+    /// assert_eq!(KsuidMs::MIN.to_string(), "000000000000000000000000000");
+    /// ```
+    pub const MIN: Self = Self([0; TOTAL_BYTES]);
+
+    /// The largest possible KsuidMs, with all bytes set to `0xFF`
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    ///
+    /// assert!(KsuidMs::new(None, None) <= KsuidMs::MAX);
+    /// assert_eq!(KsuidMs::MAX.to_string(), KsuidMs::MAX_STRING);
+    /// ```
+    pub const MAX: Self = Self([0xFF; TOTAL_BYTES]);
+
+    /// The base62 representation of [`KsuidMs::MAX`], which is also the largest valid base62
+    /// ksuid string
+    pub const MAX_STRING: &'static str = MAX_BASE62;
+
     /// Creates new KsuidMs with specified timestamp (in KSUID Epoch) and optional payload
     ///
     /// # Examples
@@ -397,6 +2386,7 @@ impl KsuidMs {
     ///
     /// let ksuid = KsuidMs::new_raw(0, None);
     /// ```
+    #[cfg(feature = "getrandom")]
     pub fn new_raw(timestamp: u64, payload: Option<&[u8]>) -> Self {
         let mut buf = [0u8; TOTAL_BYTES];
         let mut timestamp_buf = [0u8; Self::U64_BYTES];
@@ -408,7 +2398,7 @@ impl KsuidMs {
         if let Some(payload) = payload {
             buf[Self::TIMESTAMP_BYTES..].copy_from_slice(payload);
         } else {
-            getrandom::getrandom(&mut buf[Self::TIMESTAMP_BYTES..]).unwrap();
+            entropy::fill_random(&mut buf[Self::TIMESTAMP_BYTES..]);
         }
         Self::from_bytes(buf)
     }
@@ -421,13 +2411,47 @@ impl KsuidMs {
     ///
     /// let ksuid = KsuidMs::from_millis(Some(1_621_627_443_000), None);
     /// ```
+    #[cfg(feature = "getrandom")]
     pub fn from_millis(timestamp: Option<i64>, payload: Option<&[u8]>) -> Self {
-        let timestamp_ms =
-            timestamp.unwrap_or_else(|| timestamp_millis(&OffsetDateTime::now_utc()));
-        let timestamp_s = (timestamp_ms / 1_000) - KSUID_EPOCH;
-        let timestamp_ms = (timestamp_ms % 1_000) >> 2;
-        let timestamp = ((timestamp_s << 8) & 0xFFFFFFFF00) | timestamp_ms;
-        Self::new_raw(timestamp as u64, payload)
+        let timestamp = timestamp.unwrap_or_else(now_millis);
+        // Wrapping never fails
+        Self::from_millis_with(timestamp, payload, TimestampOverflow::Wrap).unwrap()
+    }
+
+    /// Creates new KsuidMs with specified timestamp (in milliseconds) and optional payload,
+    /// handling timestamps outside of [`KSUID_MIN_TIMESTAMP`] to [`KSUID_MAX_TIMESTAMP`] as
+    /// specified
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    ///
+    /// let ksuid = KsuidMs::from_millis_with(0, None, TimestampOverflow::Saturate).unwrap();
+    /// assert_eq!(ksuid.timestamp_millis(), KSUID_MIN_TIMESTAMP * 1_000);
+    /// ```
+    #[cfg(feature = "getrandom")]
+    pub fn from_millis_with(
+        timestamp: i64,
+        payload: Option<&[u8]>,
+        overflow: TimestampOverflow,
+    ) -> Result<Self, Error> {
+        Ok(Self::new_raw(raw_millis(timestamp, overflow)?, payload))
+    }
+
+    /// Creates new KsuidMs with specified timestamp (in milliseconds) and optional payload,
+    /// failing with [`Error::TimestampOutOfRange`] if the timestamp is outside of
+    /// [`KSUID_MIN_TIMESTAMP`] to [`KSUID_MAX_TIMESTAMP`]
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    ///
+    /// assert!(KsuidMs::try_from_millis(1_621_627_443_000, None).is_ok());
+    /// assert!(KsuidMs::try_from_millis(-1, None).is_err());
+    /// ```
+    #[cfg(feature = "getrandom")]
+    pub fn try_from_millis(timestamp: i64, payload: Option<&[u8]>) -> Result<Self, Error> {
+        Self::from_millis_with(timestamp, payload, TimestampOverflow::Error)
     }
 
     /// Get the timestamp portion of the ksuid in milliseconds
@@ -441,7 +2465,7 @@ impl KsuidMs {
     /// assert_eq!(ksuid.timestamp_millis(), timestamp);
     /// ```
     pub fn timestamp_millis(&self) -> i64 {
-        timestamp_millis(&self.timestamp())
+        system_time_millis(self.system_time())
     }
 
     /// Get the raw timestamp value of the ksuid
@@ -459,23 +2483,143 @@ impl KsuidMs {
         // Remove two bytes from the result (as we are only u48, not u64, and then mask the result)
         BigEndian::read_u64(self.bytes()) >> ((Self::U64_BYTES - Self::TIMESTAMP_BYTES) * 8)
     }
+
+    /// Set the timestamp of the ksuid in place, keeping the payload
+    ///
+    /// Like [`KsuidLike::new`], the timestamp is truncated to 4ms accuracy and timestamps
+    /// that are out of range wrap around.
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    /// use time::OffsetDateTime;
+    ///
+    /// let mut ksuid = KsuidMs::new(None, None);
+    /// let timestamp = OffsetDateTime::from_unix_timestamp_nanos(1_621_627_443_124_000_000).unwrap();
+    /// ksuid.set_timestamp(timestamp);
+    /// assert_eq!(ksuid.timestamp(), timestamp);
+    /// ```
+    #[cfg(feature = "time")]
+    pub fn set_timestamp(&mut self, timestamp: OffsetDateTime) {
+        // Wrapping never fails
+        let raw = raw_millis(timestamp_millis(&timestamp), TimestampOverflow::Wrap).unwrap();
+        self.set_timestamp_raw(raw);
+    }
+
+    /// Set the raw timestamp of the ksuid in place, keeping the payload
+    ///
+    /// Only the 40 least significant bits are used, see [`KsuidMs::timestamp_raw`].
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    ///
+    /// let mut ksuid = KsuidMs::new(None, None);
+    /// ksuid.set_timestamp_raw(42 << 8);
+    /// assert_eq!(ksuid.timestamp_raw(), 42 << 8);
+    /// ```
+    pub fn set_timestamp_raw(&mut self, timestamp: u64) {
+        BigEndian::write_uint(
+            &mut self.0[..Self::TIMESTAMP_BYTES],
+            timestamp & ((1 << (Self::TIMESTAMP_BYTES * 8)) - 1),
+            Self::TIMESTAMP_BYTES,
+        );
+    }
+
+    /// Set the payload of the ksuid in place, keeping the timestamp
+    ///
+    /// # Panics
+    /// If `payload` isn't [`KsuidMs::PAYLOAD_BYTES`] long.
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    ///
+    /// let mut ksuid = KsuidMs::new(None, None);
+    /// let timestamp = ksuid.timestamp_raw();
+    /// ksuid.set_payload(&[7u8; 15]);
+    /// assert_eq!(ksuid.payload(), &[7u8; 15]);
+    /// assert_eq!(ksuid.timestamp_raw(), timestamp);
+    /// ```
+    pub fn set_payload(&mut self, payload: &[u8]) {
+        debug_assert_eq!(
+            payload.len(),
+            Self::PAYLOAD_BYTES,
+            "Got ksuid payload of unexpected length"
+        );
+        self.0[Self::TIMESTAMP_BYTES..].copy_from_slice(payload);
+    }
+
+    /// Creates new KsuidMs from its timestamp and payload parts
+    ///
+    /// This is the inverse of [`KsuidMs::to_parts`]. Note that the timestamp is truncated
+    /// to the 4ms accuracy supported by `KsuidMs`.
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    /// use time::OffsetDateTime;
+    ///
+    /// let timestamp = OffsetDateTime::from_unix_timestamp(1_621_627_443).unwrap();
+    /// let ksuid = KsuidMs::from_parts(timestamp, [12u8; 15]);
+    /// assert_eq!(ksuid.to_parts(), (timestamp, [12u8; 15]));
+    /// ```
+    #[cfg(feature = "time")]
+    pub fn from_parts(timestamp: OffsetDateTime, payload: [u8; 15]) -> Self {
+        Self::from_system_time_and_payload(timestamp.into(), &payload)
+    }
+
+    /// Split the ksuid into its timestamp and payload parts
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    ///
+    /// let ksuid = KsuidMs::new(None, None);
+    /// let (timestamp, payload) = ksuid.to_parts();
+    /// assert_eq!(timestamp, ksuid.timestamp());
+    /// assert_eq!(&payload[..], ksuid.payload());
+    /// ```
+    #[cfg(feature = "time")]
+    pub fn to_parts(&self) -> (OffsetDateTime, [u8; 15]) {
+        let mut payload = [0u8; 15];
+        payload.copy_from_slice(self.payload());
+        (self.timestamp(), payload)
+    }
 }
 
 impl KsuidLike for KsuidMs {
     type Type = KsuidMs;
     const TIMESTAMP_BYTES: usize = 5;
     const PAYLOAD_BYTES: usize = 15;
+    const PRECISION: TimestampPrecision = TimestampPrecision::FourMillis;
 
+    #[cfg(all(feature = "time", feature = "getrandom"))]
     fn new(timestamp: Option<OffsetDateTime>, payload: Option<&[u8]>) -> Self {
         let timestamp = timestamp.map(|x| timestamp_millis(&x));
         Self::from_millis(timestamp, payload)
     }
 
+    #[cfg(feature = "getrandom")]
     fn from_seconds(timestamp: Option<i64>, payload: Option<&[u8]>) -> Self {
-        let timestamp = timestamp.map(|x| x * 1_000);
+        let timestamp = timestamp.map(|x| x.saturating_mul(1_000));
         Self::from_millis(timestamp, payload)
     }
 
+    #[cfg(feature = "getrandom")]
+    fn from_seconds_with(
+        timestamp: i64,
+        payload: Option<&[u8]>,
+        overflow: TimestampOverflow,
+    ) -> Result<Self, Error> {
+        Self::from_millis_with(timestamp.saturating_mul(1_000), payload, overflow)
+    }
+
+    #[cfg(feature = "getrandom")]
+    fn from_system_time(timestamp: SystemTime, payload: Option<&[u8]>) -> Self {
+        Self::from_millis(Some(system_time_millis(timestamp)), payload)
+    }
+
     fn from_bytes(bytes: [u8; TOTAL_BYTES]) -> Self {
         Self(bytes)
     }
@@ -484,6 +2628,7 @@ impl KsuidLike for KsuidMs {
         &self.0
     }
 
+    #[cfg(feature = "time")]
     fn timestamp(&self) -> OffsetDateTime {
         let timestamp = self.timestamp_raw() as i64;
         let seconds = ((timestamp >> 8) + KSUID_EPOCH) as i128;
@@ -491,6 +2636,17 @@ impl KsuidLike for KsuidMs {
 
         OffsetDateTime::from_unix_timestamp_nanos(seconds * 1_000_000_000 + ns).unwrap()
     }
+
+    fn system_time(&self) -> SystemTime {
+        let timestamp = self.timestamp_raw();
+        let seconds = (timestamp >> 8) + KSUID_EPOCH as u64;
+        let ms = ((timestamp & 0xFF) << 2) % 1_000;
+        UNIX_EPOCH + Duration::from_secs(seconds) + Duration::from_millis(ms)
+    }
+
+    fn timestamp_seconds(&self) -> i64 {
+        (self.timestamp_raw() >> 8) as i64 + KSUID_EPOCH
+    }
 }
 
 impl FromStr for KsuidMs {
@@ -501,18 +2657,162 @@ impl FromStr for KsuidMs {
     }
 }
 
+impl TryFrom<&str> for KsuidMs {
+    type Error = Error;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::from_base62(s)
+    }
+}
+
+impl TryFrom<String> for KsuidMs {
+    type Error = Error;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Self::from_base62(&s)
+    }
+}
+
+impl From<KsuidMs> for String {
+    fn from(ksuid: KsuidMs) -> Self {
+        ksuid.to_base62()
+    }
+}
+
+impl PartialEq<str> for KsuidMs {
+    fn eq(&self, other: &str) -> bool {
+        base62::encode(self.bytes()) == other.as_bytes()
+    }
+}
+
+impl PartialEq<&str> for KsuidMs {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
 impl fmt::Display for KsuidMs {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.to_base62())
+        f.pad(base62::as_str(&base62::encode(self.bytes())))
     }
 }
 
+impl fmt::LowerHex for KsuidMs {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_hex(self.bytes(), f, false)
+    }
+}
+
+impl fmt::UpperHex for KsuidMs {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_hex(self.bytes(), f, true)
+    }
+}
+
+/// The default is the nil ksuid (see [`KsuidLike::nil`])
+impl Default for KsuidMs {
+    fn default() -> Self {
+        Self::nil()
+    }
+}
+
+/// Hashes the 20 raw bytes, never the base62 string
 impl Hash for KsuidMs {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.0.hash(state);
     }
 }
 
+impl AsRef<[u8]> for KsuidMs {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl Borrow<[u8]> for KsuidMs {
+    fn borrow(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl From<[u8; TOTAL_BYTES]> for KsuidMs {
+    fn from(bytes: [u8; TOTAL_BYTES]) -> Self {
+        Self::from_bytes(bytes)
+    }
+}
+
+impl TryFrom<&[u8]> for KsuidMs {
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        let bytes: [u8; TOTAL_BYTES] = bytes
+            .try_into()
+            .map_err(|_| Error::new(format!("Got ksuid of unexpected length {}", bytes.len())))?;
+        Ok(Self::from_bytes(bytes))
+    }
+}
+
+/// Shifts the timestamp by a duration, keeping the payload
+///
+/// The result is truncated to units of 4 milliseconds. Panics if the timestamp goes outside of
+/// [`KSUID_MIN_TIMESTAMP`] to [`KSUID_MAX_TIMESTAMP`], like `OffsetDateTime` does on overflow.
+#[cfg(feature = "time")]
+impl Add<time::Duration> for KsuidMs {
+    type Output = Self;
+
+    fn add(self, rhs: time::Duration) -> Self {
+        let timestamp = self.timestamp() + rhs;
+        from_millis_and_payload::<Self>(
+            timestamp_millis(&timestamp),
+            self.payload(),
+            TimestampOverflow::Error,
+        )
+        .expect("overflow when adding duration to ksuid")
+    }
+}
+
+#[cfg(feature = "time")]
+impl AddAssign<time::Duration> for KsuidMs {
+    fn add_assign(&mut self, rhs: time::Duration) {
+        *self = *self + rhs;
+    }
+}
+
+/// Shifts the timestamp back by a duration, keeping the payload
+///
+/// See the `Add<time::Duration>` implementation for the truncation and overflow behavior.
+#[cfg(feature = "time")]
+impl Sub<time::Duration> for KsuidMs {
+    type Output = Self;
+
+    fn sub(self, rhs: time::Duration) -> Self {
+        let timestamp = self.timestamp() - rhs;
+        from_millis_and_payload::<Self>(
+            timestamp_millis(&timestamp),
+            self.payload(),
+            TimestampOverflow::Error,
+        )
+        .expect("overflow when subtracting duration from ksuid")
+    }
+}
+
+#[cfg(feature = "time")]
+impl SubAssign<time::Duration> for KsuidMs {
+    fn sub_assign(&mut self, rhs: time::Duration) {
+        *self = *self - rhs;
+    }
+}
+
+/// The time between the timestamps of two ksuids, see [`KsuidLike::duration_since`]
+#[cfg(feature = "time")]
+impl Sub for KsuidMs {
+    type Output = time::Duration;
+
+    fn sub(self, rhs: Self) -> time::Duration {
+        self.duration_since(&rhs)
+    }
+}
+
 #[cfg(feature = "serde")]
 impl Serialize for Ksuid {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -0,0 +1,76 @@
+use std::fmt;
+use std::time::UNIX_EPOCH;
+
+use crate::{KsuidLike, TimestampPrecision};
+
+/// A ksuid that only shows its timestamp when formatted, for logs that must not contain ids
+///
+/// Both `Debug` and `Display` print the UTC timestamp in RFC 3339 format followed by a masked
+/// payload, e.g. `2021-05-21T20:04:03Z/****`. `KsuidMs` timestamps include the milliseconds.
+/// The ksuid itself is still available through the public field.
+///
+/// # Examples
+/// ```
+/// use svix_ksuid::*;
+///
+/// let ksuid = Ksuid::from_seconds(Some(1_621_627_443), None);
+/// assert_eq!(format!("{:?}", ksuid.redacted()), "2021-05-21T20:04:03Z/****");
+/// assert_eq!(ksuid.redacted().to_string(), "2021-05-21T20:04:03Z/****");
+///
+/// let ksuid = KsuidMs::from_millis(Some(1_621_627_443_124), None);
+/// assert_eq!(format!("{:?}", ksuid.redacted()), "2021-05-21T20:04:03.124Z/****");
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Redacted<K>(pub K);
+
+/// Convert days since the unix epoch to a (year, month, day) civil date
+///
+/// From Howard Hinnant's `civil_from_days`, <https://howardhinnant.github.io/date_algorithms.html>
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + (month <= 2) as i64;
+    (year, month, day)
+}
+
+impl<K: KsuidLike> Redacted<K> {
+    fn fmt_redacted(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Ksuid timestamps can't be before the unix epoch
+        let elapsed = self.0.system_time().duration_since(UNIX_EPOCH).unwrap();
+        let seconds = elapsed.as_secs() as i64;
+        let (year, month, day) = civil_from_days(seconds / 86_400);
+        let time = seconds % 86_400;
+        write!(
+            f,
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+            year,
+            month,
+            day,
+            time / 3_600,
+            time / 60 % 60,
+            time % 60
+        )?;
+        if K::PRECISION != TimestampPrecision::Seconds {
+            write!(f, ".{:03}", elapsed.subsec_millis())?;
+        }
+        f.write_str("Z/****")
+    }
+}
+
+impl<K: KsuidLike> fmt::Debug for Redacted<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_redacted(f)
+    }
+}
+
+impl<K: KsuidLike> fmt::Display for Redacted<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_redacted(f)
+    }
+}
@@ -0,0 +1,151 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::Deref;
+use std::time::SystemTime;
+
+#[cfg(not(feature = "getrandom"))]
+use crate::DefaultEntropy;
+#[cfg(feature = "getrandom")]
+use crate::OsEntropy;
+use crate::{entropy, Error, Ksuid, KsuidLike, KsuidMs, Redacted, TOTAL_BYTES};
+
+/// A ksuid whose payload always comes from the library's entropy source, never from the caller
+///
+/// None of the constructors take a payload, and there is no conversion from a plain ksuid,
+/// so code holding a `SecureKsuid` knows the payload wasn't chosen by the application. Use it
+/// for token-like ids that must be unguessable. It dereferences to the wrapped ksuid, and
+/// converts into it with [`SecureKsuid::into_inner`] or `From`.
+///
+/// With the `getrandom` feature the payload always comes from `OsEntropy`, ignoring the source
+/// set with [`set_entropy_source`](crate::set_entropy_source), so it can't be replaced by the
+/// insecure or seeded generators. Without it (e.g. with the `fips` feature) the payload comes
+/// from the source set with `set_entropy_source` instead, and the constructors fail until one
+/// is set. Equality uses [`KsuidLike::ct_eq`], and `Debug` only shows the
+/// timestamp like [`Redacted`], so comparing or logging a token doesn't leak it.
+///
+/// # Examples
+/// ```
+/// use svix_ksuid::*;
+///
+/// let token: SecureKsuid = SecureKsuid::new();
+/// let ksuid: Ksuid = token.into_inner();
+///
+/// let token = SecureKsuid::<KsuidMs>::try_new().unwrap();
+/// println!("{}", token);
+/// ```
+#[derive(Clone, Copy)]
+pub struct SecureKsuid<K = Ksuid>(K);
+
+impl<K: KsuidLike<Type = K>> SecureKsuid<K> {
+    /// Create a new ksuid with the current time, panicking if the entropy source fails
+    pub fn new() -> Self {
+        Self::try_new().unwrap()
+    }
+
+    /// Create a new ksuid with the current time
+    pub fn try_new() -> Result<Self, Error> {
+        Self::try_from_system_time(SystemTime::now())
+    }
+
+    /// Create a new ksuid with the provided timestamp
+    ///
+    /// # Examples
+    /// ```
+    /// use std::time::{Duration, UNIX_EPOCH};
+    /// use svix_ksuid::*;
+    ///
+    /// let timestamp = UNIX_EPOCH + Duration::from_secs(1_621_627_443);
+    /// let token = SecureKsuid::<Ksuid>::try_from_system_time(timestamp).unwrap();
+    /// assert_eq!(token.timestamp_seconds(), 1_621_627_443);
+    /// ```
+    pub fn try_from_system_time(timestamp: SystemTime) -> Result<Self, Error> {
+        let mut payload = [0u8; TOTAL_BYTES];
+        let payload = &mut payload[..K::PAYLOAD_BYTES];
+        #[cfg(feature = "getrandom")]
+        entropy::fill(&mut OsEntropy, payload)?;
+        #[cfg(not(feature = "getrandom"))]
+        entropy::fill(&mut DefaultEntropy, payload)?;
+        Ok(Self(K::from_system_time_and_payload(timestamp, payload)))
+    }
+}
+
+/// The default is a new ksuid, like [`SecureKsuid::new`], never the nil one
+impl<K: KsuidLike<Type = K>> Default for SecureKsuid<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K> SecureKsuid<K> {
+    /// Get the wrapped ksuid
+    pub fn into_inner(self) -> K {
+        self.0
+    }
+}
+
+impl<K> Deref for SecureKsuid<K> {
+    type Target = K;
+
+    fn deref(&self) -> &K {
+        &self.0
+    }
+}
+
+impl<K> AsRef<K> for SecureKsuid<K> {
+    fn as_ref(&self) -> &K {
+        &self.0
+    }
+}
+
+impl From<SecureKsuid<Ksuid>> for Ksuid {
+    fn from(secure: SecureKsuid<Ksuid>) -> Self {
+        secure.0
+    }
+}
+
+impl From<SecureKsuid<KsuidMs>> for KsuidMs {
+    fn from(secure: SecureKsuid<KsuidMs>) -> Self {
+        secure.0
+    }
+}
+
+impl<K: KsuidLike> PartialEq for SecureKsuid<K> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl<K: KsuidLike> Eq for SecureKsuid<K> {}
+
+impl<K: KsuidLike + Ord> PartialOrd for SecureKsuid<K> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<K: KsuidLike + Ord> Ord for SecureKsuid<K> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl<K: Hash> Hash for SecureKsuid<K> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+impl<K: KsuidLike + Copy> fmt::Debug for SecureKsuid<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("SecureKsuid")
+            .field(&Redacted(self.0))
+            .finish()
+    }
+}
+
+impl<K: fmt::Display> fmt::Display for SecureKsuid<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
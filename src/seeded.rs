@@ -0,0 +1,39 @@
+use rand_chacha::rand_core::{Rng, SeedableRng};
+use rand_chacha::ChaCha20Rng;
+
+use crate::{EntropySource, Error};
+
+/// A seeded, cryptographically secure, random number generator for ksuid payloads
+///
+/// The generator is backed by ChaCha20, so two generators created from the same seed
+/// produce the exact same payloads. This makes it possible to reproduce a stream of
+/// ksuids, e.g. when replaying a recorded run. Keep the seed secret if the payloads need
+/// to be unguessable.
+///
+/// # Examples
+/// ```
+/// use svix_ksuid::*;
+///
+/// let mut rng1 = SeededRng::from_seed([7u8; 32]);
+/// let mut rng2 = SeededRng::from_seed([7u8; 32]);
+/// assert_eq!(
+///     Ksuid::new_seeded(None, &mut rng1).payload(),
+///     Ksuid::new_seeded(None, &mut rng2).payload(),
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct SeededRng(ChaCha20Rng);
+
+impl SeededRng {
+    /// Create a new generator from a 32 byte seed
+    pub fn from_seed(seed: [u8; 32]) -> Self {
+        Self(ChaCha20Rng::from_seed(seed))
+    }
+}
+
+impl EntropySource for SeededRng {
+    fn fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.0.fill_bytes(dest);
+        Ok(())
+    }
+}
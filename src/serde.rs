@@ -0,0 +1,296 @@
+//! Alternative serde representations, for use with `#[serde(with = "...")]`
+//!
+//! By default `Ksuid` and `KsuidMs` are serialized as base62 strings.
+
+/// (De)serialize a ksuid as a struct with its timestamp and payload
+///
+/// The timestamp is the unix timestamp in seconds, and the payload is the hex encoding of the
+/// remaining 16 bytes, e.g. `{"timestamp":1621627443,"payload":"0702cd8cbd6be3ab3ad26ece657abbee"}`.
+/// This follows the layout of the segment reference implementation, so for a `KsuidMs` the
+/// first byte of the payload holds the sub-second part of the timestamp.
+///
+/// # Examples
+/// ```
+/// use serde::{Deserialize, Serialize};
+/// use svix_ksuid::{Ksuid, KsuidLike};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Event {
+///     #[serde(with = "svix_ksuid::serde::expanded")]
+///     id: Ksuid,
+/// }
+///
+/// let event = Event {
+///     id: Ksuid::from_base62("1srOrx2ZWZBpBUvZwXKQmoEYga2").unwrap(),
+/// };
+/// let json = serde_json::to_string(&event).unwrap();
+/// assert_eq!(
+///     json,
+///     r#"{"id":{"timestamp":1621627443,"payload":"e1933e37f275708763adc7745af5e7f2"}}"#
+/// );
+/// let parsed: Event = serde_json::from_str(&json).unwrap();
+/// assert_eq!(parsed.id, event.id);
+/// ```
+pub mod expanded {
+    use ::serde::de::{self, Deserialize, Deserializer};
+    use ::serde::ser::{Serialize, Serializer};
+
+    use crate::{raw_seconds, Ksuid, KsuidLike, TimestampOverflow, TOTAL_BYTES_HEX};
+
+    #[derive(::serde::Serialize, ::serde::Deserialize)]
+    struct Expanded {
+        timestamp: i64,
+        payload: String,
+    }
+
+    /// Serialize a ksuid as a struct with its timestamp and payload
+    pub fn serialize<K: KsuidLike, S: Serializer>(
+        ksuid: &K,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let hex = ksuid.to_hex();
+        Expanded {
+            timestamp: Ksuid::from_bytes(*ksuid.bytes()).timestamp_seconds(),
+            payload: hex[Ksuid::TIMESTAMP_BYTES * 2..].to_owned(),
+        }
+        .serialize(serializer)
+    }
+
+    /// Deserialize a ksuid from a struct with its timestamp and payload
+    pub fn deserialize<'de, K, D>(deserializer: D) -> Result<K, D::Error>
+    where
+        K: KsuidLike<Type = K>,
+        D: Deserializer<'de>,
+    {
+        let expanded = Expanded::deserialize(deserializer)?;
+        let timestamp =
+            raw_seconds(expanded.timestamp, TimestampOverflow::Error).map_err(de::Error::custom)?;
+        if expanded.payload.len() != TOTAL_BYTES_HEX - Ksuid::TIMESTAMP_BYTES * 2 {
+            return Err(de::Error::custom(format!(
+                "Got hex payload of unexpected length {}",
+                expanded.payload.len()
+            )));
+        }
+        K::from_hex(&format!("{:08x}{}", timestamp, expanded.payload)).map_err(de::Error::custom)
+    }
+}
+
+/// (De)serialize an `Option` of a ksuid, treating empty strings as `None`
+///
+/// `None` is serialized as `null`, and both `null` and `""` are deserialized as `None`.
+///
+/// # Examples
+/// ```
+/// use serde::Deserialize;
+/// use svix_ksuid::Ksuid;
+///
+/// #[derive(Deserialize)]
+/// struct Event {
+///     #[serde(default, with = "svix_ksuid::serde::option_empty")]
+///     parent: Option<Ksuid>,
+/// }
+///
+/// let event: Event = serde_json::from_str(r#"{"parent":""}"#).unwrap();
+/// assert_eq!(event.parent, None);
+/// ```
+pub mod option_empty {
+    use ::serde::de::{self, Deserialize, Deserializer};
+    use ::serde::ser::Serializer;
+
+    use crate::{base62, KsuidLike};
+
+    /// Serialize an `Option` of a ksuid as either `null` or the base62 string
+    pub fn serialize<K: KsuidLike, S: Serializer>(
+        ksuid: &Option<K>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match ksuid {
+            Some(ksuid) => {
+                serializer.serialize_some(base62::as_str(&base62::encode(ksuid.bytes())))
+            }
+            None => serializer.serialize_none(),
+        }
+    }
+
+    /// Deserialize an `Option` of a ksuid, treating `null` and empty strings as `None`
+    pub fn deserialize<'de, K, D>(deserializer: D) -> Result<Option<K>, D::Error>
+    where
+        K: KsuidLike<Type = K>,
+        D: Deserializer<'de>,
+    {
+        match Option::<String>::deserialize(deserializer)? {
+            Some(s) if !s.is_empty() => K::from_base62(&s).map(Some).map_err(de::Error::custom),
+            _ => Ok(None),
+        }
+    }
+}
+
+/// (De)serialize a ksuid with a string prefix, e.g. `msg_1srOrx2ZWZBpBUvZwXKQmoEYga2`
+///
+/// The prefix is added when serializing and stripped when deserializing, where ksuids without
+/// the prefix are accepted as well. Use [`serde_prefixed!`](crate::serde_prefixed) to create a
+/// module for a specific prefix that can be used with `#[serde(with = "...")]`.
+pub mod prefixed {
+    use ::serde::de::{self, Deserialize, Deserializer};
+    use ::serde::ser::Serializer;
+
+    use crate::{base62, KsuidLike};
+
+    /// Serialize a ksuid as a base62 string with `prefix` prepended
+    pub fn serialize<K: KsuidLike, S: Serializer>(
+        prefix: &str,
+        ksuid: &K,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let encoded = base62::encode(ksuid.bytes());
+        serializer.collect_str(&format_args!("{}{}", prefix, base62::as_str(&encoded)))
+    }
+
+    /// Deserialize a ksuid from a base62 string that is optionally prefixed with `prefix`
+    pub fn deserialize<'de, K, D>(prefix: &str, deserializer: D) -> Result<K, D::Error>
+    where
+        K: KsuidLike<Type = K>,
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        let s = s.strip_prefix(prefix).unwrap_or(&s);
+        K::from_base62(s).map_err(de::Error::custom)
+    }
+}
+
+/// (De)serialize a ksuid as a ClickHouse `FixedString(20)` of its raw bytes
+///
+/// Use this with the `clickhouse` crate's `Row` types, so ksuid columns are sorted by their
+/// bytes (and so by time) instead of being stored as strings. The bytes are written as a
+/// 20 element tuple, which RowBinary encodes as the raw bytes, regardless of
+/// `is_human_readable()`, so this isn't meant for other formats. For
+/// `Nullable(FixedString(20))` columns use the `option` submodule.
+///
+/// # Examples
+/// ```
+/// use serde::{Deserialize, Serialize};
+/// use svix_ksuid::{Ksuid, KsuidLike};
+///
+/// // CREATE TABLE events (id FixedString(20), ...) ENGINE = MergeTree ORDER BY id
+/// #[derive(Serialize, Deserialize)]
+/// struct Event {
+///     #[serde(with = "svix_ksuid::serde::clickhouse")]
+///     id: Ksuid,
+///     #[serde(with = "svix_ksuid::serde::clickhouse::option")]
+///     parent: Option<Ksuid>,
+/// }
+/// ```
+#[cfg(feature = "clickhouse")]
+pub mod clickhouse {
+    use ::serde::de::{Deserialize, Deserializer};
+    use ::serde::ser::{Serialize, Serializer};
+
+    use crate::{KsuidLike, TOTAL_BYTES};
+
+    /// Serialize a ksuid as its raw bytes
+    pub fn serialize<K: KsuidLike, S: Serializer>(
+        ksuid: &K,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        ksuid.bytes().serialize(serializer)
+    }
+
+    /// Deserialize a ksuid from its raw bytes
+    pub fn deserialize<'de, K, D>(deserializer: D) -> Result<K, D::Error>
+    where
+        K: KsuidLike<Type = K>,
+        D: Deserializer<'de>,
+    {
+        <[u8; TOTAL_BYTES]>::deserialize(deserializer).map(K::from_bytes)
+    }
+
+    /// (De)serialize an `Option` of a ksuid as a ClickHouse `Nullable(FixedString(20))`
+    pub mod option {
+        use ::serde::de::{Deserialize, Deserializer};
+        use ::serde::ser::{Serialize, Serializer};
+
+        use crate::KsuidLike;
+
+        struct Wrapper<K>(K);
+
+        impl<K: KsuidLike> Serialize for Wrapper<&K> {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                super::serialize(self.0, serializer)
+            }
+        }
+
+        impl<'de, K: KsuidLike<Type = K>> Deserialize<'de> for Wrapper<K> {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                super::deserialize(deserializer).map(Wrapper)
+            }
+        }
+
+        /// Serialize an `Option` of a ksuid as either null or its raw bytes
+        pub fn serialize<K: KsuidLike, S: Serializer>(
+            ksuid: &Option<K>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            ksuid.as_ref().map(Wrapper).serialize(serializer)
+        }
+
+        /// Deserialize an `Option` of a ksuid from either null or its raw bytes
+        pub fn deserialize<'de, K, D>(deserializer: D) -> Result<Option<K>, D::Error>
+        where
+            K: KsuidLike<Type = K>,
+            D: Deserializer<'de>,
+        {
+            Option::<Wrapper<K>>::deserialize(deserializer).map(|ksuid| ksuid.map(|w| w.0))
+        }
+    }
+}
+
+#[doc(hidden)]
+pub mod __private {
+    pub use ::serde::{Deserializer, Serializer};
+}
+
+/// Create a module for (de)serializing ksuids with a string prefix using
+/// `#[serde(with = "...")]` (see [`serde::prefixed`](crate::serde::prefixed))
+///
+/// # Examples
+/// ```
+/// use serde::{Deserialize, Serialize};
+/// use svix_ksuid::{Ksuid, KsuidLike};
+///
+/// svix_ksuid::serde_prefixed!(mod msg_id, "msg_");
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Message {
+///     #[serde(with = "msg_id")]
+///     id: Ksuid,
+/// }
+///
+/// let json = r#"{"id":"msg_1srOrx2ZWZBpBUvZwXKQmoEYga2"}"#;
+/// let message: Message = serde_json::from_str(json).unwrap();
+/// assert_eq!(message.id.to_string(), "1srOrx2ZWZBpBUvZwXKQmoEYga2");
+/// assert_eq!(serde_json::to_string(&message).unwrap(), json);
+/// ```
+#[macro_export]
+macro_rules! serde_prefixed {
+    ($vis:vis mod $name:ident, $prefix:literal) => {
+        $vis mod $name {
+            /// Serialize a ksuid with the prefix prepended
+            pub fn serialize<K, S>(ksuid: &K, serializer: S) -> ::core::result::Result<S::Ok, S::Error>
+            where
+                K: $crate::KsuidLike,
+                S: $crate::serde::__private::Serializer,
+            {
+                $crate::serde::prefixed::serialize($prefix, ksuid, serializer)
+            }
+
+            /// Deserialize a ksuid, stripping the prefix if present
+            pub fn deserialize<'de, K, D>(deserializer: D) -> ::core::result::Result<K, D::Error>
+            where
+                K: $crate::KsuidLike<Type = K>,
+                D: $crate::serde::__private::Deserializer<'de>,
+            {
+                $crate::serde::prefixed::deserialize($prefix, deserializer)
+            }
+        }
+    };
+}
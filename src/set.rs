@@ -0,0 +1,264 @@
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::{Bound, RangeBounds};
+
+#[cfg(feature = "serde")]
+use ::serde::de::{Deserialize, Deserializer};
+#[cfg(feature = "serde")]
+use ::serde::ser::{Serialize, SerializeSeq, Serializer};
+
+use crate::{Ksuid, KsuidLike, TOTAL_BYTES};
+
+/// A sorted set of ksuids, stored as a sorted `Vec` of their bytes
+///
+/// This uses far less memory than a `BTreeSet<Ksuid>` and has much better memory locality for
+/// lookups and iteration, at the cost of `O(n)` inserts and removals. Prefer building sets
+/// with `collect()` or [`KsuidSet::extend`], which sort once, over many calls to
+/// [`KsuidSet::insert`].
+///
+/// With the `serde` feature it's serialized as a sequence of base62 strings.
+///
+/// # Examples
+/// ```
+/// use svix_ksuid::*;
+///
+/// let a = Ksuid::from_seconds(Some(1_621_627_443), None);
+/// let b = Ksuid::from_seconds(Some(1_621_627_444), None);
+/// let c = Ksuid::from_seconds(Some(1_621_627_445), None);
+///
+/// let set: KsuidSet = [c, a, b, a].into_iter().collect();
+/// assert_eq!(set.len(), 3);
+/// assert!(set.contains(&b));
+/// assert_eq!(set.range(a..c).collect::<Vec<_>>(), vec![a, b]);
+/// ```
+pub struct KsuidSet<K = Ksuid> {
+    ids: Vec<[u8; TOTAL_BYTES]>,
+    ksuid: PhantomData<K>,
+}
+
+impl<K: KsuidLike<Type = K>> KsuidSet<K> {
+    /// Create a new empty set
+    pub fn new() -> Self {
+        Self::from_sorted(Vec::new())
+    }
+
+    /// Create a new empty set with space for at least `capacity` ksuids
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::from_sorted(Vec::with_capacity(capacity))
+    }
+
+    fn from_sorted(ids: Vec<[u8; TOTAL_BYTES]>) -> Self {
+        Self {
+            ids,
+            ksuid: PhantomData,
+        }
+    }
+
+    /// The number of ksuids in the set
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    /// Whether the set is empty
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+
+    /// Add a ksuid to the set, returning whether it wasn't already in it
+    pub fn insert(&mut self, ksuid: K) -> bool {
+        match self.ids.binary_search(ksuid.bytes()) {
+            Ok(_) => false,
+            Err(index) => {
+                self.ids.insert(index, *ksuid.bytes());
+                true
+            }
+        }
+    }
+
+    /// Remove a ksuid from the set, returning whether it was in it
+    pub fn remove(&mut self, ksuid: &K) -> bool {
+        match self.ids.binary_search(ksuid.bytes()) {
+            Ok(index) => {
+                self.ids.remove(index);
+                true
+            }
+            Err(_) => false,
+        }
+    }
+
+    /// Whether the set contains the ksuid
+    pub fn contains(&self, ksuid: &K) -> bool {
+        self.ids.binary_search(ksuid.bytes()).is_ok()
+    }
+
+    /// The smallest ksuid in the set
+    pub fn first(&self) -> Option<K> {
+        self.ids.first().map(|bytes| K::from_bytes(*bytes))
+    }
+
+    /// The largest ksuid in the set
+    pub fn last(&self) -> Option<K> {
+        self.ids.last().map(|bytes| K::from_bytes(*bytes))
+    }
+
+    /// Iterate over the ksuids in ascending order
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = K> + ExactSizeIterator + '_ {
+        self.ids.iter().map(|bytes| K::from_bytes(*bytes))
+    }
+
+    /// Iterate over the ksuids within `range` in ascending order
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    ///
+    /// let ids: Vec<Ksuid> = (0..10)
+    ///     .map(|i| Ksuid::from_seconds(Some(1_621_627_443 + i), None))
+    ///     .collect();
+    /// let set: KsuidSet = ids.iter().copied().collect();
+    /// assert_eq!(set.range(ids[2]..=ids[4]).count(), 3);
+    /// assert_eq!(set.range(..ids[2]).count(), 2);
+    /// ```
+    pub fn range<R: RangeBounds<K>>(
+        &self,
+        range: R,
+    ) -> impl DoubleEndedIterator<Item = K> + ExactSizeIterator + '_ {
+        let start = match range.start_bound() {
+            Bound::Included(ksuid) => self.lower_bound(ksuid),
+            Bound::Excluded(ksuid) => self.upper_bound(ksuid),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(ksuid) => self.upper_bound(ksuid),
+            Bound::Excluded(ksuid) => self.lower_bound(ksuid),
+            Bound::Unbounded => self.ids.len(),
+        };
+        self.ids[start..end.max(start)]
+            .iter()
+            .map(|bytes| K::from_bytes(*bytes))
+    }
+
+    /// The index of the first id that isn't less than `ksuid`
+    fn lower_bound(&self, ksuid: &K) -> usize {
+        self.ids.partition_point(|bytes| bytes < ksuid.bytes())
+    }
+
+    /// The index of the first id that's greater than `ksuid`
+    fn upper_bound(&self, ksuid: &K) -> usize {
+        self.ids.partition_point(|bytes| bytes <= ksuid.bytes())
+    }
+
+    /// The ksuids that are in either set
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    ///
+    /// let a = Ksuid::new(None, None);
+    /// let b = Ksuid::new(None, None);
+    /// let first: KsuidSet = [a].into_iter().collect();
+    /// let second: KsuidSet = [a, b].into_iter().collect();
+    /// assert_eq!(first.union(&second).len(), 2);
+    /// assert_eq!(first.intersection(&second).iter().collect::<Vec<_>>(), vec![a]);
+    /// ```
+    pub fn union(&self, other: &Self) -> Self {
+        let mut ids = Vec::with_capacity(self.ids.len() + other.ids.len());
+        let (mut left, mut right) = (self.ids.iter().peekable(), other.ids.iter().peekable());
+        loop {
+            let next = match (left.peek(), right.peek()) {
+                (Some(a), Some(b)) if a < b => left.next(),
+                (Some(a), Some(b)) if a > b => right.next(),
+                (Some(_), Some(_)) => {
+                    right.next();
+                    left.next()
+                }
+                (Some(_), None) => left.next(),
+                (None, Some(_)) => right.next(),
+                (None, None) => break,
+            };
+            ids.extend(next);
+        }
+        Self::from_sorted(ids)
+    }
+
+    /// The ksuids that are in both sets
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ids = Vec::new();
+        let (mut left, mut right) = (self.ids.iter().peekable(), other.ids.iter().peekable());
+        while let (Some(a), Some(b)) = (left.peek(), right.peek()) {
+            if a < b {
+                left.next();
+            } else if a > b {
+                right.next();
+            } else {
+                ids.extend(left.next());
+                right.next();
+            }
+        }
+        Self::from_sorted(ids)
+    }
+}
+
+impl<K: KsuidLike<Type = K>> Default for KsuidSet<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K> Clone for KsuidSet<K> {
+    fn clone(&self) -> Self {
+        Self {
+            ids: self.ids.clone(),
+            ksuid: PhantomData,
+        }
+    }
+}
+
+impl<K> PartialEq for KsuidSet<K> {
+    fn eq(&self, other: &Self) -> bool {
+        self.ids == other.ids
+    }
+}
+
+impl<K> Eq for KsuidSet<K> {}
+
+impl<K: KsuidLike<Type = K> + fmt::Debug> fmt::Debug for KsuidSet<K> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<K: KsuidLike<Type = K>> FromIterator<K> for KsuidSet<K> {
+    fn from_iter<I: IntoIterator<Item = K>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<K: KsuidLike<Type = K>> Extend<K> for KsuidSet<K> {
+    fn extend<I: IntoIterator<Item = K>>(&mut self, iter: I) {
+        self.ids
+            .extend(iter.into_iter().map(|ksuid| *ksuid.bytes()));
+        self.ids.sort_unstable();
+        self.ids.dedup();
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<K: KsuidLike<Type = K> + Serialize> Serialize for KsuidSet<K> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for ksuid in self.iter() {
+            seq.serialize_element(&ksuid)?;
+        }
+        seq.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, K: KsuidLike<Type = K> + Deserialize<'de>> Deserialize<'de> for KsuidSet<K> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Vec::<K>::deserialize(deserializer).map(|ksuids| ksuids.into_iter().collect())
+    }
+}
@@ -0,0 +1,72 @@
+use std::fmt;
+
+use crate::{base62, TOTAL_BYTES, TOTAL_BYTES_BASE62};
+
+/// An abbreviated base62 ksuid for log lines, created with [`KsuidLike::short`]
+///
+/// This displays the first [`ShortKsuid::DEFAULT_LEN`] base62 characters by default, or the
+/// number set with [`ShortKsuid::with_len`]. The leading characters mostly encode the
+/// timestamp, so ids created in the same second share most of their prefix; use a longer
+/// prefix when many ids are created at once. Use [`KsuidLike::matches_short`] to find the full
+/// ids matching a prefix from a log.
+///
+/// [`KsuidLike::short`]: crate::KsuidLike::short
+/// [`KsuidLike::matches_short`]: crate::KsuidLike::matches_short
+///
+/// # Examples
+/// ```
+/// use svix_ksuid::*;
+///
+/// let ksuid = Ksuid::from_base62("1srOrx2ZWZBpBUvZwXKQmoEYga2").unwrap();
+/// assert_eq!(ksuid.short().to_string(), "1srOrx2Z");
+/// assert_eq!(format!("id={}", ksuid.short().with_len(12)), "id=1srOrx2ZWZBp");
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ShortKsuid {
+    base62: [u8; TOTAL_BYTES_BASE62],
+    len: usize,
+}
+
+impl ShortKsuid {
+    /// The number of base62 characters shown by default
+    pub const DEFAULT_LEN: usize = 8;
+
+    pub(crate) fn new(bytes: &[u8; TOTAL_BYTES]) -> Self {
+        Self {
+            base62: base62::encode(bytes),
+            len: Self::DEFAULT_LEN,
+        }
+    }
+
+    /// Show `len` base62 characters instead, up to the full 27
+    ///
+    /// # Examples
+    /// ```
+    /// use svix_ksuid::*;
+    ///
+    /// let ksuid = Ksuid::new(None, None);
+    /// assert_eq!(ksuid.short().with_len(4).as_str().len(), 4);
+    /// assert_eq!(ksuid.short().with_len(100).as_str(), ksuid.to_string());
+    /// ```
+    pub fn with_len(mut self, len: usize) -> Self {
+        self.len = len.min(TOTAL_BYTES_BASE62);
+        self
+    }
+
+    /// Get the abbreviated base62 string
+    pub fn as_str(&self) -> &str {
+        &base62::as_str(&self.base62)[..self.len]
+    }
+}
+
+impl fmt::Display for ShortKsuid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.as_str())
+    }
+}
+
+impl fmt::Debug for ShortKsuid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ShortKsuid").field(&self.as_str()).finish()
+    }
+}
@@ -0,0 +1,125 @@
+//! Helpers for sorting, deduplicating and encoding slices of ksuids
+//!
+//! These order ksuids by their 20 raw bytes, the same as comparing the ksuids (or their base62
+//! strings), without round-tripping through strings.
+
+use crate::{base62, KsuidLike, TOTAL_BYTES, TOTAL_BYTES_BASE62};
+
+/// Below this length a comparison sort is faster than a radix sort
+const RADIX_THRESHOLD: usize = 256;
+
+/// Sort a slice of ksuids in ascending order
+///
+/// Large slices are sorted with an LSD radix sort over the 20 bytes, which allocates a
+/// scratch copy of the slice. Bytes that are the same for all ksuids, e.g. the leading bytes
+/// of the timestamp, are skipped. Small slices use an unstable comparison sort instead.
+///
+/// # Examples
+/// ```
+/// use svix_ksuid::{slice, Ksuid, KsuidLike};
+///
+/// let mut ids: Vec<Ksuid> = (0..1000).map(|_| Ksuid::new(None, None)).collect();
+/// slice::sort(&mut ids);
+/// assert!(slice::is_sorted(&ids));
+/// ```
+pub fn sort<K: KsuidLike + Copy>(ids: &mut [K]) {
+    if ids.len() < RADIX_THRESHOLD {
+        ids.sort_unstable_by(|a, b| a.bytes().cmp(b.bytes()));
+        return;
+    }
+
+    // The counts don't change between passes, so get them all up front
+    let mut counts = vec![[0usize; 256]; TOTAL_BYTES];
+    for id in ids.iter() {
+        for (count, &byte) in counts.iter_mut().zip(id.bytes()) {
+            count[byte as usize] += 1;
+        }
+    }
+
+    let len = ids.len();
+    let mut scratch = ids.to_vec();
+    let mut src: &mut [K] = ids;
+    let mut dst: &mut [K] = &mut scratch;
+    let mut in_scratch = false;
+    for (i, count) in counts.iter().enumerate().rev() {
+        if count.contains(&len) {
+            continue;
+        }
+        let mut offsets = [0usize; 256];
+        let mut total = 0;
+        for (offset, &n) in offsets.iter_mut().zip(count) {
+            *offset = total;
+            total += n;
+        }
+        for id in src.iter() {
+            let offset = &mut offsets[id.bytes()[i] as usize];
+            dst[*offset] = *id;
+            *offset += 1;
+        }
+        std::mem::swap(&mut src, &mut dst);
+        in_scratch = !in_scratch;
+    }
+    if in_scratch {
+        // `dst` is the original slice
+        dst.copy_from_slice(src);
+    }
+}
+
+/// Remove consecutive duplicates from a sorted `Vec` of ksuids
+///
+/// # Examples
+/// ```
+/// use svix_ksuid::{slice, Ksuid, KsuidLike};
+///
+/// let a = Ksuid::new(None, None);
+/// let b = Ksuid::new(None, None);
+/// let mut ids = vec![a, b, a, b, a];
+/// slice::sort(&mut ids);
+/// slice::dedup_sorted(&mut ids);
+/// assert_eq!(ids.len(), 2);
+/// ```
+pub fn dedup_sorted<K: KsuidLike>(ids: &mut Vec<K>) {
+    ids.dedup_by(|a, b| a.bytes() == b.bytes());
+}
+
+/// Check whether a slice of ksuids is sorted in ascending order
+///
+/// Duplicates are allowed, so this is also true after [`sort`] but before [`dedup_sorted`].
+///
+/// # Examples
+/// ```
+/// use svix_ksuid::{slice, Ksuid, KsuidLike};
+///
+/// let a = Ksuid::from_seconds(Some(1_621_627_443), None);
+/// let b = Ksuid::from_seconds(Some(1_621_627_444), None);
+/// assert!(slice::is_sorted(&[a, a, b]));
+/// assert!(!slice::is_sorted(&[b, a]));
+/// ```
+pub fn is_sorted<K: KsuidLike>(ids: &[K]) -> bool {
+    ids.windows(2).all(|w| w[0].bytes() <= w[1].bytes())
+}
+
+/// Append the base62 ksuids to `out`, separated by `sep`
+///
+/// The capacity for all of them is reserved up front, and each ksuid is encoded into the same
+/// stack buffer, so this doesn't allocate per ksuid like `to_string()` does. Nothing is added
+/// before the first or after the last ksuid.
+///
+/// # Examples
+/// ```
+/// use svix_ksuid::{slice, Ksuid, KsuidLike};
+///
+/// let ids = [Ksuid::new(None, None), Ksuid::new(None, None)];
+/// let mut out = String::from("ids: ");
+/// slice::encode_all(&ids, &mut out, ", ");
+/// assert_eq!(out, format!("ids: {}, {}", ids[0], ids[1]));
+/// ```
+pub fn encode_all<K: KsuidLike>(ids: &[K], out: &mut String, sep: &str) {
+    out.reserve(ids.len() * TOTAL_BYTES_BASE62 + ids.len().saturating_sub(1) * sep.len());
+    for (i, id) in ids.iter().enumerate() {
+        if i != 0 {
+            out.push_str(sep);
+        }
+        out.push_str(base62::as_str(&base62::encode(id.bytes())));
+    }
+}
@@ -0,0 +1,202 @@
+//! Helpers for tests
+//!
+//! These are only meant for tests, demos and benchmarks, the randomness used to spread the
+//! timestamps is not uniform enough for anything else.
+
+use std::sync::{Arc, Mutex, MutexGuard};
+#[cfg(feature = "getrandom")]
+use std::time::UNIX_EPOCH;
+use std::time::{Duration, SystemTime};
+
+use crate::Clock;
+#[cfg(feature = "getrandom")]
+use crate::{entropy, KsuidLike};
+
+/// A clock that only moves when told to, for use with [`Generator`](crate::Generator)
+///
+/// Clones share the same time, so a test can keep a clone around to control the clock that
+/// was handed to a generator. The clock starts out frozen, use [`MockClock::step`] to have it
+/// move forward every time it's read instead.
+///
+/// # Examples
+/// ```
+/// use svix_ksuid::testing::MockClock;
+/// use svix_ksuid::*;
+/// use std::time::{Duration, UNIX_EPOCH};
+///
+/// let clock = MockClock::new(UNIX_EPOCH + Duration::from_secs(1_621_627_443));
+/// let mut generator = Generator::new().with_clock(clock.clone());
+///
+/// let first: Ksuid = generator.generate();
+/// clock.advance(Duration::from_secs(10));
+/// let second: Ksuid = generator.generate();
+/// assert_eq!(second.timestamp_seconds() - first.timestamp_seconds(), 10);
+/// ```
+#[derive(Debug, Clone)]
+pub struct MockClock {
+    state: Arc<Mutex<MockClockState>>,
+}
+
+#[derive(Debug)]
+struct MockClockState {
+    now: SystemTime,
+    step: Duration,
+}
+
+impl MockClock {
+    /// Create a new clock frozen at `now`
+    pub fn new(now: SystemTime) -> Self {
+        Self {
+            state: Arc::new(Mutex::new(MockClockState {
+                now,
+                step: Duration::ZERO,
+            })),
+        }
+    }
+
+    /// Stop the clock from moving when it's read (see [`MockClock::step`])
+    pub fn freeze(&self) {
+        self.state().step = Duration::ZERO;
+    }
+
+    /// Move the clock forward by `step` after every read
+    pub fn step(&self, step: Duration) {
+        self.state().step = step;
+    }
+
+    /// Move the clock forward by `duration`
+    pub fn advance(&self, duration: Duration) {
+        self.state().now += duration;
+    }
+
+    /// Set the clock to `now`, which may also be in the past
+    pub fn jump(&self, now: SystemTime) {
+        self.state().now = now;
+    }
+
+    fn state(&self) -> MutexGuard<'_, MockClockState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> SystemTime {
+        let mut state = self.state();
+        let now = state.now;
+        state.now = now + state.step;
+        now
+    }
+}
+
+/// How to distribute the timestamps of generated ksuids
+#[cfg(feature = "getrandom")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Distribution {
+    /// Timestamps are spread uniformly across the time range
+    #[default]
+    Uniform,
+    /// Timestamps are grouped in the given number of bursts, placed randomly in the time range
+    Clustered(usize),
+}
+
+/// Create `n` ksuids with random payloads and timestamps spread uniformly between `from` and
+/// `to` (inclusive), sorted in ascending order
+///
+/// See [`spread_with`] for how `from` and `to` are rounded to the precision of the timestamps.
+///
+/// # Examples
+/// ```
+/// use svix_ksuid::*;
+/// use std::time::{Duration, UNIX_EPOCH};
+///
+/// let from = UNIX_EPOCH + Duration::from_secs(1_600_000_000);
+/// let to = from + Duration::from_secs(24 * 60 * 60);
+/// let ksuids: Vec<Ksuid> = testing::spread(100, from, to);
+/// assert_eq!(ksuids.len(), 100);
+/// assert!(ksuids.iter().all(|k| from <= k.system_time() && k.system_time() <= to));
+/// ```
+#[cfg(feature = "getrandom")]
+pub fn spread<K: KsuidLike<Type = K>>(n: usize, from: SystemTime, to: SystemTime) -> Vec<K> {
+    spread_with(n, from, to, Distribution::Uniform)
+}
+
+/// Create `n` ksuids with random payloads and timestamps between `from` and `to` (inclusive)
+/// following `distribution`, sorted in ascending order
+///
+/// The order of `from` and `to` doesn't matter. Timestamps are truncated to
+/// [`KsuidLike::PRECISION`], so the earlier one is rounded up to it first, to keep the ksuids
+/// from ending up before it. If no timestamp of that precision lies between the two, all
+/// ksuids get the later one, truncated.
+///
+/// # Examples
+/// ```
+/// use svix_ksuid::testing::{spread_with, Distribution};
+/// use svix_ksuid::*;
+/// use std::time::{Duration, UNIX_EPOCH};
+///
+/// let from = UNIX_EPOCH + Duration::from_secs(1_600_000_000);
+/// let to = from + Duration::from_secs(24 * 60 * 60);
+/// let ksuids: Vec<KsuidMs> = spread_with(100, from, to, Distribution::Clustered(3));
+/// assert!(ksuids.windows(2).all(|w| w[0] <= w[1]));
+/// ```
+#[cfg(feature = "getrandom")]
+pub fn spread_with<K: KsuidLike<Type = K>>(
+    n: usize,
+    from: SystemTime,
+    to: SystemTime,
+    distribution: Distribution,
+) -> Vec<K> {
+    let (from, to) = if from <= to { (from, to) } else { (to, from) };
+    let from = round_up(from, K::PRECISION.as_duration()).min(to);
+    let range = millis(to.duration_since(from).unwrap());
+
+    let offsets: Vec<u64> = match distribution {
+        Distribution::Uniform => (0..n).map(|_| random_below(range + 1)).collect(),
+        Distribution::Clustered(clusters) => {
+            let centers: Vec<u64> = (0..clusters.max(1))
+                .map(|_| random_below(range + 1))
+                .collect();
+            // Each burst covers roughly 1% of the range
+            let radius = range / 200;
+            (0..n)
+                .map(|_| {
+                    let center = centers[random_below(centers.len() as u64) as usize];
+                    let offset = random_below(2 * radius + 1);
+                    (center + offset).saturating_sub(radius).min(range)
+                })
+                .collect()
+        }
+    };
+
+    let mut ksuids: Vec<K> = offsets
+        .into_iter()
+        .map(|offset| K::from_system_time(from + Duration::from_millis(offset), None))
+        .collect();
+    ksuids.sort_by(|a, b| a.bytes().cmp(b.bytes()));
+    ksuids
+}
+
+/// Round `time` up to a multiple of `precision` since the unix epoch
+#[cfg(feature = "getrandom")]
+fn round_up(time: SystemTime, precision: Duration) -> SystemTime {
+    let since_epoch = time.duration_since(UNIX_EPOCH).unwrap_or_default();
+    let remainder = since_epoch.as_nanos() % precision.as_nanos();
+    if remainder == 0 {
+        time
+    } else {
+        time + (precision - Duration::from_nanos(remainder as u64))
+    }
+}
+
+#[cfg(feature = "getrandom")]
+fn millis(duration: Duration) -> u64 {
+    u64::try_from(duration.as_millis()).unwrap_or(u64::MAX)
+}
+
+/// A random number in `0..n` (with a slight modulo bias), `n` must not be zero
+#[cfg(feature = "getrandom")]
+fn random_below(n: u64) -> u64 {
+    let mut buf = [0u8; 8];
+    entropy::fill_random(&mut buf);
+    u64::from_le_bytes(buf) % n
+}
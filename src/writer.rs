@@ -0,0 +1,71 @@
+use std::io::{self, BufWriter, Write};
+
+use crate::{base62, KsuidLike};
+
+/// Writes many base62 ksuids to an `io::Write`, separated by a configurable separator
+///
+/// Writes are buffered, so there's no need to wrap the writer in a `BufWriter`. The buffer
+/// is flushed when the `KsuidWriter` is dropped, but errors are ignored at that point, so
+/// prefer calling [`KsuidWriter::flush`] or [`KsuidWriter::into_inner`] explicitly.
+///
+/// # Examples
+/// ```
+/// use svix_ksuid::*;
+///
+/// let ids = vec![Ksuid::new(None, None), Ksuid::new(None, None)];
+/// let mut writer = KsuidWriter::with_separator(Vec::new(), b",");
+/// writer.write_all(&ids).unwrap();
+/// let out = writer.into_inner().unwrap();
+/// assert_eq!(out, format!("{},{}", ids[0], ids[1]).into_bytes());
+/// ```
+pub struct KsuidWriter<W: Write> {
+    inner: BufWriter<W>,
+    separator: Vec<u8>,
+    first: bool,
+}
+
+impl<W: Write> KsuidWriter<W> {
+    /// Create a new writer separating ksuids with newlines
+    pub fn new(inner: W) -> Self {
+        Self::with_separator(inner, b"\n")
+    }
+
+    /// Create a new writer separating ksuids with the provided separator
+    pub fn with_separator(inner: W, separator: &[u8]) -> Self {
+        Self {
+            inner: BufWriter::new(inner),
+            separator: separator.to_vec(),
+            first: true,
+        }
+    }
+
+    /// Write a single ksuid, preceded by the separator if it's not the first one
+    pub fn write<K: KsuidLike>(&mut self, ksuid: &K) -> io::Result<()> {
+        if !self.first {
+            self.inner.write_all(&self.separator)?;
+        }
+        self.first = false;
+        self.inner.write_all(&base62::encode(ksuid.bytes()))
+    }
+
+    /// Write all of the ksuids
+    pub fn write_all<'a, K: KsuidLike + 'a>(
+        &mut self,
+        ksuids: impl IntoIterator<Item = &'a K>,
+    ) -> io::Result<()> {
+        for ksuid in ksuids {
+            self.write(ksuid)?;
+        }
+        Ok(())
+    }
+
+    /// Flush the buffered data to the underlying writer
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+
+    /// Flush the buffered data and return the underlying writer
+    pub fn into_inner(self) -> io::Result<W> {
+        self.inner.into_inner().map_err(|e| e.into_error())
+    }
+}
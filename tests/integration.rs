@@ -1,7 +1,9 @@
-use serde::Deserialize;
+#![cfg(feature = "getrandom")]
+
+use ::serde::Deserialize;
 #[cfg(feature = "serde")]
-use serde::Serialize;
-use std::collections::HashSet;
+use ::serde::Serialize;
+use std::collections::{HashMap, HashSet};
 use std::hash::{Hash, Hasher};
 use std::{
     fs::File,
@@ -112,8 +114,8 @@ fn test_hash() {
     let ksuid1 = Ksuid::new(None, None);
     let ksuid2 = Ksuid::new(None, None);
     // when
-    set.insert(ksuid1.clone());
-    set.insert(ksuid2.clone());
+    set.insert(ksuid1);
+    set.insert(ksuid2);
     // then
     assert_eq!(set.len(), 2);
     assert!(set.contains(&ksuid1));
@@ -127,8 +129,8 @@ fn test_hash_ms() {
     let ksuidms1 = KsuidMs::new(None, None);
     let ksuidms2 = KsuidMs::new(None, None);
     // when
-    set.insert(ksuidms1.clone());
-    set.insert(ksuidms2.clone());
+    set.insert(ksuidms1);
+    set.insert(ksuidms2);
     // then
     assert_eq!(set.len(), 2);
     assert!(set.contains(&ksuidms1));
@@ -204,6 +206,190 @@ fn test_deserialize_from_base62() {
     assert_eq!(ksuidms_obj.id.to_string(), b62);
 }
 
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde_expanded() {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Row {
+        #[serde(with = "svix_ksuid::serde::expanded")]
+        id: Ksuid,
+        #[serde(with = "svix_ksuid::serde::expanded")]
+        idms: KsuidMs,
+    }
+
+    let row = Row {
+        id: Ksuid::from_seconds(Some(1_621_627_443), Some(&[0xAB; 16])),
+        idms: KsuidMs::from_millis(Some(1_621_627_443_124), Some(&[0xCD; 15])),
+    };
+    let value = serde_json::to_value(&row).unwrap();
+    assert_eq!(
+        value,
+        serde_json::json!({
+            "id": {"timestamp": 1_621_627_443, "payload": "ab".repeat(16)},
+            "idms": {"timestamp": 1_621_627_443, "payload": format!("1f{}", "cd".repeat(15))},
+        })
+    );
+    assert_eq!(serde_json::from_value::<Row>(value).unwrap(), row);
+
+    let bad = [
+        // Out of range timestamp
+        serde_json::json!({"timestamp": 0, "payload": "ab".repeat(16)}),
+        // Short payload
+        serde_json::json!({"timestamp": 1_621_627_443, "payload": "abcd"}),
+        // Not hex
+        serde_json::json!({"timestamp": 1_621_627_443, "payload": "zz".repeat(16)}),
+        // Missing fields
+        serde_json::json!({"timestamp": 1_621_627_443}),
+    ];
+    for id in bad {
+        let value = serde_json::json!({"id": id.clone(), "idms": id});
+        assert!(serde_json::from_value::<Row>(value).is_err());
+    }
+}
+
+#[cfg(feature = "clickhouse")]
+#[test]
+fn test_serde_clickhouse() {
+    use serde_test::{assert_tokens, Configure, Token};
+
+    #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+    struct Row {
+        #[serde(with = "svix_ksuid::serde::clickhouse")]
+        id: Ksuid,
+        #[serde(with = "svix_ksuid::serde::clickhouse::option")]
+        parent: Option<KsuidMs>,
+    }
+
+    let b62 = "1srOrx2ZWZBpBUvZwXKQmoEYga2";
+    let id = Ksuid::from_base62(b62).unwrap();
+    let row = Row {
+        id,
+        parent: Some(KsuidMs::from_bytes(*id.bytes())),
+    };
+
+    // FixedString(20) is the 20 raw bytes in RowBinary
+    let fixed_string = || {
+        let mut tokens = vec![Token::Tuple { len: 20 }];
+        tokens.extend(id.bytes().iter().map(|&b| Token::U8(b)));
+        tokens.push(Token::TupleEnd);
+        tokens
+    };
+    let mut tokens = vec![
+        Token::Struct {
+            name: "Row",
+            len: 2,
+        },
+        Token::Str("id"),
+    ];
+    tokens.extend(fixed_string());
+    tokens.extend([Token::Str("parent"), Token::Some]);
+    tokens.extend(fixed_string());
+    tokens.push(Token::StructEnd);
+    assert_tokens(&row.compact(), &tokens);
+    assert_tokens(&row.readable(), &tokens);
+
+    let row = Row { id, parent: None };
+    let mut tokens = vec![
+        Token::Struct {
+            name: "Row",
+            len: 2,
+        },
+        Token::Str("id"),
+    ];
+    tokens.extend(fixed_string());
+    tokens.extend([Token::Str("parent"), Token::None, Token::StructEnd]);
+    assert_tokens(&row.compact(), &tokens);
+
+    // The bytes are written whatever the serializer reports from `is_human_readable()`
+    assert_tokens(&row.readable(), &tokens);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde_option_empty() {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Row {
+        #[serde(default, with = "svix_ksuid::serde::option_empty")]
+        id: Option<Ksuid>,
+        #[serde(default, with = "svix_ksuid::serde::option_empty")]
+        idms: Option<KsuidMs>,
+    }
+
+    let b62 = "1srOrx2ZWZBpBUvZwXKQmoEYga2";
+    let row: Row = serde_json::from_str(&format!(r#"{{"id":"{b62}","idms":"{b62}"}}"#)).unwrap();
+    assert_eq!(row.id, Some(Ksuid::from_base62(b62).unwrap()));
+    assert_eq!(row.idms, Some(KsuidMs::from_base62(b62).unwrap()));
+    assert_eq!(
+        serde_json::to_string(&row).unwrap(),
+        format!(r#"{{"id":"{b62}","idms":"{b62}"}}"#)
+    );
+
+    let empty = Row {
+        id: None,
+        idms: None,
+    };
+    for json in [r#"{"id":"","idms":""}"#, r#"{"id":null,"idms":null}"#, "{}"] {
+        assert_eq!(serde_json::from_str::<Row>(json).unwrap(), empty);
+    }
+    assert_eq!(
+        serde_json::to_string(&empty).unwrap(),
+        r#"{"id":null,"idms":null}"#
+    );
+
+    assert!(serde_json::from_str::<Row>(r#"{"id":"invalid"}"#).is_err());
+    assert!(serde_json::from_str::<Row>(r#"{"id":12}"#).is_err());
+}
+
+#[cfg(feature = "serde")]
+svix_ksuid::serde_prefixed!(mod msg_id, "msg_");
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde_prefixed() {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Message {
+        #[serde(with = "msg_id")]
+        id: Ksuid,
+        #[serde(with = "msg_id")]
+        idms: KsuidMs,
+    }
+
+    let b62 = "1srOrx2ZWZBpBUvZwXKQmoEYga2";
+    let message = Message {
+        id: Ksuid::from_base62(b62).unwrap(),
+        idms: KsuidMs::from_base62(b62).unwrap(),
+    };
+    let json = format!(r#"{{"id":"msg_{b62}","idms":"msg_{b62}"}}"#);
+    assert_eq!(serde_json::to_string(&message).unwrap(), json);
+    assert_eq!(serde_json::from_str::<Message>(&json).unwrap(), message);
+
+    // The prefix is optional when deserializing
+    let json = format!(r#"{{"id":"{b62}","idms":"msg_{b62}"}}"#);
+    assert_eq!(serde_json::from_str::<Message>(&json).unwrap(), message);
+
+    // But other prefixes aren't accepted
+    let json = format!(r#"{{"id":"usr_{b62}","idms":"msg_{b62}"}}"#);
+    assert!(serde_json::from_str::<Message>(&json).is_err());
+    let json = r#"{"id":"msg_","idms":"msg_"}"#;
+    assert!(serde_json::from_str::<Message>(json).is_err());
+
+    // Without the macro
+    #[derive(Serialize)]
+    struct Wrapper(#[serde(serialize_with = "ws_serialize")] Ksuid);
+
+    fn ws_serialize<S: ::serde::Serializer>(
+        ksuid: &Ksuid,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        svix_ksuid::serde::prefixed::serialize("ws_", ksuid, serializer)
+    }
+
+    assert_eq!(
+        serde_json::to_value(Wrapper(message.id)).unwrap(),
+        serde_json::json!(format!("ws_{b62}"))
+    );
+}
+
 #[test]
 fn test_deserialize_bad_base62_length() {
     let short_b62 = "ZBpBUvZwXKQmoEYga2";
@@ -215,3 +401,3143 @@ fn test_deserialize_bad_base62_length() {
     let result = Ksuid::from_base62(long_b62);
     assert!(result.is_err(), "Long base62 strings should fail to parse");
 }
+
+#[test]
+fn test_parts_roundtrip() {
+    let ksuid = Ksuid::new(None, None);
+    let (timestamp, payload) = ksuid.to_parts();
+    assert_eq!(Ksuid::from_parts(timestamp, payload), ksuid);
+
+    let ksuidms = KsuidMs::new(None, None);
+    let (timestamp, payload) = ksuidms.to_parts();
+    assert_eq!(KsuidMs::from_parts(timestamp, payload), ksuidms);
+}
+
+#[test]
+fn test_borrow_as_bytes() {
+    let ksuid = Ksuid::new(None, None);
+    let ksuidms = KsuidMs::new(None, None);
+    assert_eq!(ksuid.as_ref(), &ksuid.bytes()[..]);
+    assert_eq!(ksuidms.as_ref(), &ksuidms.bytes()[..]);
+
+    let mut map = HashMap::new();
+    map.insert(ksuid, "ksuid");
+    let key: &[u8] = &ksuid.bytes()[..];
+    assert_eq!(map.get(key), Some(&"ksuid"));
+
+    let mut map = HashMap::new();
+    map.insert(ksuidms, "ksuidms");
+    let key: &[u8] = &ksuidms.bytes()[..];
+    assert_eq!(map.get(key), Some(&"ksuidms"));
+}
+
+#[test]
+fn test_bytes_conversions() {
+    let bytes = [12u8; 20];
+    let ksuid = Ksuid::from(bytes);
+    assert_eq!(ksuid.into_bytes(), bytes);
+    assert_eq!(Ksuid::try_from(&bytes[..]).unwrap(), ksuid);
+    assert!(Ksuid::try_from(&bytes[..19]).is_err());
+    assert!(Ksuid::try_from(&[12u8; 21][..]).is_err());
+
+    let ksuidms = KsuidMs::from(bytes);
+    assert_eq!(ksuidms.into_bytes(), bytes);
+    assert_eq!(KsuidMs::try_from(&bytes[..]).unwrap(), ksuidms);
+    assert!(KsuidMs::try_from(&bytes[..19]).is_err());
+}
+
+#[test]
+fn test_string_conversions() {
+    let b62 = "1srOrx2ZWZBpBUvZwXKQmoEYga2";
+
+    let ksuid = Ksuid::try_from(b62).unwrap();
+    assert_eq!(Ksuid::try_from(b62.to_owned()).unwrap(), ksuid);
+    assert_eq!(String::from(ksuid), b62);
+    assert!(Ksuid::try_from("invalid").is_err());
+
+    let ksuidms = KsuidMs::try_from(b62).unwrap();
+    assert_eq!(KsuidMs::try_from(b62.to_owned()).unwrap(), ksuidms);
+    let as_string: String = ksuidms.into();
+    assert_eq!(as_string, b62);
+    assert!(KsuidMs::try_from("invalid".to_owned()).is_err());
+}
+
+#[test]
+fn test_eq_str() {
+    let b62 = "1srOrx2ZWZBpBUvZwXKQmoEYga2";
+    let ksuid = Ksuid::from_str(b62).unwrap();
+    assert_eq!(ksuid, b62);
+    assert!(ksuid == *b62);
+    assert!(ksuid != "1srOrx2ZWZBpBUvZwXKQmoEYga3");
+    assert!(ksuid != "1srOrx2ZWZBpBUvZwXKQmoEYga");
+
+    let ksuidms = KsuidMs::from_str(b62).unwrap();
+    assert_eq!(ksuidms, b62);
+    assert!(ksuidms != "");
+}
+
+#[test]
+fn test_base62_bounds() {
+    assert_eq!(
+        Ksuid::from_bytes([0u8; 20]).to_base62(),
+        "000000000000000000000000000"
+    );
+    assert_eq!(
+        Ksuid::from_bytes([0xFFu8; 20]).to_base62(),
+        "aWgEPTl1tmebfsQzFP4bxwgy80V"
+    );
+}
+
+#[test]
+fn test_hex_formatting() {
+    let ksuid = Ksuid::from_str("0ujtsYcgvSTl8PAuAdqWYSMnLOv").unwrap();
+    assert_eq!(
+        format!("{:x}", ksuid),
+        "0669f7efb5a1cd34b5f99d1154fb6853345c9735"
+    );
+    assert_eq!(
+        format!("{:X}", ksuid),
+        "0669F7EFB5A1CD34B5F99D1154FB6853345C9735"
+    );
+    assert_eq!(
+        format!("{:#x}", ksuid),
+        "0x0669f7efb5a1cd34b5f99d1154fb6853345c9735"
+    );
+
+    // Respects width, fill and alignment like Display
+    assert_eq!(
+        format!("{:>45x}", ksuid),
+        "     0669f7efb5a1cd34b5f99d1154fb6853345c9735"
+    );
+    assert_eq!(
+        format!("{:*<44X}", ksuid),
+        "0669F7EFB5A1CD34B5F99D1154FB6853345C9735****"
+    );
+    assert_eq!(
+        format!("{:#044x}", ksuid),
+        "0x000669f7efb5a1cd34b5f99d1154fb6853345c9735"
+    );
+
+    let ksuidms = KsuidMs::from_bytes(*ksuid.bytes());
+    assert_eq!(format!("{:x}", ksuidms), format!("{:x}", ksuid));
+    assert_eq!(format!("{:X}", ksuidms).len(), 40);
+}
+
+#[test]
+fn test_hex_roundtrip() {
+    let ksuid = Ksuid::new(None, None);
+    assert_eq!(Ksuid::from_hex(&ksuid.to_hex()).unwrap(), ksuid);
+    assert_eq!(
+        Ksuid::from_hex(&ksuid.to_hex().to_uppercase()).unwrap(),
+        ksuid
+    );
+    assert_eq!(ksuid.to_hex(), format!("{:x}", ksuid));
+
+    let ksuidms = KsuidMs::new(None, None);
+    assert_eq!(KsuidMs::from_hex(&ksuidms.to_hex()).unwrap(), ksuidms);
+
+    assert!(Ksuid::from_hex("0669f7efb5a1cd34b5f99d1154fb6853345c973").is_err());
+    assert!(Ksuid::from_hex("0669f7efb5a1cd34b5f99d1154fb6853345c97355").is_err());
+    assert!(Ksuid::from_hex("0669f7efb5a1cd34b5f99d1154fb6853345c973g").is_err());
+    assert!(Ksuid::from_hex("0669f7efb5a1cd34b5f99d1154fb6853345c97\u{e9}").is_err());
+}
+
+#[test]
+fn test_base32_crockford() {
+    let ksuid = Ksuid::new(None, None);
+    let base32 = ksuid.to_base32_crockford();
+    assert_eq!(base32.len(), 32);
+    assert_eq!(Ksuid::from_base32_crockford(&base32).unwrap(), ksuid);
+    assert_eq!(
+        Ksuid::from_base32_crockford(&base32.to_lowercase()).unwrap(),
+        ksuid
+    );
+
+    let ksuidms = KsuidMs::new(None, None);
+    assert_eq!(
+        KsuidMs::from_base32_crockford(&ksuidms.to_base32_crockford()).unwrap(),
+        ksuidms
+    );
+
+    assert_eq!(
+        Ksuid::from_bytes([0xFFu8; 20]).to_base32_crockford(),
+        "ZZZZZZZZZZZZZZZZZZZZZZZZZZZZZZZZ"
+    );
+    assert_eq!(
+        Ksuid::from_base32_crockford("OOOOOOOOOOOOOOOOOOOOOOOOOOOOOOOI").unwrap(),
+        Ksuid::from_base32_crockford("0000000000000000000000000000000l").unwrap()
+    );
+
+    assert!(Ksuid::from_base32_crockford("ZZZZZZZZZZZZZZZZZZZZZZZZZZZZZZZ").is_err());
+    assert!(Ksuid::from_base32_crockford("ZZZZZZZZZZZZZZZZZZZZZZZZZZZZZZZZZ").is_err());
+    assert!(Ksuid::from_base32_crockford("ZZZZZZZZZZZZZZZZZZZZZZZZZZZZZZZU").is_err());
+}
+
+#[test]
+fn test_base32_crockford_ordering() {
+    let ksuid1 = Ksuid::from_seconds(Some(1_555_555_555), None);
+    let ksuid2 = Ksuid::from_seconds(Some(1_777_777_777), None);
+    assert!(ksuid1.to_base32_crockford() < ksuid2.to_base32_crockford());
+}
+
+#[test]
+fn test_base58() {
+    let ksuid = Ksuid::new(None, None);
+    let base58 = ksuid.to_base58();
+    assert_eq!(base58.len(), 28);
+    assert_eq!(Ksuid::from_base58(&base58).unwrap(), ksuid);
+
+    let ksuidms = KsuidMs::new(None, None);
+    assert_eq!(KsuidMs::from_base58(&ksuidms.to_base58()).unwrap(), ksuidms);
+
+    let min = Ksuid::from_bytes([0u8; 20]);
+    let max = Ksuid::from_bytes([0xFFu8; 20]);
+    assert_eq!(min.to_base58(), "1111111111111111111111111111");
+    assert_eq!(max.to_base58(), "4ZrjxJnU1LA5xSyrWMNuXTvSYKwt");
+    assert_eq!(Ksuid::from_base58(&min.to_base58()).unwrap(), min);
+    assert_eq!(Ksuid::from_base58(&max.to_base58()).unwrap(), max);
+
+    assert!(Ksuid::from_base58("4ZrjxJnU1LA5xSyrWMNuXTvSYKwu").is_err());
+    assert!(Ksuid::from_base58("0ZrjxJnU1LA5xSyrWMNuXTvSYKwt").is_err());
+    assert!(Ksuid::from_base58("4ZrjxJnU1LA5xSyrWMNuXTvSYKw").is_err());
+}
+
+#[test]
+fn test_base62_custom_alphabet() {
+    let alphabet =
+        Base62Alphabet::new("zyxwvutsrqponmlkjihgfedcbaZYXWVUTSRQPONMLKJIHGFEDCBA9876543210")
+            .unwrap();
+    let ksuid = Ksuid::new(None, None);
+    let encoded = ksuid.to_base62_with(&alphabet);
+    assert_ne!(encoded, ksuid.to_base62());
+    assert_eq!(Ksuid::from_base62_with(&encoded, &alphabet).unwrap(), ksuid);
+    assert_eq!(
+        Ksuid::from_bytes([0u8; 20]).to_base62_with(&alphabet),
+        "zzzzzzzzzzzzzzzzzzzzzzzzzzz"
+    );
+
+    let ksuidms = KsuidMs::new(None, None);
+    let encoded = ksuidms.to_base62_with(&alphabet);
+    assert_eq!(
+        KsuidMs::from_base62_with(&encoded, &alphabet).unwrap(),
+        ksuidms
+    );
+
+    assert_eq!(Base62Alphabet::default(), Base62Alphabet::STANDARD);
+    assert_eq!(
+        Base62Alphabet::STANDARD.as_str(),
+        "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz"
+    );
+    // Duplicate characters
+    assert!(
+        Base62Alphabet::new("0023456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz")
+            .is_err()
+    );
+    // Non-ASCII characters
+    assert!(Base62Alphabet::new(
+        "\u{e9}23456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz"
+    )
+    .is_err());
+}
+
+#[test]
+fn test_parse_any() {
+    let ksuid = Ksuid::new(None, None);
+    let cases = vec![
+        (ksuid.bytes().to_vec(), KsuidFormat::Bytes),
+        (ksuid.to_base62().into_bytes(), KsuidFormat::Base62),
+        (ksuid.to_base58().into_bytes(), KsuidFormat::Base58),
+        (
+            ksuid.to_base32_crockford().into_bytes(),
+            KsuidFormat::Base32Crockford,
+        ),
+        (ksuid.to_hex().into_bytes(), KsuidFormat::Hex),
+        (ksuid.to_hex().to_uppercase().into_bytes(), KsuidFormat::Hex),
+    ];
+    for (input, expected_format) in cases {
+        let (parsed, format) = Ksuid::parse_any(&input).unwrap();
+        assert_eq!(parsed, ksuid);
+        assert_eq!(format, expected_format);
+
+        let (parsed, format) = KsuidMs::parse_any(&input).unwrap();
+        assert_eq!(parsed.bytes(), ksuid.bytes());
+        assert_eq!(format, expected_format);
+    }
+
+    assert!(Ksuid::parse_any(b"").is_err());
+    assert!(Ksuid::parse_any(b"not a ksuid").is_err());
+    assert!(Ksuid::parse_any(&[0xFFu8; 27]).is_err());
+}
+
+#[test]
+fn test_is_valid_ksuid() {
+    let mut file = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    file.push("tests/test_kuids.txt");
+
+    for line in read_lines(file).unwrap() {
+        let data_line: TestDataLine = serde_json::from_str(&line.unwrap()).unwrap();
+        assert!(is_valid_ksuid(&data_line.ksuid));
+    }
+
+    assert!(is_valid_ksuid("000000000000000000000000000"));
+    assert!(is_valid_ksuid("aWgEPTl1tmebfsQzFP4bxwgy80V"));
+    assert!(!is_valid_ksuid("aWgEPTl1tmebfsQzFP4bxwgy80W"));
+    assert!(!is_valid_ksuid(""));
+    assert!(!is_valid_ksuid("1srOrx2ZWZBpBUvZwXKQmoEYga2 "));
+    assert!(!is_valid_ksuid("1srOrx2ZWZBpBUvZwXKQmoEYg\u{e9}"));
+}
+
+#[test]
+fn test_deserialize_overflowing_base62() {
+    assert!(Ksuid::from_base62("aWgEPTl1tmebfsQzFP4bxwgy80V").is_ok());
+    assert!(Ksuid::from_base62("aWgEPTl1tmebfsQzFP4bxwgy80W").is_err());
+    assert!(Ksuid::from_base62("zzzzzzzzzzzzzzzzzzzzzzzzzzz").is_err());
+    assert_eq!(
+        KsuidMs::from_base62("aWgEPTl1tmebfsQzFP4bxwgy80W")
+            .unwrap_err()
+            .to_string(),
+        "Got base62 ksuid that overflows 20 bytes"
+    );
+}
+
+#[test]
+fn test_base62_decode_round_trip() {
+    // A single set byte at every position, to cover all limb and chunk boundaries
+    for i in 0..20 {
+        for byte in [0x01, 0x80, 0xff] {
+            let mut bytes = [0u8; 20];
+            bytes[i] = byte;
+            let ksuid = Ksuid::from_bytes(bytes);
+            assert_eq!(Ksuid::from_base62(&ksuid.to_base62()).unwrap(), ksuid);
+        }
+    }
+    for _ in 0..1000 {
+        let ksuid = Ksuid::new(None, None);
+        assert_eq!(Ksuid::from_base62(&ksuid.to_base62()).unwrap(), ksuid);
+    }
+    assert!(Ksuid::from_base62("1srOrx2ZWZBpBUvZwXKQmoEYg-2").is_err());
+    assert!(Ksuid::from_base62("1srOrx2ZWZBpBUvZwXKQmoEYg\u{e9}").is_err());
+}
+
+#[test]
+fn test_from_str_trimmed() {
+    let ksuid = Ksuid::from_base62("1srOrx2ZWZBpBUvZwXKQmoEYga2").unwrap();
+    assert_eq!(
+        Ksuid::from_str_trimmed("1srOrx2ZWZBpBUvZwXKQmoEYga2").unwrap(),
+        ksuid
+    );
+    assert_eq!(
+        Ksuid::from_str_trimmed("1srOrx2ZWZBpBUvZwXKQmoEYga2\n").unwrap(),
+        ksuid
+    );
+    assert_eq!(
+        Ksuid::from_str_trimmed("\t 1srOrx2ZWZBpBUvZwXKQmoEYga2 \r\n").unwrap(),
+        ksuid
+    );
+    assert_eq!(
+        KsuidMs::from_str_trimmed(" 1srOrx2ZWZBpBUvZwXKQmoEYga2 ").unwrap(),
+        KsuidMs::from_bytes(*ksuid.bytes())
+    );
+
+    // Still strict everywhere else
+    assert!(Ksuid::from_base62("1srOrx2ZWZBpBUvZwXKQmoEYga2\n").is_err());
+    assert!(Ksuid::from_str_trimmed("1srOrx2ZWZBpB UvZwXKQmoEYga2").is_err());
+    assert!(Ksuid::from_str_trimmed("  ").is_err());
+}
+
+#[test]
+fn test_redacted() {
+    let ksuid = Ksuid::from_seconds(Some(1_621_627_443), None);
+    assert_eq!(
+        format!("{:?}", ksuid.redacted()),
+        "2021-05-21T20:04:03Z/****"
+    );
+    assert_eq!(format!("{}", Redacted(ksuid)), "2021-05-21T20:04:03Z/****");
+    assert_eq!(ksuid.redacted().0, ksuid);
+    assert_eq!(
+        format!("{:?}", Ksuid::nil().redacted()),
+        "2014-05-13T16:53:20Z/****"
+    );
+    assert_eq!(
+        format!("{:?}", Ksuid::from_bytes([0xff; 20]).redacted()),
+        "2150-06-19T23:21:35Z/****"
+    );
+    // Leap day
+    assert_eq!(
+        format!(
+            "{:?}",
+            Ksuid::from_seconds(Some(1_709_251_199), None).redacted()
+        ),
+        "2024-02-29T23:59:59Z/****"
+    );
+
+    let ksuid_ms = KsuidMs::from_millis(Some(1_621_627_443_124), None);
+    assert_eq!(
+        format!("{:?}", ksuid_ms.redacted()),
+        "2021-05-21T20:04:03.124Z/****"
+    );
+    assert_eq!(
+        format!(
+            "{:?}",
+            KsuidMs::from_millis(Some(1_621_627_443_000), None).redacted()
+        ),
+        "2021-05-21T20:04:03.000Z/****"
+    );
+
+    #[derive(Debug)]
+    #[allow(dead_code)]
+    struct Event {
+        id: Redacted<Ksuid>,
+    }
+    let event = format!(
+        "{:?}",
+        Event {
+            id: ksuid.redacted()
+        }
+    );
+    assert_eq!(event, "Event { id: 2021-05-21T20:04:03Z/**** }");
+    assert!(!event.contains(&ksuid.to_string()));
+}
+
+#[test]
+fn test_short() {
+    let ksuid = Ksuid::from_base62("1srOrx2ZWZBpBUvZwXKQmoEYga2").unwrap();
+    assert_eq!(ksuid.short().to_string(), "1srOrx2Z");
+    assert_eq!(ksuid.short().as_str(), "1srOrx2Z");
+    assert_eq!(ksuid.short().with_len(3).to_string(), "1sr");
+    assert_eq!(ksuid.short().with_len(0).to_string(), "");
+    assert_eq!(ksuid.short().with_len(27).to_string(), ksuid.to_string());
+    assert_eq!(ksuid.short().with_len(28).to_string(), ksuid.to_string());
+    assert_eq!(format!("[{:>10}]", ksuid.short()), "[  1srOrx2Z]");
+    assert_eq!(format!("{:?}", ksuid.short()), "ShortKsuid(\"1srOrx2Z\")");
+
+    let ksuid_ms = KsuidMs::from_bytes(*ksuid.bytes());
+    assert_eq!(ksuid_ms.short(), ksuid.short());
+
+    assert!(ksuid.matches_short("1srOrx2Z"));
+    assert!(ksuid.matches_short("1srOrx2ZWZBpBUvZwXKQmoEYga2"));
+    assert!(ksuid_ms.matches_short("1sr"));
+    assert!(!ksuid.matches_short("1srOrx2ZWZBpBUvZwXKQmoEYga2x"));
+    assert!(!ksuid.matches_short("srOrx2Z"));
+    assert!(!ksuid.matches_short(""));
+}
+
+#[test]
+fn test_from_cuid2() {
+    let cuid = "tz4a98xxat96iws9zmbrgj3a";
+    let ksuid = Ksuid::from_cuid2(cuid, Some(1_621_627_443)).unwrap();
+    assert_eq!(ksuid.timestamp_seconds(), 1_621_627_443);
+    // 128-bit FNV-1a of the cuid2
+    assert_eq!(
+        ksuid.payload(),
+        0x7efb_4136_c889_cd63_4dd4_ecae_2e14_e8cfu128.to_be_bytes()
+    );
+    let ksuid_ms = KsuidMs::from_cuid2(cuid, Some(1_621_627_443)).unwrap();
+    assert_eq!(ksuid_ms.payload(), &ksuid.payload()[..15]);
+
+    // Deterministic for the same cuid2 and timestamp only
+    assert_eq!(Ksuid::from_cuid2(cuid, Some(1_621_627_443)).unwrap(), ksuid);
+    assert_ne!(Ksuid::from_cuid2(cuid, Some(1_621_627_444)).unwrap(), ksuid);
+    assert_ne!(
+        Ksuid::from_cuid2("tz4a98xxat96iws9zmbrgj3b", Some(1_621_627_443)).unwrap(),
+        ksuid
+    );
+    assert_eq!(
+        Ksuid::from_cuid2(cuid, None).unwrap().payload(),
+        ksuid.payload()
+    );
+
+    assert!(Ksuid::from_cuid2("", None).is_err());
+    assert!(Ksuid::from_cuid2("a", None).is_err());
+    assert!(Ksuid::from_cuid2("1z4a98xxat96iws9zmbrgj3a", None).is_err());
+    assert!(Ksuid::from_cuid2("tz4a98xxAt96iws9zmbrgj3a", None).is_err());
+    assert!(Ksuid::from_cuid2("tz4a98xx-t96iws9zmbrgj3a", None).is_err());
+    assert!(Ksuid::from_cuid2(&"a".repeat(33), None).is_err());
+    assert!(Ksuid::from_cuid2(&"a".repeat(32), None).is_ok());
+}
+
+#[test]
+fn test_cbor() {
+    let ksuid = Ksuid::from_base62("1srOrx2ZWZBpBUvZwXKQmoEYga2").unwrap();
+    let cbor = ksuid.to_cbor();
+    // 1263752521(h'...') in diagnostic notation
+    let mut expected = vec![0xda, 0x4b, 0x53, 0x55, 0x49, 0x54];
+    expected.extend_from_slice(ksuid.bytes());
+    assert_eq!(cbor[..], expected[..]);
+    assert_eq!(KSUID_CBOR_TAG, 1_263_752_521);
+    assert_eq!(Ksuid::from_cbor(&cbor).unwrap(), ksuid);
+    assert_eq!(
+        KsuidMs::from_cbor(&cbor).unwrap(),
+        KsuidMs::from_bytes(*ksuid.bytes())
+    );
+
+    // Non-minimal heads are still well-formed
+    let mut wide = vec![0xdb, 0, 0, 0, 0, 0x4b, 0x53, 0x55, 0x49, 0x58, 20];
+    wide.extend_from_slice(ksuid.bytes());
+    assert_eq!(Ksuid::from_cbor(&wide).unwrap(), ksuid);
+
+    // Untagged, wrong tag, wrong type, wrong length, truncated and trailing data
+    assert!(Ksuid::from_cbor(&cbor[5..]).is_err());
+    let mut wrong_tag = cbor;
+    wrong_tag[4] = 0x48;
+    assert!(Ksuid::from_cbor(&wrong_tag).is_err());
+    let mut text = cbor;
+    text[5] = 0x74;
+    assert!(Ksuid::from_cbor(&text).is_err());
+    let mut short = cbor;
+    short[5] = 0x53;
+    assert!(Ksuid::from_cbor(&short[..25]).is_err());
+    assert!(Ksuid::from_cbor(&cbor[..25]).is_err());
+    assert!(Ksuid::from_cbor(&cbor[..3]).is_err());
+    assert!(Ksuid::from_cbor(&[&cbor[..], &[0]].concat()).is_err());
+    assert!(Ksuid::from_cbor(&[0xda, 0x4b, 0x53, 0x55, 0x49, 0x5f]).is_err());
+    assert!(Ksuid::from_cbor(&[]).is_err());
+}
+
+#[test]
+fn test_length_constants() {
+    let ksuid = Ksuid::new(None, None);
+    assert_eq!(ksuid.bytes().len(), KSUID_BYTES);
+    assert_eq!(ksuid.to_string().len(), KSUID_STRING_LENGTH);
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn test_ksuid_regex() {
+    let regex = ksuid_regex();
+    assert_eq!(regex.as_str(), KSUID_PATTERN);
+    assert!(regex.is_match(&Ksuid::new(None, None).to_string()));
+    assert!(regex.is_match(&KsuidMs::new(None, None).to_string()));
+    assert!(!regex.is_match("1srOrx2ZWZBpBUvZwXKQmoEYga"));
+    assert!(!regex.is_match("1srOrx2ZWZBpBUvZwXKQmoEYga2a"));
+    assert!(!regex.is_match("1srOrx2ZWZBpBUvZwXKQmoEYg-2"));
+}
+
+#[cfg(feature = "validator")]
+#[test]
+fn test_validator() {
+    use validator::Validate;
+
+    #[derive(Validate)]
+    struct Request {
+        #[validate(custom(function = "validate_ksuid"))]
+        id: String,
+        #[validate(custom(function = "validate_ksuid"))]
+        parent_id: Option<String>,
+        #[validate(nested)]
+        ksuid: Ksuid,
+        #[validate(nested)]
+        ksuidms: KsuidMs,
+    }
+
+    let valid = Request {
+        id: "1srOrx2ZWZBpBUvZwXKQmoEYga2".to_owned(),
+        parent_id: None,
+        ksuid: Ksuid::new(None, None),
+        ksuidms: KsuidMs::new(None, None),
+    };
+    assert!(valid.validate().is_ok());
+
+    let invalid = Request {
+        parent_id: Some("zzzzzzzzzzzzzzzzzzzzzzzzzzz".to_owned()),
+        ..valid
+    };
+    let errors = invalid.validate().unwrap_err();
+    assert!(errors.field_errors().contains_key("parent_id"));
+    assert!(!errors.field_errors().contains_key("id"));
+}
+
+#[cfg(feature = "defmt")]
+mod defmt_logger {
+    use std::sync::Mutex;
+
+    pub static OUTPUT: Mutex<Vec<u8>> = Mutex::new(Vec::new());
+
+    #[defmt::global_logger]
+    struct Logger;
+
+    unsafe impl defmt::Logger for Logger {
+        fn acquire() {}
+        unsafe fn flush() {}
+        unsafe fn release() {}
+        unsafe fn write(bytes: &[u8]) {
+            OUTPUT.lock().unwrap().extend_from_slice(bytes);
+        }
+    }
+
+    defmt::timestamp!("");
+}
+
+#[cfg(feature = "defmt")]
+#[test]
+fn test_defmt() {
+    let ksuid = Ksuid::from_base62("1srOrx2ZWZBpBUvZwXKQmoEYga2").unwrap();
+    let ksuid_ms = KsuidMs::from_base62("0ujtsYcgvSTl8PAuAdqWYSMnLOv").unwrap();
+    // Unlike the log macros, `println!` isn't filtered by `DEFMT_LOG`
+    defmt::println!("id={} parent={}", ksuid, ksuid_ms);
+
+    let output = defmt_logger::OUTPUT.lock().unwrap();
+    for s in [ksuid.to_string(), ksuid_ms.to_string()] {
+        assert!(output.windows(27).any(|w| w == s.as_bytes()));
+    }
+}
+
+#[cfg(feature = "embassy-time")]
+#[test]
+fn test_embassy_time() {
+    use embassy_time::{Instant, MockDriver};
+    use std::time::{Duration, UNIX_EPOCH};
+
+    let driver = MockDriver::get();
+    let boot_time = UNIX_EPOCH + Duration::from_secs(1_621_627_443);
+    let clock = EmbassyClock::new(boot_time);
+    let mut generator = Generator::new().with_clock(clock);
+
+    driver.advance(embassy_time::Duration::from_millis(90_500));
+    let since_boot = Duration::from_micros(Instant::now().as_micros());
+    assert_eq!(clock.now(), boot_time + since_boot);
+    let ksuid: Ksuid = generator.generate();
+    assert_eq!(ksuid.timestamp_seconds(), 1_621_627_533);
+
+    // Anchored after a sync, ahead of the boot time
+    let synced = boot_time + Duration::from_secs(3_600);
+    let clock = EmbassyClock::anchored(synced, Instant::now());
+    assert_eq!(clock.now(), synced);
+    driver.advance(embassy_time::Duration::from_secs(1));
+    assert_eq!(clock.now(), synced + Duration::from_secs(1));
+
+    // Anchored at an instant in the future
+    let clock = EmbassyClock::anchored(
+        synced,
+        Instant::now() + embassy_time::Duration::from_secs(5),
+    );
+    assert_eq!(clock.now(), synced - Duration::from_secs(5));
+}
+
+#[cfg(feature = "duckdb")]
+#[test]
+fn test_duckdb() {
+    use duckdb::Connection;
+
+    let conn = Connection::open_in_memory().unwrap();
+    conn.execute_batch("CREATE TABLE events (id VARCHAR PRIMARY KEY, raw BLOB)")
+        .unwrap();
+    let a = Ksuid::from_seconds(Some(1_621_627_443), None);
+    let b = Ksuid::from_seconds(Some(1_621_627_444), None);
+    for ksuid in [b, a] {
+        conn.execute(
+            "INSERT INTO events VALUES (?, ?)",
+            duckdb::params![ksuid, &ksuid.bytes()[..]],
+        )
+        .unwrap();
+    }
+
+    let mut stmt = conn
+        .prepare("SELECT id, raw FROM events ORDER BY id")
+        .unwrap();
+    let rows: Vec<(Ksuid, KsuidMs)> = stmt
+        .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(
+        rows,
+        vec![
+            (a, KsuidMs::from_bytes(*a.bytes())),
+            (b, KsuidMs::from_bytes(*b.bytes()))
+        ]
+    );
+
+    let found: Ksuid = conn
+        .query_row("SELECT id FROM events WHERE id = ?", [b], |row| row.get(0))
+        .unwrap();
+    assert_eq!(found, b);
+
+    let get = |sql: &str| conn.query_row(sql, [], |row| row.get::<_, Ksuid>(0));
+    assert!(get("SELECT 'not-a-ksuid'").is_err());
+    assert!(get("SELECT 'abc'::BLOB").is_err());
+    assert!(get("SELECT 1").is_err());
+}
+
+#[cfg(feature = "foundationdb-tuple")]
+#[test]
+fn test_foundationdb_tuple() {
+    let mut ksuids: Vec<Ksuid> = (0..50)
+        .map(|i| Ksuid::from_seconds(Some(1_621_627_443 + i % 5), None))
+        .collect();
+    // Include some bytes that need escaping
+    ksuids.push(Ksuid::from_bytes([0; 20]));
+    ksuids.push(Ksuid::from_bytes([0xff; 20]));
+    ksuids.push(Ksuid::from_seconds(Some(1_621_627_443), Some(&[0; 16])));
+
+    let mut packed: Vec<Vec<u8>> = ksuids
+        .iter()
+        .map(|ksuid| foundationdb_tuple::pack(&("items", *ksuid, 7)))
+        .collect();
+    packed.sort();
+    ksuids.sort();
+    let unpacked: Vec<Ksuid> = packed
+        .iter()
+        .map(|packed| {
+            let (name, ksuid, n): (String, Ksuid, i64) =
+                foundationdb_tuple::unpack(packed).unwrap();
+            assert_eq!((name.as_str(), n), ("items", 7));
+            ksuid
+        })
+        .collect();
+    assert_eq!(unpacked, ksuids);
+
+    let ksuidms = KsuidMs::from_millis(Some(1_621_627_443_123), None);
+    let packed = foundationdb_tuple::pack(&ksuidms);
+    assert_eq!(
+        foundationdb_tuple::unpack::<KsuidMs>(&packed).unwrap(),
+        ksuidms
+    );
+    assert_eq!(
+        foundationdb_tuple::unpack::<Ksuid>(&packed)
+            .unwrap()
+            .bytes(),
+        ksuidms.bytes()
+    );
+
+    let packed = foundationdb_tuple::pack(&vec![1u8, 2, 3]);
+    assert!(foundationdb_tuple::unpack::<Ksuid>(&packed).is_err());
+    let packed = foundationdb_tuple::pack(&"1srOrx2ZWZBpBUvZwXKQmoEYga2");
+    assert!(foundationdb_tuple::unpack::<Ksuid>(&packed).is_err());
+}
+
+#[cfg(feature = "garde")]
+#[test]
+fn test_garde() {
+    use garde::Validate;
+
+    #[derive(Validate)]
+    struct Request {
+        #[garde(custom(garde_ksuid))]
+        id: String,
+        #[garde(dive)]
+        ksuid: Ksuid,
+        #[garde(dive)]
+        ksuidms: KsuidMs,
+    }
+
+    let valid = Request {
+        id: "1srOrx2ZWZBpBUvZwXKQmoEYga2".to_owned(),
+        ksuid: Ksuid::new(None, None),
+        ksuidms: KsuidMs::new(None, None),
+    };
+    assert!(valid.validate().is_ok());
+
+    let invalid = Request {
+        id: "1srOrx2ZWZBpBUvZwXKQmoEYga".to_owned(),
+        ..valid
+    };
+    assert!(invalid.validate().is_err());
+}
+
+#[test]
+fn test_display_padding() {
+    let ksuid = Ksuid::from_str("1srOrx2ZWZBpBUvZwXKQmoEYga2").unwrap();
+    assert_eq!(format!("{:>30}", ksuid), "   1srOrx2ZWZBpBUvZwXKQmoEYga2");
+    assert_eq!(format!("{:<30}|", ksuid), "1srOrx2ZWZBpBUvZwXKQmoEYga2   |");
+    assert_eq!(format!("{:*^31}", ksuid), "**1srOrx2ZWZBpBUvZwXKQmoEYga2**");
+    assert_eq!(format!("{:.8}", ksuid), "1srOrx2Z");
+    assert_eq!(format!("{:10}", ksuid), "1srOrx2ZWZBpBUvZwXKQmoEYga2");
+
+    let ksuidms = KsuidMs::from_str("1srOrx2ZWZBpBUvZwXKQmoEYga2").unwrap();
+    assert_eq!(format!("{:>30}", ksuidms), "   1srOrx2ZWZBpBUvZwXKQmoEYga2");
+}
+
+#[test]
+fn test_write_to() {
+    use std::fmt::Write;
+
+    let ksuid = Ksuid::new(None, None);
+    let ksuidms = KsuidMs::new(None, None);
+
+    let mut out = String::new();
+    ksuid.write_to(&mut out).unwrap();
+    out.push(',');
+    ksuidms.write_to(&mut out).unwrap();
+    write!(out, ",{}", ksuid).unwrap();
+    assert_eq!(out, format!("{},{},{}", ksuid, ksuidms, ksuid));
+
+    let mut buf = Vec::new();
+    ksuid.write_to_io(&mut buf).unwrap();
+    ksuidms.write_to_io(&mut buf).unwrap();
+    assert_eq!(buf, format!("{}{}", ksuid, ksuidms).into_bytes());
+}
+
+#[test]
+fn test_ksuid_writer() {
+    let ids: Vec<Ksuid> = (0..1_000).map(|_| Ksuid::new(None, None)).collect();
+
+    let mut writer = KsuidWriter::new(Vec::new());
+    writer.write_all(&ids).unwrap();
+    let out = String::from_utf8(writer.into_inner().unwrap()).unwrap();
+    let parsed: Vec<Ksuid> = out.lines().map(|l| Ksuid::from_str(l).unwrap()).collect();
+    assert_eq!(parsed, ids);
+
+    let ksuidms = KsuidMs::new(None, None);
+    let mut writer = KsuidWriter::with_separator(Vec::new(), b", ");
+    writer.write(&ksuidms).unwrap();
+    writer.write(&ksuidms).unwrap();
+    let out = writer.into_inner().unwrap();
+    assert_eq!(out, format!("{}, {}", ksuidms, ksuidms).into_bytes());
+
+    let writer = KsuidWriter::new(Vec::new());
+    assert!(writer.into_inner().unwrap().is_empty());
+}
+
+#[cfg(feature = "bytemuck")]
+#[test]
+fn test_bytemuck() {
+    let ids: Vec<Ksuid> = (0..3).map(|_| Ksuid::new(None, None)).collect();
+    let bytes: &[u8] = bytemuck::cast_slice(&ids);
+    assert_eq!(bytes.len(), 3 * 20);
+    assert_eq!(&bytes[20..40], ids[1].bytes());
+    let back: &[Ksuid] = bytemuck::cast_slice(bytes);
+    assert_eq!(back, &ids[..]);
+
+    // Any bytes work, e.g. from an unaligned buffer
+    let buf = [0xffu8; 41];
+    let ms: &[KsuidMs] = bytemuck::cast_slice(&buf[1..]);
+    assert_eq!(ms, &[KsuidMs::MAX, KsuidMs::MAX]);
+    assert!(bytemuck::try_cast_slice::<u8, Ksuid>(&buf[1..40]).is_err());
+
+    assert_eq!(<Ksuid as bytemuck::Zeroable>::zeroed(), Ksuid::nil());
+    assert_eq!(bytemuck::cast::<[u8; 20], Ksuid>([7; 20]).bytes(), &[7; 20]);
+}
+
+#[cfg(feature = "zerocopy")]
+#[test]
+fn test_zerocopy() {
+    use zerocopy::byteorder::{BigEndian, U16};
+    use zerocopy::{FromBytes, Immutable, IntoBytes, KnownLayout, Unaligned};
+
+    #[derive(FromBytes, IntoBytes, KnownLayout, Immutable, Unaligned)]
+    #[repr(C)]
+    struct Record {
+        kind: U16<BigEndian>,
+        id: Ksuid,
+        parent: KsuidMs,
+    }
+
+    let id = Ksuid::new(None, None);
+    let parent = KsuidMs::new(None, None);
+    let mut packet = vec![0x00, 0x2a];
+    packet.extend_from_slice(id.bytes());
+    packet.extend_from_slice(parent.bytes());
+    packet.extend_from_slice(b"trailing");
+
+    let (record, rest) = Record::ref_from_prefix(&packet).unwrap();
+    assert_eq!(record.kind.get(), 42);
+    assert_eq!(record.id, id);
+    assert_eq!(record.parent, parent);
+    assert_eq!(rest, b"trailing");
+    assert_eq!(record.as_bytes(), &packet[..42]);
+
+    let ids = <[Ksuid]>::ref_from_bytes(&packet[2..42]).unwrap();
+    assert_eq!(ids, &[id, Ksuid::from_bytes(*parent.bytes())]);
+    assert!(Ksuid::ref_from_bytes(&packet[..19]).is_err());
+}
+
+#[cfg(feature = "hex")]
+#[test]
+fn test_hex_crate() {
+    use hex::{FromHex, FromHexError, ToHex};
+
+    let ksuid = Ksuid::from_base62("1srOrx2ZWZBpBUvZwXKQmoEYga2").unwrap();
+    let encoded = hex::encode(ksuid);
+    assert_eq!(encoded, format!("{:x}", ksuid));
+    assert_eq!(ksuid.encode_hex::<String>(), encoded);
+    assert_eq!(ksuid.encode_hex_upper::<String>(), format!("{:X}", ksuid));
+
+    assert_eq!(<Ksuid as FromHex>::from_hex(&encoded).unwrap(), ksuid);
+    assert_eq!(
+        <Ksuid as FromHex>::from_hex(encoded.to_uppercase()).unwrap(),
+        ksuid
+    );
+    assert_eq!(
+        <KsuidMs as FromHex>::from_hex(encoded.as_bytes()).unwrap(),
+        KsuidMs::from_bytes(*ksuid.bytes())
+    );
+    assert_eq!(
+        <Ksuid as FromHex>::from_hex(&encoded[2..]),
+        Err(FromHexError::InvalidStringLength)
+    );
+    assert_eq!(
+        <Ksuid as FromHex>::from_hex(format!("{}g{}", &encoded[..5], &encoded[6..])),
+        Err(FromHexError::InvalidHexCharacter { c: 'g', index: 5 })
+    );
+
+    // Works with utilities bounded on `FromHex`
+    fn parse<T: FromHex>(s: &str) -> Option<T> {
+        T::from_hex(s).ok()
+    }
+    assert_eq!(parse::<Ksuid>(&encoded), Some(ksuid));
+}
+
+#[cfg(feature = "surrealdb")]
+#[test]
+fn test_surrealdb() {
+    use surrealdb_types::{RecordId, RecordIdKey, SurrealValue, ToSql, Value};
+
+    let ksuid = Ksuid::from_base62("1srOrx2ZWZBpBUvZwXKQmoEYga2").unwrap();
+    let value = ksuid.into_value();
+    assert_eq!(
+        value,
+        Value::String("1srOrx2ZWZBpBUvZwXKQmoEYga2".to_owned())
+    );
+    assert!(Ksuid::is_value(&value));
+    assert_eq!(Ksuid::from_value(value).unwrap(), ksuid);
+
+    let id = RecordId::new("user", ksuid);
+    assert_eq!(id.to_sql(), "user:1srOrx2ZWZBpBUvZwXKQmoEYga2");
+    assert_eq!(Ksuid::try_from(id.clone()).unwrap(), ksuid);
+    assert_eq!(
+        Ksuid::from_value(Value::RecordId(id.clone())).unwrap(),
+        ksuid
+    );
+    assert_eq!(KsuidMs::try_from(id.key).unwrap().bytes(), ksuid.bytes());
+
+    // Record ids sort like the ksuids
+    let a = KsuidMs::from_millis(Some(1_621_627_443_000), None);
+    let b = KsuidMs::from_millis(Some(1_621_627_443_004), None);
+    assert!(RecordIdKey::from(a) < RecordIdKey::from(b));
+    assert_eq!(KsuidMs::from_value(b.into_value()).unwrap(), b);
+
+    assert!(Ksuid::try_from(RecordIdKey::Number(1)).is_err());
+    assert!(Ksuid::try_from(RecordId::new("user", "not-a-ksuid")).is_err());
+    assert!(!Ksuid::is_value(&Value::String("not-a-ksuid".to_owned())));
+    assert!(!Ksuid::is_value(&Value::Bool(true)));
+    assert!(Ksuid::from_value(Value::Bool(true)).is_err());
+}
+
+#[cfg(feature = "neo4rs")]
+#[test]
+fn test_neo4rs() {
+    use neo4rs::{BoltList, BoltType, Row};
+
+    let ksuid = Ksuid::from_base62("1srOrx2ZWZBpBUvZwXKQmoEYga2").unwrap();
+    let ksuid_ms = KsuidMs::new(None, None);
+    let bolt = BoltType::from(ksuid);
+    assert_eq!(bolt, BoltType::from("1srOrx2ZWZBpBUvZwXKQmoEYga2"));
+    assert_eq!(Ksuid::try_from(bolt.clone()).unwrap(), ksuid);
+    assert_eq!(
+        KsuidMs::try_from(BoltType::from(ksuid_ms)).unwrap(),
+        ksuid_ms
+    );
+
+    let row = Row::new(
+        BoltList::from(vec![
+            BoltType::from("id"),
+            BoltType::from("parent"),
+            BoltType::from("name"),
+        ]),
+        BoltList::from(vec![bolt, ksuid_ms.into(), BoltType::from("not-a-ksuid")]),
+    );
+    assert_eq!(row.get::<Ksuid>("id").unwrap(), ksuid);
+    assert_eq!(row.get::<KsuidMs>("parent").unwrap(), ksuid_ms);
+    assert!(row.get::<Ksuid>("name").is_err());
+    assert!(row.get::<Ksuid>("missing").is_err());
+
+    assert!(Ksuid::try_from(BoltType::from(1)).is_err());
+    assert!(Ksuid::try_from(BoltType::from("not-a-ksuid")).is_err());
+}
+
+#[cfg(feature = "tiberius")]
+#[test]
+fn test_tiberius() {
+    use tiberius::{ColumnData, FromSql, FromSqlOwned, IntoSql, ToSql};
+
+    let ksuid = Ksuid::from_base62("1srOrx2ZWZBpBUvZwXKQmoEYga2").unwrap();
+    let ksuid_ms = KsuidMs::from_bytes(*ksuid.bytes());
+    let string = ColumnData::String(Some("1srOrx2ZWZBpBUvZwXKQmoEYga2".into()));
+    let binary = ColumnData::Binary(Some(ksuid.bytes().to_vec().into()));
+
+    assert_eq!(ksuid.to_sql(), string);
+    assert_eq!(ksuid_ms.to_sql(), string);
+    assert_eq!(ksuid.into_sql(), string);
+    assert_eq!(ksuid_ms.into_sql(), string);
+
+    assert_eq!(Ksuid::from_sql(&string).unwrap(), Some(ksuid));
+    assert_eq!(Ksuid::from_sql(&binary).unwrap(), Some(ksuid));
+    assert_eq!(KsuidMs::from_sql(&binary).unwrap(), Some(ksuid_ms));
+    assert_eq!(KsuidMs::from_sql_owned(string).unwrap(), Some(ksuid_ms));
+    assert_eq!(Ksuid::from_sql(&ColumnData::Binary(None)).unwrap(), None);
+
+    let short = ColumnData::Binary(Some(ksuid.bytes()[..19].to_vec().into()));
+    assert!(Ksuid::from_sql(&short).is_err());
+    assert!(Ksuid::from_sql(&ColumnData::String(Some("not-a-ksuid".into()))).is_err());
+    assert!(Ksuid::from_sql(&ColumnData::I32(Some(1))).is_err());
+}
+
+#[cfg(feature = "heed")]
+#[test]
+fn test_heed() {
+    use heed_traits::{BytesDecode, BytesEncode};
+
+    let a = KsuidMs::from_millis(Some(1_621_627_443_000), None);
+    let b = KsuidMs::from_millis(Some(1_621_627_443_004), None);
+    let encoded_a = KsuidMs::bytes_encode(&a).unwrap();
+    let encoded_b = KsuidMs::bytes_encode(&b).unwrap();
+    // Borrowed, and sorted the same way as the ksuids
+    assert!(matches!(encoded_a, std::borrow::Cow::Borrowed(_)));
+    assert!(encoded_a < encoded_b);
+    assert_eq!(KsuidMs::bytes_decode(&encoded_b).unwrap(), b);
+
+    let ksuid = Ksuid::new(None, None);
+    let encoded = Ksuid::bytes_encode(&ksuid).unwrap();
+    assert_eq!(&encoded[..], ksuid.bytes());
+    assert_eq!(Ksuid::bytes_decode(&encoded).unwrap(), ksuid);
+    assert!(Ksuid::bytes_decode(&encoded[..19]).is_err());
+    assert!(Ksuid::bytes_decode(&[0; 21]).is_err());
+}
+
+#[cfg(feature = "minicbor")]
+#[test]
+fn test_minicbor() {
+    use minicbor::{Decode, Decoder, Encode, Encoder};
+
+    #[derive(Debug, PartialEq)]
+    struct Reading {
+        id: KsuidMs,
+        value: u16,
+    }
+
+    impl<C> Encode<C> for Reading {
+        fn encode<W: minicbor::encode::Write>(
+            &self,
+            e: &mut Encoder<W>,
+            ctx: &mut C,
+        ) -> Result<(), minicbor::encode::Error<W::Error>> {
+            e.array(2)?.encode_with(self.id, ctx)?.u16(self.value)?.ok()
+        }
+    }
+
+    impl<'b, C> Decode<'b, C> for Reading {
+        fn decode(d: &mut Decoder<'b>, ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
+            d.array()?;
+            Ok(Reading {
+                id: d.decode_with(ctx)?,
+                value: d.u16()?,
+            })
+        }
+    }
+
+    let ksuid = Ksuid::from_base62("1srOrx2ZWZBpBUvZwXKQmoEYga2").unwrap();
+    let mut buf = [0u8; 21];
+    minicbor::encode(ksuid, buf.as_mut_slice()).unwrap();
+    assert_eq!(buf[0], 0x54);
+    assert_eq!(&buf[1..], ksuid.bytes());
+    assert_eq!(minicbor::len(ksuid), 21);
+    assert_eq!(minicbor::decode::<Ksuid>(&buf).unwrap(), ksuid);
+    // The same layout as the bytes in `to_cbor`, without the tag
+    assert_eq!(&ksuid.to_cbor()[5..], &buf[..]);
+
+    let reading = Reading {
+        id: KsuidMs::from_bytes(*ksuid.bytes()),
+        value: 42,
+    };
+    let mut buf = [0u8; 32];
+    minicbor::encode(&reading, buf.as_mut_slice()).unwrap();
+    assert_eq!(minicbor::decode::<Reading>(&buf).unwrap(), reading);
+
+    // A byte string of the wrong length, and a text string
+    assert!(minicbor::decode::<Ksuid>(&[0x53; 20]).is_err());
+    let mut text = vec![0x78, 27];
+    text.extend_from_slice(ksuid.to_string().as_bytes());
+    assert!(minicbor::decode::<Ksuid>(&text).is_err());
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn test_generate_many_parallel() {
+    use std::time::{Duration, SystemTime};
+
+    let before = SystemTime::now() - Duration::from_secs(1);
+    // More than one chunk, and a partial last one
+    let mut ids: Vec<KsuidMs> = generate_many_parallel(10_001);
+    assert_eq!(ids.len(), 10_001);
+    assert!(ids.iter().all(|id| id.system_time() >= before));
+    slice::sort(&mut ids);
+    slice::dedup_sorted(&mut ids);
+    assert_eq!(ids.len(), 10_001);
+
+    assert!(generate_many_parallel::<Ksuid>(0).is_empty());
+}
+
+#[cfg(feature = "metrics")]
+#[test]
+fn test_metrics() {
+    use metrics_util::debugging::{DebugValue, DebuggingRecorder};
+    use metrics_util::MetricKind;
+
+    let recorder = DebuggingRecorder::new();
+    let snapshotter = recorder.snapshotter();
+    metrics::with_local_recorder(&recorder, || {
+        let mut generator = Generator::new();
+        for _ in 0..3 {
+            generator.generate::<Ksuid>();
+        }
+        generator.generate::<KsuidMs>();
+        // Ksuids created without a generator aren't recorded
+        Ksuid::new(None, None);
+    });
+
+    let snapshot = snapshotter.snapshot().into_hashmap();
+    let value = |kind, name, variant| {
+        snapshot
+            .iter()
+            .find(|(key, _)| {
+                key.kind() == kind
+                    && key.key().name() == name
+                    && key.key().labels().any(|l| l.value() == variant)
+            })
+            .map(|(_, (_, _, value))| value)
+    };
+    assert_eq!(
+        value(MetricKind::Counter, KSUID_GENERATED_METRIC, "ksuid"),
+        Some(&DebugValue::Counter(3))
+    );
+    assert_eq!(
+        value(MetricKind::Counter, KSUID_GENERATED_METRIC, "ksuid_ms"),
+        Some(&DebugValue::Counter(1))
+    );
+    match value(
+        MetricKind::Histogram,
+        KSUID_GENERATION_DURATION_METRIC,
+        "ksuid",
+    ) {
+        Some(DebugValue::Histogram(samples)) => assert_eq!(samples.len(), 3),
+        other => panic!("unexpected histogram {:?}", other),
+    }
+}
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn test_zeroize() {
+    use zeroize::{Zeroize, Zeroizing};
+
+    let mut ksuid = Ksuid::new(None, None);
+    ksuid.zeroize();
+    assert_eq!(ksuid.bytes(), &[0u8; 20]);
+
+    let mut ksuidms = KsuidMs::new(None, None);
+    ksuidms.zeroize();
+    assert_eq!(ksuidms.bytes(), &[0u8; 20]);
+
+    let secret = Zeroizing::new(Ksuid::new(None, None));
+    assert_eq!(Ksuid::from_str(&secret.to_string()).unwrap(), *secret);
+}
+
+#[test]
+fn test_ct_eq() {
+    let ksuid = Ksuid::new(None, None);
+    let mut bytes = *ksuid.bytes();
+    assert!(ksuid.ct_eq(&Ksuid::from_bytes(bytes)));
+    bytes[19] ^= 1;
+    assert!(!ksuid.ct_eq(&Ksuid::from_bytes(bytes)));
+
+    let ksuidms = KsuidMs::new(None, None);
+    let mut bytes = *ksuidms.bytes();
+    assert!(ksuidms.ct_eq(&KsuidMs::from_bytes(bytes)));
+    bytes[0] ^= 0x80;
+    assert!(!ksuidms.ct_eq(&KsuidMs::from_bytes(bytes)));
+}
+
+#[cfg(feature = "subtle")]
+#[test]
+fn test_subtle() {
+    use subtle::ConstantTimeEq;
+
+    let ksuid = Ksuid::new(None, None);
+    assert!(bool::from(ConstantTimeEq::ct_eq(&ksuid, &ksuid)));
+    assert!(!bool::from(ConstantTimeEq::ct_eq(&ksuid, &Ksuid::nil())));
+    let ksuidms = KsuidMs::new(None, None);
+    assert!(bool::from(ConstantTimeEq::ct_eq(&ksuidms, &ksuidms)));
+    assert!(!bool::from(ConstantTimeEq::ct_eq(
+        &ksuidms,
+        &KsuidMs::nil()
+    )));
+}
+
+#[cfg(feature = "insecure-fast-rng")]
+#[test]
+fn test_new_insecure() {
+    let timestamp = time::OffsetDateTime::from_unix_timestamp(1_621_627_443).unwrap();
+    let ksuids: HashSet<Ksuid> = (0..10_000)
+        .map(|_| Ksuid::new_insecure(Some(timestamp)))
+        .collect();
+    assert_eq!(ksuids.len(), 10_000);
+    assert!(ksuids.iter().all(|k| k.timestamp() == timestamp));
+
+    let ksuidms = KsuidMs::new_insecure(Some(timestamp));
+    assert_eq!(ksuidms.timestamp(), timestamp);
+    assert_ne!(ksuidms.payload(), KsuidMs::new_insecure(None).payload());
+}
+
+#[cfg(feature = "chacha")]
+#[test]
+fn test_new_seeded() {
+    let timestamp = time::OffsetDateTime::from_unix_timestamp(1_621_627_443).unwrap();
+
+    let mut rng = SeededRng::from_seed([42u8; 32]);
+    let run1: Vec<Ksuid> = (0..100)
+        .map(|_| Ksuid::new_seeded(Some(timestamp), &mut rng))
+        .collect();
+    let mut rng = SeededRng::from_seed([42u8; 32]);
+    let run2: Vec<Ksuid> = (0..100)
+        .map(|_| Ksuid::new_seeded(Some(timestamp), &mut rng))
+        .collect();
+    assert_eq!(run1, run2);
+    assert_eq!(run1.iter().collect::<HashSet<_>>().len(), 100);
+
+    let mut rng = SeededRng::from_seed([43u8; 32]);
+    assert_ne!(Ksuid::new_seeded(Some(timestamp), &mut rng), run1[0]);
+
+    let mut rng1 = SeededRng::from_seed([42u8; 32]);
+    let mut rng2 = rng1.clone();
+    assert_eq!(
+        KsuidMs::new_seeded(Some(timestamp), &mut rng1),
+        KsuidMs::new_seeded(Some(timestamp), &mut rng2)
+    );
+}
+
+#[test]
+fn test_entropy_source() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    struct Counting(Arc<AtomicUsize>);
+
+    impl EntropySource for Counting {
+        fn fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+            self.0.fetch_add(1, Ordering::SeqCst);
+            OsEntropy.fill_bytes(dest)
+        }
+    }
+
+    struct Failing;
+
+    impl EntropySource for Failing {
+        fn fill_bytes(&mut self, _dest: &mut [u8]) -> Result<(), Error> {
+            Err(Error::new("failing source".to_owned()))
+        }
+    }
+
+    let counter = Arc::new(AtomicUsize::new(0));
+    let mut source = Counting(counter.clone());
+    let ksuid = Ksuid::new_with_entropy(None, &mut source).unwrap();
+    let ksuidms = KsuidMs::new_with_entropy(None, &mut source).unwrap();
+    assert_ne!(ksuid.payload(), &[0u8; 16]);
+    assert_ne!(ksuidms.payload(), &[0u8; 15]);
+    assert_eq!(counter.load(Ordering::SeqCst), 2);
+    assert!(Ksuid::new_with_entropy(None, &mut Failing).is_err());
+
+    // Wraps the OS source, so doesn't affect the other tests
+    set_entropy_source(Counting(counter.clone())).unwrap();
+    Ksuid::new(None, None);
+    KsuidMs::new(None, None);
+    assert!(counter.load(Ordering::SeqCst) >= 4);
+    assert!(set_entropy_source(OsEntropy).is_err());
+}
+
+#[test]
+fn test_entropy_failure_hook() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static FAILURES: AtomicUsize = AtomicUsize::new(0);
+
+    struct Failing;
+
+    impl EntropySource for Failing {
+        fn fill_bytes(&mut self, _dest: &mut [u8]) -> Result<(), Error> {
+            Err(Error::new("unavailable"))
+        }
+    }
+
+    set_entropy_failure_hook(|err| {
+        if err.to_string() == "unavailable" {
+            FAILURES.fetch_add(1, Ordering::SeqCst);
+        }
+    })
+    .unwrap();
+    assert!(set_entropy_failure_hook(|_| ()).is_err());
+
+    assert!(Ksuid::new_with_entropy(None, &mut Failing).is_err());
+    assert_eq!(FAILURES.load(Ordering::SeqCst), 1);
+    let mut generator = Generator::new().with_entropy(Failing);
+    assert!(generator.try_generate::<KsuidMs>().is_err());
+    assert_eq!(FAILURES.load(Ordering::SeqCst), 2);
+
+    // Successes and direct calls aren't reported
+    Ksuid::new(None, None);
+    assert!(Failing.fill_bytes(&mut [0; 16]).is_err());
+    assert_eq!(FAILURES.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn test_secure_ksuid() {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    let a: SecureKsuid = SecureKsuid::new();
+    let b = SecureKsuid::<Ksuid>::default();
+    assert_ne!(a, b);
+    assert!(!a.is_nil());
+    assert_eq!(a.to_string(), a.into_inner().to_string());
+    let ksuid: Ksuid = a.into();
+    assert_eq!(&ksuid, a.as_ref());
+
+    let timestamp = UNIX_EPOCH + Duration::from_millis(1_621_627_443_124);
+    let token = SecureKsuid::<KsuidMs>::try_from_system_time(timestamp).unwrap();
+    assert_eq!(token.timestamp_millis(), 1_621_627_443_124);
+    assert_ne!(token.payload(), &[0; 15]);
+    let copy = token;
+    assert_eq!(token, copy);
+    assert_eq!(
+        format!("{:?}", token),
+        "SecureKsuid(2021-05-21T20:04:03.124Z/****)"
+    );
+}
+
+#[test]
+fn test_system_time() {
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    let timestamp = UNIX_EPOCH + Duration::from_secs(1_621_627_443);
+    let ksuid = Ksuid::from_system_time(timestamp, Some(&[3u8; 16]));
+    assert_eq!(ksuid.system_time(), timestamp);
+    assert_eq!(
+        ksuid,
+        Ksuid::from_seconds(Some(1_621_627_443), Some(&[3u8; 16]))
+    );
+
+    // Sub-second precision is truncated to what the type supports
+    let timestamp = UNIX_EPOCH + Duration::from_nanos(1_621_627_443_126_999_999);
+    let ksuid = Ksuid::from_system_time(timestamp, None);
+    assert_eq!(
+        ksuid.system_time(),
+        UNIX_EPOCH + Duration::from_secs(1_621_627_443)
+    );
+    let ksuidms = KsuidMs::from_system_time(timestamp, None);
+    assert_eq!(
+        ksuidms.system_time(),
+        UNIX_EPOCH + Duration::from_millis(1_621_627_443_124)
+    );
+    assert_eq!(ksuidms.timestamp_millis(), 1_621_627_443_124);
+
+    // Consistent with the `OffsetDateTime` accessors
+    let ksuid = Ksuid::from_system_time(SystemTime::now(), None);
+    assert_eq!(SystemTime::from(ksuid.timestamp()), ksuid.system_time());
+    let ksuidms = KsuidMs::from_system_time(SystemTime::now(), None);
+    assert_eq!(SystemTime::from(ksuidms.timestamp()), ksuidms.system_time());
+}
+
+#[test]
+fn test_min_max() {
+    assert_eq!(Ksuid::MIN.bytes(), &[0u8; 20]);
+    assert_eq!(Ksuid::MAX.bytes(), &[0xFFu8; 20]);
+    assert_eq!(Ksuid::MAX_STRING, "aWgEPTl1tmebfsQzFP4bxwgy80V");
+    assert_eq!(Ksuid::from_str(Ksuid::MAX_STRING).unwrap(), Ksuid::MAX);
+    assert_eq!(Ksuid::MIN.timestamp_seconds(), KSUID_MIN_TIMESTAMP);
+    assert_eq!(Ksuid::MAX.timestamp_seconds(), KSUID_MAX_TIMESTAMP);
+    assert!(is_valid_ksuid(Ksuid::MAX_STRING));
+
+    assert_eq!(KsuidMs::MIN.bytes(), Ksuid::MIN.bytes());
+    assert_eq!(KsuidMs::MAX.bytes(), Ksuid::MAX.bytes());
+    assert_eq!(KsuidMs::MAX.to_string(), KsuidMs::MAX_STRING);
+    assert_eq!(KsuidMs::MAX_STRING, Ksuid::MAX_STRING);
+
+    for _ in 0..100 {
+        let ksuid = Ksuid::new(None, None);
+        assert!(Ksuid::MIN < ksuid && ksuid < Ksuid::MAX);
+        assert!(Ksuid::MIN.to_string() < ksuid.to_string());
+        assert!(ksuid.to_string().as_str() < Ksuid::MAX_STRING);
+    }
+}
+
+#[test]
+fn test_nil() {
+    let nil = Ksuid::nil();
+    assert!(nil.is_nil());
+    assert_eq!(nil, Ksuid::MIN);
+    assert_eq!(nil.to_string(), "000000000000000000000000000");
+    assert_eq!(Ksuid::from_str("000000000000000000000000000").unwrap(), nil);
+    assert!(!Ksuid::new(None, None).is_nil());
+    assert!(Ksuid::from_seconds(Some(KSUID_EPOCH), Some(&[0; 16])).is_nil());
+    let mut bytes = [0u8; 20];
+    bytes[19] = 1;
+    assert!(!Ksuid::from_bytes(bytes).is_nil());
+
+    let nil = KsuidMs::nil();
+    assert!(nil.is_nil());
+    assert_eq!(nil, KsuidMs::MIN);
+    assert!(!KsuidMs::new(None, None).is_nil());
+}
+
+#[test]
+fn test_checked_offset() {
+    fn as_u128(bytes: &[u8; 20]) -> u128 {
+        u128::from_be_bytes(bytes[4..].try_into().unwrap())
+    }
+
+    let ksuid = Ksuid::from_seconds(Some(1_621_627_443), Some(&[0x7Fu8; 16]));
+    for offset in [0, 1, 255, 256, 0xFFFF, u64::MAX / 3, u64::MAX] {
+        let added = ksuid.checked_add(offset).unwrap();
+        assert_eq!(
+            as_u128(added.bytes()),
+            as_u128(ksuid.bytes()) + u128::from(offset)
+        );
+        assert_eq!(added.timestamp_raw(), ksuid.timestamp_raw());
+        assert_eq!(added.checked_sub(offset).unwrap(), ksuid);
+        let subbed = ksuid.checked_sub(offset).unwrap();
+        assert_eq!(
+            as_u128(subbed.bytes()),
+            as_u128(ksuid.bytes()) - u128::from(offset)
+        );
+    }
+
+    // Carries and borrows propagate into the timestamp
+    let ksuid = Ksuid::from_seconds(Some(1_621_627_443), Some(&[0xFFu8; 16]));
+    let next = ksuid.checked_add(1).unwrap();
+    assert_eq!(next.timestamp_raw(), ksuid.timestamp_raw() + 1);
+    assert_eq!(next.payload(), &[0u8; 16]);
+    assert_eq!(next.checked_sub(1).unwrap(), ksuid);
+
+    assert_eq!(Ksuid::MAX.checked_add(0).unwrap(), Ksuid::MAX);
+    assert!(Ksuid::MAX.checked_add(1).is_none());
+    assert!(Ksuid::MAX.checked_sub(u64::MAX).unwrap() < Ksuid::MAX);
+    assert_eq!(Ksuid::nil().checked_sub(0).unwrap(), Ksuid::nil());
+    assert!(Ksuid::nil().checked_sub(1).is_none());
+    assert_eq!(
+        Ksuid::nil().checked_add(u64::MAX).unwrap().bytes()[12..],
+        [0xFF; 8]
+    );
+
+    // Blocks of ids stay sorted
+    let start = KsuidMs::new(None, None);
+    let block: Vec<KsuidMs> = (0..100).map(|i| start.checked_add(i).unwrap()).collect();
+    assert!(block.windows(2).all(|w| w[0] < w[1]));
+}
+
+#[test]
+fn test_random_between() {
+    let a = Ksuid::from_seconds(Some(1_600_000_000), None);
+    let b = Ksuid::from_seconds(Some(1_700_000_000), None);
+    for _ in 0..1_000 {
+        let ksuid = Ksuid::random_between(&a, &b).unwrap();
+        assert!(a < ksuid && ksuid < b);
+        let ksuid = Ksuid::random_between(&b, &a).unwrap();
+        assert!(a < ksuid && ksuid < b);
+    }
+
+    // Narrow ranges
+    assert!(Ksuid::random_between(&a, &a).is_none());
+    let next = a.checked_add(1).unwrap();
+    assert!(Ksuid::random_between(&a, &next).is_none());
+    let two = a.checked_add(2).unwrap();
+    assert_eq!(Ksuid::random_between(&a, &two).unwrap(), next);
+    assert_eq!(Ksuid::random_between(&two, &a).unwrap(), next);
+
+    // Ranges crossing byte boundaries
+    let low = Ksuid::from_seconds(Some(1_600_000_000), Some(&[0xFF; 16]));
+    let high = low.checked_add(3).unwrap();
+    let seen: HashSet<Ksuid> = (0..1_000)
+        .map(|_| Ksuid::random_between(&low, &high).unwrap())
+        .collect();
+    assert_eq!(seen.len(), 2);
+    assert!(seen.iter().all(|k| low < *k && *k < high));
+
+    // The full range
+    let ksuidms = KsuidMs::random_between(&KsuidMs::MIN, &KsuidMs::MAX).unwrap();
+    assert!(KsuidMs::MIN < ksuidms && ksuidms < KsuidMs::MAX);
+}
+
+#[test]
+fn test_default() {
+    #[derive(Default)]
+    struct Row {
+        id: Ksuid,
+        parent: KsuidMs,
+    }
+
+    let row = Row::default();
+    assert!(row.id.is_nil());
+    assert!(row.parent.is_nil());
+    assert_eq!(Ksuid::default(), Ksuid::nil());
+
+    let mut id = Ksuid::new(None, None);
+    let taken = std::mem::take(&mut id);
+    assert!(id.is_nil());
+    assert!(!taken.is_nil());
+}
+
+#[test]
+fn test_fingerprint64() {
+    // These must never change
+    let ksuid = Ksuid::from_base62("1srOrx2ZWZBpBUvZwXKQmoEYga2").unwrap();
+    assert_eq!(ksuid.fingerprint64(), 0x7b69_b960_7a80_6196);
+    assert_eq!(Ksuid::nil().fingerprint64(), 0xee85_fafd_354b_0935);
+
+    // Covers the timestamp too
+    let later = Ksuid::from_seconds(Some(ksuid.timestamp_seconds() + 1), Some(ksuid.payload()));
+    assert_ne!(later.fingerprint64(), ksuid.fingerprint64());
+    assert_eq!(
+        KsuidMs::from_bytes(*ksuid.bytes()).fingerprint64(),
+        ksuid.fingerprint64()
+    );
+}
+
+#[test]
+fn test_timestamp_raw_u64() {
+    fn raw<K: KsuidLike>(ksuid: &K) -> u64 {
+        ksuid.timestamp_raw_u64()
+    }
+
+    let ksuid = Ksuid::from_base62("1srOrx2ZWZBpBUvZwXKQmoEYga2").unwrap();
+    assert_eq!(raw(&ksuid), ksuid.timestamp_raw() as u64);
+    assert_eq!(raw(&Ksuid::MAX), u32::MAX as u64);
+    assert_eq!(raw(&Ksuid::nil()), 0);
+
+    let ksuidms = KsuidMs::from_bytes(*ksuid.bytes());
+    assert_eq!(raw(&ksuidms), ksuidms.timestamp_raw());
+    assert_eq!(raw(&ksuidms) >> 8, raw(&ksuid));
+    assert_eq!(raw(&KsuidMs::MAX), (1 << 40) - 1);
+}
+
+#[test]
+fn test_precision() {
+    use std::time::Duration;
+
+    let ksuid = Ksuid::new(None, None);
+    assert_eq!(ksuid.precision(), TimestampPrecision::Seconds);
+    assert_eq!(ksuid.precision().as_duration(), Duration::from_secs(1));
+
+    let ksuidms = KsuidMs::new(None, None);
+    assert_eq!(ksuidms.precision(), TimestampPrecision::FourMillis);
+    assert_eq!(ksuidms.precision().as_duration(), Duration::from_millis(4));
+
+    // One unit of precision is the smallest step of the timestamp
+    let ksuidms = KsuidMs::new_raw((100 << 8) | 10, None);
+    let next = KsuidMs::new_raw(ksuidms.timestamp_raw() + 1, Some(ksuidms.payload()));
+    assert_eq!(
+        next.system_time()
+            .duration_since(ksuidms.system_time())
+            .unwrap(),
+        KsuidMs::PRECISION.as_duration()
+    );
+}
+
+#[test]
+fn test_setters() {
+    let original = Ksuid::from_base62("1srOrx2ZWZBpBUvZwXKQmoEYga2").unwrap();
+    let mut ksuid = original;
+    ksuid.set_timestamp_raw(original.timestamp_raw() + 1);
+    assert_eq!(ksuid.timestamp_seconds(), original.timestamp_seconds() + 1);
+    assert_eq!(ksuid.payload(), original.payload());
+    ksuid.set_timestamp(original.timestamp());
+    assert_eq!(ksuid, original);
+    ksuid.set_payload(&[0xAB; 16]);
+    assert_eq!(
+        ksuid,
+        Ksuid::new_raw(original.timestamp_raw(), Some(&[0xAB; 16]))
+    );
+
+    let original = KsuidMs::from_millis(Some(1_621_627_443_124), None);
+    let mut ksuid = original;
+    ksuid.set_timestamp_raw(0x12_3456_789A);
+    assert_eq!(ksuid.timestamp_raw(), 0x12_3456_789A);
+    assert_eq!(ksuid.payload(), original.payload());
+    // Only the low 40 bits are kept, like new_raw
+    ksuid.set_timestamp_raw(u64::MAX);
+    assert_eq!(ksuid, KsuidMs::new_raw(u64::MAX, Some(original.payload())));
+    ksuid.set_timestamp(original.timestamp());
+    assert_eq!(ksuid, original);
+    ksuid.set_payload(&[0xAB; 15]);
+    assert_eq!(ksuid.payload(), &[0xAB; 15]);
+    assert_eq!(ksuid.timestamp_millis(), 1_621_627_443_124);
+}
+
+#[test]
+#[should_panic]
+fn test_set_payload_wrong_length() {
+    Ksuid::new(None, None).set_payload(&[0; 15]);
+}
+
+#[test]
+fn test_duration_since() {
+    use time::Duration;
+
+    let a = Ksuid::from_seconds(Some(1_621_627_443), Some(&[0xFF; 16]));
+    let b = Ksuid::from_seconds(Some(1_621_627_443 + 86_400), Some(&[0; 16]));
+    assert_eq!(b.duration_since(&a), Duration::days(1));
+    assert_eq!(a.duration_since(&b), -Duration::days(1));
+    assert_eq!(a.duration_since(&a), Duration::ZERO);
+    assert_eq!(b - a, Duration::days(1));
+
+    // The full range of timestamps
+    assert_eq!(
+        Ksuid::MAX.duration_since(&Ksuid::nil()),
+        Duration::seconds(u32::MAX as i64)
+    );
+    let a = KsuidMs::from_millis(Some(1_621_627_443_000), None);
+    let b = KsuidMs::from_millis(Some(1_621_627_443_004), None);
+    assert_eq!(b.duration_since(&a), Duration::milliseconds(4));
+    assert_eq!(b - a, Duration::milliseconds(4));
+    assert_eq!(a - b, Duration::milliseconds(-4));
+}
+
+#[test]
+fn test_elapsed() {
+    use time::{Duration, OffsetDateTime};
+
+    let now = OffsetDateTime::now_utc();
+    let ksuid = Ksuid::new(Some(now - Duration::hours(1)), None);
+    let elapsed = ksuid.elapsed();
+    // Ksuid timestamps are truncated to seconds
+    assert!(elapsed >= Duration::hours(1) && elapsed < Duration::hours(1) + Duration::minutes(1));
+
+    let ksuid = KsuidMs::new(Some(now + Duration::hours(1)), None);
+    let elapsed = ksuid.elapsed();
+    assert!(elapsed <= -Duration::minutes(59) && elapsed > -Duration::hours(1));
+}
+
+#[test]
+fn test_validate_timestamp() {
+    use std::time::{Duration, SystemTime};
+
+    let now = SystemTime::now();
+    let minute = Duration::from_secs(60);
+
+    let ksuid = KsuidMs::from_system_time(now, None);
+    assert_eq!(ksuid.validate_not_future(Duration::ZERO), Ok(()));
+    assert_eq!(ksuid.validate_within(minute), Ok(()));
+
+    let ksuid = KsuidMs::from_system_time(now + 10 * minute, None);
+    assert!(matches!(
+        ksuid.validate_not_future(minute),
+        Err(Error::TimestampInFuture { ahead }) if ahead > 9 * minute && ahead <= 10 * minute
+    ));
+    assert!(matches!(
+        ksuid.validate_within(minute),
+        Err(Error::TimestampInFuture { .. })
+    ));
+    assert_eq!(ksuid.validate_not_future(20 * minute), Ok(()));
+    assert_eq!(ksuid.validate_within(20 * minute), Ok(()));
+
+    let ksuid = Ksuid::from_system_time(now - 10 * minute, None);
+    assert_eq!(ksuid.validate_not_future(Duration::ZERO), Ok(()));
+    let err = ksuid.validate_within(minute).unwrap_err();
+    assert!(matches!(err, Error::TimestampTooOld { age } if age >= 10 * minute));
+    assert!(err.to_string().ends_with(" old"));
+    assert_eq!(ksuid.validate_within(20 * minute), Ok(()));
+}
+
+#[test]
+fn test_add_sub_duration() {
+    use time::Duration;
+
+    let ksuid = Ksuid::from_seconds(Some(1_621_627_443), Some(&[7; 16]));
+    let later = ksuid + Duration::days(30);
+    assert_eq!(later.timestamp_seconds(), 1_621_627_443 + 30 * 86_400);
+    assert_eq!(later.payload(), ksuid.payload());
+    assert_eq!(later - Duration::days(30), ksuid);
+    // Sub-second durations are truncated
+    assert_eq!(
+        ksuid + Duration::milliseconds(1_500),
+        ksuid + Duration::seconds(1)
+    );
+
+    let mut shifted = ksuid;
+    shifted += Duration::hours(1);
+    shifted -= Duration::minutes(30);
+    assert_eq!(shifted.timestamp_seconds(), 1_621_627_443 + 1_800);
+
+    let ksuid = KsuidMs::from_millis(Some(1_621_627_443_124), Some(&[7; 15]));
+    let earlier = ksuid - Duration::milliseconds(500);
+    assert_eq!(earlier.timestamp_millis(), 1_621_627_442_624);
+    assert_eq!(earlier.payload(), ksuid.payload());
+    assert_eq!(earlier + Duration::milliseconds(500), ksuid);
+}
+
+#[test]
+#[should_panic(expected = "overflow when subtracting duration from ksuid")]
+fn test_sub_duration_overflow() {
+    let _ = Ksuid::nil() - time::Duration::seconds(1);
+}
+
+#[test]
+fn test_cmp_timestamp() {
+    use std::cmp::Ordering;
+
+    let early = Ksuid::from_seconds(Some(1_621_627_443), Some(&[0xFF; 16]));
+    let same = Ksuid::from_seconds(Some(1_621_627_443), Some(&[0; 16]));
+    let late = Ksuid::from_seconds(Some(1_621_627_444), Some(&[0; 16]));
+    assert_eq!(early.cmp_timestamp(&same), Ordering::Equal);
+    assert_eq!(early.cmp_timestamp(&late), Ordering::Less);
+    assert_eq!(late.cmp_timestamp(&early), Ordering::Greater);
+    assert_eq!(early.cmp(&same), Ordering::Greater);
+
+    // Stable sorts keep the original order within the same timestamp
+    let mut ids = vec![late, early, same];
+    ids.sort_by_key(by_timestamp);
+    assert_eq!(ids, [early, same, late]);
+    let mut ids = vec![late, same, early];
+    ids.sort_by(|a, b| a.cmp_timestamp(b));
+    assert_eq!(ids, [same, early, late]);
+
+    // Sub-second precision counts for KsuidMs
+    let early = KsuidMs::from_millis(Some(1_621_627_443_100), Some(&[0xFF; 15]));
+    let same = KsuidMs::from_millis(Some(1_621_627_443_101), Some(&[0; 15]));
+    let late = KsuidMs::from_millis(Some(1_621_627_443_200), Some(&[0; 15]));
+    assert_eq!(early.cmp_timestamp(&same), Ordering::Equal);
+    assert_eq!(early.cmp_timestamp(&late), Ordering::Less);
+    let mut ids = vec![late, early, same];
+    ids.sort_by_key(by_timestamp);
+    assert_eq!(ids, [early, same, late]);
+}
+
+#[test]
+fn test_raw_parts() {
+    let ksuid = Ksuid::from_base62("1srOrx2ZWZBpBUvZwXKQmoEYga2").unwrap();
+    let (timestamp, payload) = ksuid.to_raw_parts();
+    assert_eq!(timestamp, ksuid.timestamp_raw());
+    assert_eq!(payload.to_be_bytes(), *ksuid.payload());
+    assert_eq!(Ksuid::from_raw_parts(timestamp, payload), ksuid);
+
+    assert_eq!(Ksuid::nil().to_raw_parts(), (0, 0));
+    assert_eq!(Ksuid::MAX.to_raw_parts(), (u32::MAX, u128::MAX));
+
+    // Sorts the same as the ksuids
+    let later = Ksuid::from_raw_parts(timestamp, payload + 1);
+    assert!(later > ksuid);
+    assert!(later.to_raw_parts() > ksuid.to_raw_parts());
+
+    // The sub-second byte is the top byte of the payload word
+    let ms = KsuidMs::from_bytes(*ksuid.bytes());
+    assert_eq!(ms.to_raw_parts(), (timestamp, payload));
+    assert_eq!((payload >> 120) as u8, (ms.timestamp_raw() & 0xFF) as u8);
+    assert_eq!(KsuidMs::from_raw_parts(timestamp, payload), ms);
+}
+
+#[test]
+fn test_hash_raw_bytes() {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    fn hash<T: Hash + ?Sized>(value: &T) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    let ksuid = Ksuid::new(None, None);
+    assert_eq!(hash(&ksuid), hash(ksuid.bytes()));
+    let ksuidms = KsuidMs::new(None, None);
+    assert_eq!(hash(&ksuidms), hash(ksuidms.bytes()));
+}
+
+#[cfg(feature = "avro")]
+#[test]
+fn test_avro() {
+    use apache_avro::reader::datum::GenericDatumReader;
+    use apache_avro::types::Value;
+    use apache_avro::writer::datum::GenericDatumWriter;
+    use apache_avro::Schema;
+
+    fn roundtrip(schema: &Schema, value: impl Into<Value>) -> (Vec<u8>, Value) {
+        let writer = GenericDatumWriter::builder(schema).build().unwrap();
+        let encoded = writer.write_value_to_vec(value).unwrap();
+        let reader = GenericDatumReader::builder(schema).build().unwrap();
+        let decoded = reader.read_value(&mut &encoded[..]).unwrap();
+        (encoded, decoded)
+    }
+
+    let schema = ksuid_avro_schema();
+    let ksuid = Ksuid::from_base62("1srOrx2ZWZBpBUvZwXKQmoEYga2").unwrap();
+    let (encoded, decoded) = roundtrip(&schema, ksuid);
+    assert_eq!(encoded, ksuid.bytes());
+    assert_eq!(Ksuid::try_from(decoded).unwrap(), ksuid);
+
+    let ksuidms = KsuidMs::new(None, None);
+    let (_, decoded) = roundtrip(&schema, ksuidms);
+    assert_eq!(KsuidMs::try_from(decoded).unwrap(), ksuidms);
+
+    // Nullable fields within records
+    let record = Schema::parse_str(&format!(
+        r#"{{"type":"record","name":"Event","fields":[{{"name":"id","type":["null",{}]}}]}}"#,
+        KSUID_AVRO_SCHEMA
+    ))
+    .unwrap();
+    let value = Value::Record(vec![(
+        "id".to_owned(),
+        Value::Union(1, Box::new(ksuid.into())),
+    )]);
+    match roundtrip(&record, value).1 {
+        Value::Record(mut fields) => {
+            assert_eq!(Ksuid::try_from(fields.remove(0).1).unwrap(), ksuid);
+        }
+        other => panic!("Unexpected value {:?}", other),
+    }
+
+    assert!(Ksuid::try_from(Value::Bytes(vec![0; 19])).is_err());
+    assert!(Ksuid::try_from(Value::String("nope".to_owned())).is_err());
+    assert!(Ksuid::try_from(Value::Null).is_err());
+}
+
+#[cfg(feature = "rmpv")]
+#[test]
+fn test_rmpv() {
+    use rmpv::{Value, ValueRef};
+
+    let ksuid = Ksuid::from_base62("1srOrx2ZWZBpBUvZwXKQmoEYga2").unwrap();
+    let mut buf = Vec::new();
+    rmpv::encode::write_value(&mut buf, &Value::from(ksuid)).unwrap();
+    let mut expected = vec![0xc7, 20, 75];
+    expected.extend_from_slice(ksuid.bytes());
+    assert_eq!(buf, expected);
+
+    let decoded = rmpv::decode::read_value(&mut &buf[..]).unwrap();
+    assert_eq!(Ksuid::try_from(decoded).unwrap(), ksuid);
+    let decoded = rmpv::decode::read_value_ref(&mut &buf[..]).unwrap();
+    assert_eq!(Ksuid::try_from(decoded).unwrap(), ksuid);
+
+    let mut borrowed = Vec::new();
+    rmpv::encode::write_value_ref(&mut borrowed, &ValueRef::from(&ksuid)).unwrap();
+    assert_eq!(borrowed, buf);
+
+    let ksuidms = KsuidMs::new(None, None);
+    let value = Value::Array(vec![Value::from(ksuidms), Value::from("event")]);
+    let mut buf = Vec::new();
+    rmpv::encode::write_value(&mut buf, &value).unwrap();
+    match rmpv::decode::read_value(&mut &buf[..]).unwrap() {
+        Value::Array(mut items) => assert_eq!(KsuidMs::try_from(items.remove(0)).unwrap(), ksuidms),
+        other => panic!("Unexpected value {:?}", other),
+    }
+
+    assert!(Ksuid::try_from(Value::Ext(75, vec![0; 16])).is_err());
+    assert!(Ksuid::try_from(Value::Ext(-1, ksuid.bytes().to_vec())).is_err());
+    assert!(Ksuid::try_from(Value::from(ksuid.to_string())).is_err());
+}
+
+#[cfg(feature = "nohash-hasher")]
+#[test]
+fn test_nohash_key() {
+    use nohash_hasher::{IntMap, IntSet};
+
+    let ids: Vec<Ksuid> = (0..1000).map(|_| Ksuid::new(None, None)).collect();
+    let map: IntMap<NoHashKey, usize> = ids
+        .iter()
+        .enumerate()
+        .map(|(i, id)| (NoHashKey(*id), i))
+        .collect();
+    assert_eq!(map.len(), 1000);
+    for (i, id) in ids.iter().enumerate() {
+        assert_eq!(map[&NoHashKey(*id)], i);
+    }
+
+    let mut payload = [0u8; 16];
+    payload[0] = 1;
+    payload[15] = 3;
+    let key = NoHashKey(Ksuid::from_seconds(Some(1_621_627_443), Some(&payload)));
+    assert_eq!(key.key(), (1 << 56) ^ 3);
+
+    let set: IntSet<NoHashKey<KsuidMs>> = (0..100)
+        .map(|_| NoHashKey(KsuidMs::new(None, None)))
+        .collect();
+    assert_eq!(set.len(), 100);
+}
+
+#[test]
+fn test_partition() {
+    let ksuid = Ksuid::from_base62("1srOrx2ZWZBpBUvZwXKQmoEYga2").unwrap();
+    // These must never change
+    assert_eq!(ksuid.partition(1000), 189);
+    assert_eq!(ksuid.partition_with(1000, PartitionHash::Murmur2), 675);
+    assert_eq!(ksuid.partition(1), 0);
+
+    // The payload hash ignores the timestamp
+    let later = Ksuid::from_seconds(Some(ksuid.timestamp_seconds() + 1), Some(ksuid.payload()));
+    assert_eq!(later.partition(1000), ksuid.partition(1000));
+
+    // Ids from the same second spread over all partitions
+    let mut counts = [0; 8];
+    for _ in 0..800 {
+        let ksuid = Ksuid::from_seconds(Some(1_621_627_443), None);
+        counts[ksuid.partition(8) as usize] += 1;
+        assert!(ksuid.partition_with(8, PartitionHash::Murmur2) < 8);
+    }
+    assert!(counts.iter().all(|&count| count > 50));
+}
+
+#[test]
+#[should_panic]
+fn test_partition_zero() {
+    Ksuid::new(None, None).partition(0);
+}
+
+#[test]
+fn test_shard_id() {
+    let ksuid = Ksuid::from_base62("1srOrx2ZWZBpBUvZwXKQmoEYga2").unwrap();
+    // These must never change
+    assert_eq!(ksuid.shard_id(1000), 189);
+    assert_eq!(
+        ksuid.shard_id_with(1000, ShardAlgorithm::JumpConsistent),
+        705
+    );
+    assert_eq!(ksuid.shard_id(1), 0);
+    assert_eq!(ksuid.shard_id_with(1, ShardAlgorithm::JumpConsistent), 0);
+
+    // Adding a shard only moves ids to the new shard
+    let mut moved = 0;
+    for _ in 0..1000 {
+        let ksuid = Ksuid::from_seconds(Some(1_621_627_443), None);
+        let before = ksuid.shard_id_with(10, ShardAlgorithm::JumpConsistent);
+        let after = ksuid.shard_id_with(11, ShardAlgorithm::JumpConsistent);
+        assert!(before < 10);
+        if before != after {
+            assert_eq!(after, 10);
+            moved += 1;
+        }
+    }
+    assert!(moved > 30 && moved < 200);
+}
+
+#[test]
+#[should_panic]
+fn test_shard_id_zero() {
+    Ksuid::new(None, None).shard_id(0);
+}
+
+#[test]
+fn test_collision_probability() {
+    use std::time::Duration;
+    use svix_ksuid::analysis::{collision_probability, expected_collisions};
+
+    let day = Duration::from_secs(24 * 60 * 60);
+    assert_eq!(collision_probability::<Ksuid>(0.0, day), 0.0);
+    assert_eq!(collision_probability::<Ksuid>(1000.0, Duration::ZERO), 0.0);
+
+    // 2^64 ids in a single second is the birthday bound of 128 bits
+    let expected = expected_collisions::<Ksuid>(2f64.powi(64), Duration::from_secs(1));
+    assert!((expected - 0.5).abs() < 1e-9);
+    let probability = collision_probability::<Ksuid>(2f64.powi(64), Duration::from_secs(1));
+    assert!((probability - (1.0 - (-0.5f64).exp())).abs() < 1e-9);
+
+    // Grows with the square of the rate, and linearly with the horizon
+    let base = collision_probability::<Ksuid>(1e9, day);
+    assert!((collision_probability::<Ksuid>(2e9, day) / base - 4.0).abs() < 1e-6);
+    assert!((collision_probability::<Ksuid>(1e9, day * 2) / base - 2.0).abs() < 1e-6);
+
+    // KsuidMs has 250 timestamps per second
+    let expected = expected_collisions::<KsuidMs>(250.0 * 2f64.powi(60), Duration::from_secs(1));
+    assert!((expected - 250.0 / 2.0).abs() < 1e-6);
+
+    // Saturates at 1
+    assert_eq!(collision_probability::<KsuidMs>(1e30, day), 1.0);
+}
+
+#[test]
+fn test_ksuid_set() {
+    use std::collections::BTreeSet;
+
+    let ids: Vec<Ksuid> = (0..200)
+        .map(|i| Ksuid::from_seconds(Some(1_621_627_443 + i % 17), None))
+        .collect();
+    let expected: BTreeSet<Ksuid> = ids.iter().copied().collect();
+
+    let mut set = KsuidSet::new();
+    for (i, id) in ids.iter().enumerate() {
+        assert!(set.insert(*id));
+        assert!(!set.insert(*id));
+        assert_eq!(set.len(), i + 1);
+    }
+    assert_eq!(
+        set,
+        ids.iter().copied().chain(ids.iter().copied()).collect()
+    );
+    assert!(set.iter().eq(expected.iter().copied()));
+    assert_eq!(set.first(), expected.first().copied());
+    assert_eq!(set.last(), expected.last().copied());
+    assert!(ids.iter().all(|id| set.contains(id)));
+    assert!(!set.contains(&Ksuid::nil()));
+
+    // Ranges match BTreeSet
+    let sorted: Vec<Ksuid> = expected.iter().copied().collect();
+    let (a, b) = (sorted[20], sorted[120]);
+    assert!(set.range(a..b).eq(expected.range(a..b).copied()));
+    assert!(set.range(a..=b).eq(expected.range(a..=b).copied()));
+    assert!(set.range(..b).eq(expected.range(..b).copied()));
+    assert!(set.range(a..).eq(expected.range(a..).copied()));
+    assert_eq!(set.range(..).len(), 200);
+    assert_eq!(set.range(b..a).count(), 0);
+    let missing = Ksuid::from_seconds(Some(1_621_627_450), Some(&[0; 16]));
+    assert!(set.range(missing..).eq(expected.range(missing..).copied()));
+
+    // Union and intersection
+    let odd: KsuidSet = sorted.iter().skip(1).step_by(2).copied().collect();
+    let even: KsuidSet = sorted.iter().step_by(2).copied().collect();
+    let low: KsuidSet = sorted[..150].iter().copied().collect();
+    assert_eq!(odd.union(&even), set);
+    assert!(odd.intersection(&even).is_empty());
+    assert_eq!(set.intersection(&low), low);
+    assert_eq!(
+        even.intersection(&low).len(),
+        sorted[..150].iter().step_by(2).count()
+    );
+    assert_eq!(low.union(&KsuidSet::new()), low);
+
+    assert!(set.remove(&a));
+    assert!(!set.remove(&a));
+    assert!(!set.contains(&a));
+    assert_eq!(set.len(), 199);
+
+    let ms: KsuidSet<KsuidMs> = (0..10).map(|_| KsuidMs::new(None, None)).collect();
+    assert_eq!(ms.len(), 10);
+    assert!(ms.iter().zip(ms.iter().skip(1)).all(|(a, b)| a < b));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_ksuid_set_serde() {
+    let a = Ksuid::from_base62("1srOrx2ZWZBpBUvZwXKQmoEYga2").unwrap();
+    let b = Ksuid::from_base62("0ujtsYcgvSTl8PAuAdqWYSMnLOv").unwrap();
+    let set: KsuidSet = [a, b].into_iter().collect();
+    let json = serde_json::to_string(&set).unwrap();
+    assert_eq!(
+        json,
+        r#"["0ujtsYcgvSTl8PAuAdqWYSMnLOv","1srOrx2ZWZBpBUvZwXKQmoEYga2"]"#
+    );
+
+    // Unsorted and duplicate input is fine
+    let parsed: KsuidSet = serde_json::from_str(
+        r#"["1srOrx2ZWZBpBUvZwXKQmoEYga2","0ujtsYcgvSTl8PAuAdqWYSMnLOv","1srOrx2ZWZBpBUvZwXKQmoEYga2"]"#,
+    )
+    .unwrap();
+    assert_eq!(parsed, set);
+    assert!(serde_json::from_str::<KsuidSet>(r#"["not-a-ksuid"]"#).is_err());
+}
+
+#[test]
+fn test_delta() {
+    use std::io::ErrorKind;
+
+    let mut ids: Vec<Ksuid> = (0..500)
+        .map(|i| Ksuid::from_seconds(Some(1_621_627_443 + i / 100), None))
+        .collect();
+    ids.push(ids[10]);
+    ids.push(Ksuid::nil());
+    ids.push(Ksuid::from_bytes([0xff; 20]));
+    ids.sort();
+
+    let mut writer = DeltaWriter::new(Vec::new());
+    writer.write_all(&ids).unwrap();
+    let encoded = writer.into_inner().unwrap();
+    assert!(encoded.len() < ids.len() * 20);
+
+    let mut reader: DeltaReader<_> = DeltaReader::new(&encoded[..]);
+    for id in &ids {
+        assert_eq!(reader.read().unwrap().unwrap(), *id);
+    }
+    assert!(reader.read().unwrap().is_none());
+
+    let decoded: Vec<KsuidMs> = DeltaReader::new(&encoded[..])
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert!(decoded
+        .iter()
+        .zip(&ids)
+        .all(|(a, b)| a.bytes() == b.bytes()));
+
+    // The whole range takes the longest encoding
+    let mut writer = DeltaWriter::new(Vec::new());
+    writer.write(&Ksuid::from_bytes([0xff; 20])).unwrap();
+    let encoded = writer.into_inner().unwrap();
+    assert_eq!(encoded.len(), 23);
+    assert_eq!(
+        DeltaReader::<_, Ksuid>::new(&encoded[..])
+            .read()
+            .unwrap()
+            .unwrap(),
+        Ksuid::from_bytes([0xff; 20])
+    );
+
+    // Must be ascending
+    let mut writer = DeltaWriter::new(Vec::new());
+    writer.write(&ids[1]).unwrap();
+    let err = writer.write(&ids[0]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidInput);
+
+    // Truncated
+    let mut writer = DeltaWriter::new(Vec::new());
+    writer.write(&ids[100]).unwrap();
+    let encoded = writer.into_inner().unwrap();
+    let err = DeltaReader::<_, Ksuid>::new(&encoded[..encoded.len() - 1])
+        .read()
+        .unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+
+    // Too many bytes, and too many bits in the last one
+    let err = DeltaReader::<_, Ksuid>::new(&[0x80u8; 24][..])
+        .read()
+        .unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+    let mut overflow = [0xffu8; 23];
+    overflow[22] = 0x7f;
+    let err = DeltaReader::<_, Ksuid>::new(&overflow[..])
+        .read()
+        .unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+
+    // Deltas that go past the largest ksuid
+    let mut writer = DeltaWriter::new(Vec::new());
+    writer.write(&Ksuid::from_bytes([0xff; 20])).unwrap();
+    writer.write(&Ksuid::from_bytes([0xff; 20])).unwrap();
+    let mut encoded = writer.into_inner().unwrap();
+    *encoded.last_mut().unwrap() = 1;
+    let mut reader = DeltaReader::<_, Ksuid>::new(&encoded[..]);
+    assert!(reader.read().is_ok());
+    assert_eq!(reader.read().unwrap_err().kind(), ErrorKind::InvalidData);
+}
+
+#[test]
+fn test_slice() {
+    for len in [0, 1, 2, 100, 255, 256, 1000, 5000] {
+        let mut ids: Vec<Ksuid> = (0..len).map(|_| Ksuid::new(None, None)).collect();
+        let mut expected: Vec<String> = ids.iter().map(|id| id.to_string()).collect();
+        expected.sort();
+        slice::sort(&mut ids);
+        assert!(slice::is_sorted(&ids));
+        let sorted: Vec<String> = ids.iter().map(|id| id.to_string()).collect();
+        assert_eq!(sorted, expected);
+    }
+
+    // Spread over all timestamp bytes, with duplicates
+    let mut ids: Vec<KsuidMs> = (0..2000)
+        .map(|i| KsuidMs::new_raw((i % 700) * 0x0101_0101, Some(&[(i % 3) as u8; 15])))
+        .collect();
+    let mut expected = ids.clone();
+    expected.sort();
+    slice::sort(&mut ids);
+    assert_eq!(ids, expected);
+    slice::dedup_sorted(&mut ids);
+    expected.dedup();
+    assert_eq!(ids, expected);
+    assert!(ids.windows(2).all(|w| w[0] < w[1]));
+
+    // All equal
+    let mut ids = vec![Ksuid::MAX; 300];
+    slice::sort(&mut ids);
+    slice::dedup_sorted(&mut ids);
+    assert_eq!(ids, [Ksuid::MAX]);
+
+    assert!(slice::is_sorted::<Ksuid>(&[]));
+    assert!(!slice::is_sorted(&[Ksuid::MAX, Ksuid::nil()]));
+}
+
+#[test]
+fn test_slice_encode_all() {
+    let ids: Vec<KsuidMs> = (0..100).map(|_| KsuidMs::new(None, None)).collect();
+    let mut out = String::new();
+    slice::encode_all(&ids, &mut out, "\n");
+    let expected: Vec<String> = ids.iter().map(|id| id.to_string()).collect();
+    assert_eq!(out, expected.join("\n"));
+
+    // Appends to what's already there
+    slice::encode_all(&[Ksuid::nil(), Ksuid::MAX], &mut out, "");
+    assert!(out.ends_with(&format!("{}{}", Ksuid::nil(), Ksuid::MAX_STRING)));
+
+    let mut out = String::from("[]");
+    slice::encode_all::<Ksuid>(&[], &mut out, ",");
+    assert_eq!(out, "[]");
+}
+
+#[test]
+fn test_firebase_push_id() {
+    // From the Firebase documentation
+    let ksuid = KsuidMs::from_firebase_push_id("-JSOpn9ZC54A4P4RoqVa").unwrap();
+    assert_eq!(ksuid.timestamp_millis(), 1_405_961_581_220);
+    assert_eq!(ksuid.to_firebase_push_id(), "-JSOpn9ZC54A4P4RoqVa");
+
+    // Sub-4ms differences and the random part keep their order
+    let ids = [
+        "-Nf3kP0pqxu5sQeVb7Xy",
+        "-Nf3kP0pqxu5sQeVb7Xz",
+        "-Nf3kP0q--0000000000",
+        "-Nf3kP0r--0000000000",
+        "-Nf3kP0rzzzzzzzzzzzz",
+        "-Nf3kP0s--0000000000",
+        "-Nf3kP1---0000000000",
+    ];
+    let ksuids: Vec<KsuidMs> = ids
+        .iter()
+        .map(|id| KsuidMs::from_firebase_push_id(id).unwrap())
+        .collect();
+    assert!(ksuids.windows(2).all(|w| w[0] < w[1]));
+    for (ksuid, id) in ksuids.iter().zip(ids) {
+        assert_eq!(ksuid.to_firebase_push_id(), id);
+    }
+
+    assert!(KsuidMs::from_firebase_push_id("-JSOpn9ZC54A4P4Roq").is_err());
+    assert!(KsuidMs::from_firebase_push_id("-JSOpn9ZC54A4P4Roq+a").is_err());
+    // Before the KSUID epoch
+    assert_eq!(
+        KsuidMs::from_firebase_push_id("-----------------AAA"),
+        Err(Error::TimestampOutOfRange)
+    );
+}
+
+#[test]
+fn test_keys() {
+    use svix_ksuid::keys;
+
+    let mut ksuids: Vec<Ksuid> = (0..100)
+        .map(|i| Ksuid::from_seconds(Some(1_621_627_443 + i % 7), None))
+        .collect();
+    let mut by_key = ksuids.clone();
+    by_key.sort_by_key(keys::to_key_bytes);
+    ksuids.sort();
+    assert_eq!(ksuids, by_key);
+
+    for ksuid in &ksuids {
+        let key = keys::to_key_bytes(ksuid);
+        assert_eq!(keys::from_key_bytes::<Ksuid>(&key).unwrap(), *ksuid);
+        assert_eq!(
+            keys::from_key_bytes::<KsuidMs>(&key).unwrap().bytes(),
+            ksuid.bytes()
+        );
+    }
+    assert!(keys::from_key_bytes::<Ksuid>(&[0; 21]).is_err());
+
+    // Prefixed keys sort by prefix, then by ksuid
+    let mut prefixed: Vec<Vec<u8>> = ksuids
+        .iter()
+        .enumerate()
+        .map(|(i, ksuid)| keys::prefixed(&[(i % 3) as u8], ksuid))
+        .collect();
+    prefixed.sort();
+    for pair in prefixed.windows(2) {
+        let (a_prefix, a) = keys::split_prefixed::<Ksuid>(&pair[0]).unwrap();
+        let (b_prefix, b) = keys::split_prefixed::<Ksuid>(&pair[1]).unwrap();
+        assert!(a_prefix < b_prefix || (a_prefix == b_prefix && a < b));
+    }
+
+    let (start, end) = keys::prefix_range::<Ksuid>(&[1]);
+    let in_range = prefixed
+        .iter()
+        .filter(|key| start <= **key && **key <= end)
+        .count();
+    assert_eq!(in_range, 33);
+
+    let (prefix, ksuid) = keys::split_prefixed::<Ksuid>(&[0; 20]).unwrap();
+    assert!(prefix.is_empty());
+    assert!(ksuid.is_nil());
+    assert!(keys::split_prefixed::<Ksuid>(&[0; 19]).is_err());
+}
+
+#[test]
+fn test_timestamp_range() {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    let min = Ksuid::try_from_seconds(KSUID_MIN_TIMESTAMP, None).unwrap();
+    assert_eq!(min.timestamp_raw(), 0);
+    assert_eq!(min.timestamp_seconds(), KSUID_MIN_TIMESTAMP);
+    let max = Ksuid::try_from_seconds(KSUID_MAX_TIMESTAMP, None).unwrap();
+    assert_eq!(max.timestamp_raw(), u32::MAX);
+    assert_eq!(max.timestamp_seconds(), KSUID_MAX_TIMESTAMP);
+    assert_eq!(
+        Ksuid::try_from_seconds(KSUID_MIN_TIMESTAMP - 1, None).unwrap_err(),
+        Error::TimestampOutOfRange
+    );
+    assert_eq!(
+        Ksuid::try_from_seconds(KSUID_MAX_TIMESTAMP + 1, None).unwrap_err(),
+        Error::TimestampOutOfRange
+    );
+
+    let max = KsuidMs::try_from_seconds(KSUID_MAX_TIMESTAMP, None).unwrap();
+    assert_eq!(max.timestamp_seconds(), KSUID_MAX_TIMESTAMP);
+    assert!(KsuidMs::try_from_seconds(KSUID_MAX_TIMESTAMP + 1, None).is_err());
+    let max = KsuidMs::try_from_millis(KSUID_MAX_TIMESTAMP * 1_000 + 999, None).unwrap();
+    assert_eq!(max.timestamp_millis(), KSUID_MAX_TIMESTAMP * 1_000 + 996);
+    assert!(KsuidMs::try_from_millis((KSUID_MAX_TIMESTAMP + 1) * 1_000, None).is_err());
+    assert!(KsuidMs::try_from_millis(KSUID_MIN_TIMESTAMP * 1_000, None).is_ok());
+    assert!(KsuidMs::try_from_millis(KSUID_MIN_TIMESTAMP * 1_000 - 1, None).is_err());
+
+    let before = UNIX_EPOCH + Duration::from_millis(KSUID_MIN_TIMESTAMP as u64 * 1_000 - 1);
+    assert!(Ksuid::try_from_system_time(before, None).is_err());
+    assert!(KsuidMs::try_from_system_time(before + Duration::from_millis(1), None).is_ok());
+
+    let timestamp = time::OffsetDateTime::from_unix_timestamp(KSUID_MAX_TIMESTAMP).unwrap();
+    assert_eq!(
+        Ksuid::try_new(timestamp, None).unwrap().timestamp(),
+        timestamp
+    );
+    assert!(Ksuid::try_new(timestamp + time::Duration::SECOND, None).is_err());
+
+    assert!(Error::TimestampOutOfRange
+        .to_string()
+        .contains(&KSUID_MAX_TIMESTAMP.to_string()));
+}
+
+#[test]
+fn test_timestamp_overflow() {
+    use TimestampOverflow::*;
+
+    for overflow in [Wrap, Saturate, Error] {
+        let min = Ksuid::from_seconds_with(KSUID_MIN_TIMESTAMP, None, overflow).unwrap();
+        assert_eq!(min.timestamp_raw(), 0);
+        let max = Ksuid::from_seconds_with(KSUID_MAX_TIMESTAMP, None, overflow).unwrap();
+        assert_eq!(max.timestamp_raw(), u32::MAX);
+
+        let min = KsuidMs::from_millis_with(KSUID_MIN_TIMESTAMP * 1_000, None, overflow).unwrap();
+        assert_eq!(min.timestamp_raw(), 0);
+        let max =
+            KsuidMs::from_millis_with(KSUID_MAX_TIMESTAMP * 1_000 + 999, None, overflow).unwrap();
+        assert_eq!(max.timestamp_raw(), (u64::from(u32::MAX) << 8) | 249);
+    }
+
+    // Wrapping keeps the low 32 bits, like the reference implementation
+    let wrapped = Ksuid::from_seconds_with(KSUID_MIN_TIMESTAMP - 1, None, Wrap).unwrap();
+    assert_eq!(wrapped.timestamp_raw(), u32::MAX);
+    let wrapped = Ksuid::from_seconds_with(KSUID_MAX_TIMESTAMP + 1, None, Wrap).unwrap();
+    assert_eq!(wrapped.timestamp_raw(), 0);
+    assert_eq!(
+        Ksuid::from_seconds(Some(KSUID_MAX_TIMESTAMP + 1), None).timestamp_raw(),
+        0
+    );
+    let wrapped = KsuidMs::from_millis_with(KSUID_MIN_TIMESTAMP * 1_000 - 1, None, Wrap).unwrap();
+    assert_eq!(wrapped.timestamp_raw(), (u64::from(u32::MAX) << 8) | 249);
+    assert!(Ksuid::from_seconds_with(i64::MIN, None, Wrap).is_ok());
+
+    let saturated = Ksuid::from_seconds_with(KSUID_MIN_TIMESTAMP - 1, None, Saturate).unwrap();
+    assert_eq!(saturated.timestamp_raw(), 0);
+    let saturated = Ksuid::from_seconds_with(i64::MAX, None, Saturate).unwrap();
+    assert_eq!(saturated.timestamp_raw(), u32::MAX);
+    let saturated = KsuidMs::from_seconds_with(i64::MIN, None, Saturate).unwrap();
+    assert_eq!(saturated.timestamp_raw(), 0);
+    let saturated = KsuidMs::from_millis_with(i64::MAX, None, Saturate).unwrap();
+    assert_eq!(saturated.timestamp_raw(), (u64::from(u32::MAX) << 8) | 249);
+
+    for timestamp in [
+        KSUID_MIN_TIMESTAMP - 1,
+        KSUID_MAX_TIMESTAMP + 1,
+        i64::MIN,
+        i64::MAX,
+    ] {
+        assert_eq!(
+            Ksuid::from_seconds_with(timestamp, None, Error).unwrap_err(),
+            svix_ksuid::Error::TimestampOutOfRange
+        );
+        assert!(KsuidMs::from_seconds_with(timestamp, None, Error).is_err());
+    }
+    assert_eq!(TimestampOverflow::default(), Wrap);
+}
+
+#[test]
+fn test_generator() {
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    struct Fixed(SystemTime);
+
+    impl Clock for Fixed {
+        fn now(&self) -> SystemTime {
+            self.0
+        }
+    }
+
+    struct Zeros;
+
+    impl EntropySource for Zeros {
+        fn fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+            dest.fill(0);
+            Ok(())
+        }
+    }
+
+    let timestamp = UNIX_EPOCH + Duration::from_millis(1_621_627_443_124);
+    let mut generator = Generator::new()
+        .with_clock(Fixed(timestamp))
+        .with_entropy(Zeros);
+    let ksuid: Ksuid = generator.generate();
+    assert_eq!(
+        ksuid,
+        Ksuid::from_seconds(Some(1_621_627_443), Some(&[0; 16]))
+    );
+    let ksuidms: KsuidMs = generator.try_generate().unwrap();
+    assert_eq!(ksuidms.system_time(), timestamp);
+    assert_eq!(ksuidms.payload(), &[0; 15]);
+    assert_eq!(generator.clock().now(), timestamp);
+
+    // The region is kept when swapping the clock or entropy source
+    let mut generator = Generator::new()
+        .with_region(0xAB)
+        .with_clock(Fixed(timestamp))
+        .with_entropy(Zeros);
+    let ksuid: Ksuid = generator.generate();
+    assert_eq!(ksuid.region(), 0xAB);
+    assert_eq!(&ksuid.payload()[1..], &[0; 15]);
+    assert_eq!(generator.generate::<KsuidMs>().region(), 0xAB);
+
+    let mut generator = Generator::new();
+    let first = generator.generate::<Ksuid>();
+    let second = generator.generate::<Ksuid>();
+    assert_ne!(first, second);
+    let now = Ksuid::from_system_time(SystemClock.now(), None);
+    assert!((first.timestamp_seconds() - now.timestamp_seconds()).abs() < 2);
+
+    let clock = std::sync::Arc::new(Fixed(timestamp));
+    let mut generator = Generator::new().with_clock(clock.clone());
+    assert_eq!(generator.generate::<KsuidMs>().system_time(), timestamp);
+}
+
+#[cfg(feature = "test-util")]
+#[test]
+fn test_mock_clock() {
+    use std::time::{Duration, UNIX_EPOCH};
+    use svix_ksuid::testing::MockClock;
+
+    let start = UNIX_EPOCH + Duration::from_secs(1_621_627_443);
+    let clock = MockClock::new(start);
+    let mut generator = Generator::new().with_clock(clock.clone());
+
+    // Frozen by default
+    let ids: Vec<KsuidMs> = (0..10).map(|_| generator.generate()).collect();
+    assert!(ids.iter().all(|k| k.system_time() == start));
+
+    clock.advance(Duration::from_millis(500));
+    assert_eq!(
+        generator.generate::<KsuidMs>().system_time(),
+        start + Duration::from_millis(500)
+    );
+
+    clock.jump(start);
+    clock.step(Duration::from_millis(4));
+    let ids: Vec<KsuidMs> = (0..10).map(|_| generator.generate()).collect();
+    for (i, ksuid) in ids.iter().enumerate() {
+        assert_eq!(
+            ksuid.system_time(),
+            start + Duration::from_millis(4 * i as u64)
+        );
+    }
+    assert!(ids.windows(2).all(|w| w[0] < w[1]));
+
+    clock.freeze();
+    let now = clock.now();
+    assert_eq!(clock.now(), now);
+
+    // Jumping backwards
+    clock.jump(start - Duration::from_secs(60));
+    assert_eq!(
+        generator.generate::<Ksuid>().timestamp_seconds(),
+        1_621_627_443 - 60
+    );
+}
+
+#[cfg(feature = "test-util")]
+#[test]
+fn test_testing_spread() {
+    use std::time::{Duration, UNIX_EPOCH};
+    use svix_ksuid::testing::{spread, spread_with, Distribution};
+
+    let from = UNIX_EPOCH + Duration::from_secs(1_600_000_000);
+    let to = from + Duration::from_secs(30 * 24 * 60 * 60);
+
+    let ksuids: Vec<Ksuid> = spread(1_000, from, to);
+    assert_eq!(ksuids.len(), 1_000);
+    assert!(ksuids.windows(2).all(|w| w[0] <= w[1]));
+    assert!(ksuids
+        .iter()
+        .all(|k| from <= k.system_time() && k.system_time() <= to));
+    // Uniform, so both halves get a fair share
+    let middle = from + Duration::from_secs(15 * 24 * 60 * 60);
+    let first_half = ksuids.iter().filter(|k| k.system_time() < middle).count();
+    assert!((300..700).contains(&first_half));
+
+    // Reversed ranges work too
+    let ksuids: Vec<KsuidMs> = spread(100, to, from);
+    assert!(ksuids
+        .iter()
+        .all(|k| from <= k.system_time() && k.system_time() <= to));
+
+    let ksuids: Vec<KsuidMs> = spread_with(1_000, from, to, Distribution::Clustered(2));
+    assert!(ksuids.windows(2).all(|w| w[0] <= w[1]));
+    let first = ksuids[0].timestamp_seconds();
+    let last = ksuids[999].timestamp_seconds();
+    let in_clusters = ksuids
+        .iter()
+        .filter(|k| {
+            let t = k.timestamp_seconds();
+            t - first <= 30 * 24 * 60 * 60 / 100 || last - t <= 30 * 24 * 60 * 60 / 100
+        })
+        .count();
+    assert_eq!(in_clusters, 1_000);
+
+    let single: Vec<Ksuid> = spread(10, from, from);
+    assert!(single.iter().all(|k| k.system_time() == from));
+
+    // A sub-second start is rounded up, not truncated to before it
+    let from = from + Duration::from_millis(1_500);
+    let to = from + Duration::from_secs(2);
+    let ksuids: Vec<Ksuid> = spread(100, from, to);
+    assert!(ksuids
+        .iter()
+        .all(|k| from <= k.system_time() && k.system_time() <= to));
+    let ksuids: Vec<KsuidMs> = spread(100, from + Duration::from_millis(1), to);
+    assert!(ksuids
+        .iter()
+        .all(|k| from < k.system_time() && k.system_time() <= to));
+    assert!(spread::<Ksuid>(0, from, to).is_empty());
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn test_chrono() {
+    use chrono::{DateTime, TimeZone, Utc};
+
+    let timestamp: DateTime<Utc> = Utc.timestamp_opt(1_621_627_443, 0).unwrap();
+    let ksuid = Ksuid::new_chrono(Some(timestamp), None);
+    assert_eq!(ksuid.timestamp_chrono(), timestamp);
+    assert_eq!(ksuid.timestamp_seconds(), 1_621_627_443);
+
+    // Sub-second precision is truncated to what the type supports
+    let timestamp = Utc.timestamp_millis_opt(1_621_627_443_126).unwrap();
+    let ksuid = Ksuid::new_chrono(Some(timestamp), None);
+    assert_eq!(
+        ksuid.timestamp_chrono().timestamp_millis(),
+        1_621_627_443_000
+    );
+    let ksuidms = KsuidMs::new_chrono(Some(timestamp), None);
+    assert_eq!(
+        ksuidms.timestamp_chrono().timestamp_millis(),
+        1_621_627_443_124
+    );
+    assert_eq!(ksuidms.timestamp_millis(), 1_621_627_443_124);
+
+    let now = time::OffsetDateTime::now_utc().unix_timestamp();
+    let ksuidms = KsuidMs::new_chrono(None, None);
+    assert!((ksuidms.timestamp_chrono().timestamp() - now).abs() < 2);
+}
+
+#[cfg(feature = "jiff")]
+#[test]
+fn test_jiff() {
+    use jiff::Timestamp;
+
+    let timestamp = Timestamp::from_second(1_621_627_443).unwrap();
+    let ksuid = Ksuid::from_jiff(timestamp, None);
+    assert_eq!(ksuid.timestamp_jiff(), timestamp);
+    assert_eq!(ksuid.timestamp_seconds(), 1_621_627_443);
+
+    // Sub-second precision is truncated to what the type supports
+    let timestamp = Timestamp::from_millisecond(1_621_627_443_126).unwrap();
+    let ksuid = Ksuid::from_jiff(timestamp, None);
+    assert_eq!(ksuid.timestamp_jiff().as_millisecond(), 1_621_627_443_000);
+    let ksuidms = KsuidMs::from_jiff(timestamp, Some(&[1u8; 15]));
+    assert_eq!(ksuidms.timestamp_jiff().as_millisecond(), 1_621_627_443_124);
+    assert_eq!(ksuidms.payload(), &[1u8; 15]);
+}
+
+#[cfg(all(feature = "tokio", feature = "test-util"))]
+#[test]
+fn test_prefetch_generator() {
+    use std::time::{Duration, UNIX_EPOCH};
+    use svix_ksuid::testing::MockClock;
+
+    let start = UNIX_EPOCH + Duration::from_secs(1_621_627_443);
+    let clock = MockClock::new(start);
+    let generator = Generator::new().with_clock(clock.clone());
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap();
+    runtime.block_on(async {
+        let pool = PrefetchGenerator::<KsuidMs>::spawn(generator, 8, Duration::from_millis(50));
+        // The refill task hasn't had a chance to run yet
+        assert!(pool.try_next().is_none());
+        let first = pool.next().await.unwrap();
+        assert_eq!(first.system_time(), start);
+        assert_eq!(pool.len(), 7);
+
+        let mut ids = HashSet::new();
+        ids.insert(first);
+        for _ in 0..100 {
+            assert!(ids.insert(pool.next().await.unwrap()));
+        }
+        assert!(pool.len() <= 8);
+
+        // Old ksuids are dropped, the next one is generated at the new time
+        let later = start + Duration::from_secs(60);
+        clock.jump(later);
+        std::thread::sleep(Duration::from_millis(60));
+        assert_eq!(pool.next().await.unwrap().system_time(), later);
+    });
+}
+
+#[cfg(feature = "tokio")]
+#[test]
+fn test_prefetch_generator_failure() {
+    use std::time::Duration;
+
+    // Succeeds for the first `n` ksuids
+    struct FailingAfter(usize);
+
+    impl EntropySource for FailingAfter {
+        fn fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+            if self.0 == 0 {
+                return Err(Error::new("failing source".to_owned()));
+            }
+            self.0 -= 1;
+            dest.fill(1);
+            Ok(())
+        }
+    }
+
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap();
+    runtime.block_on(async {
+        let generator = Generator::new().with_entropy(FailingAfter(12));
+        let pool = PrefetchGenerator::<Ksuid>::spawn(generator, 8, Duration::from_secs(60));
+        // The first batch is handed out, then the pool is closed instead of waiting forever
+        for _ in 0..8 {
+            pool.next().await.unwrap();
+        }
+        assert_eq!(
+            pool.next().await.unwrap_err(),
+            Error::new("failing source".to_owned())
+        );
+        assert!(pool.next().await.is_err());
+        assert!(pool.try_next().is_none());
+    });
+}
+
+#[cfg(feature = "tower")]
+#[test]
+fn test_request_id_layer() {
+    use http::{HeaderName, Request};
+    use tower::{service_fn, Layer, ServiceExt};
+
+    // Returns the request id header and extension seen by the inner service
+    let call = |layer: &RequestIdLayer, req: Request<()>| {
+        let service = layer.layer(service_fn(|req: Request<()>| async move {
+            let header = req.headers().clone();
+            let RequestId(id) = *req.extensions().get::<RequestId>().unwrap();
+            Ok::<_, std::convert::Infallible>((header, id))
+        }));
+        tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap()
+            .block_on(service.oneshot(req))
+            .unwrap()
+    };
+
+    let layer = RequestIdLayer::new();
+    let base62 = "1srOrx2ZWZBpBUvZwXKQmoEYga2";
+
+    // Reuses valid inbound ids
+    let req = Request::get("/")
+        .header("x-request-id", base62)
+        .body(())
+        .unwrap();
+    let (headers, id) = call(&layer, req);
+    assert_eq!(id, KsuidMs::from_base62(base62).unwrap());
+    assert_eq!(headers.get(REQUEST_ID_HEADER).unwrap(), base62);
+
+    // Replaces missing and invalid ones
+    let before = KsuidMs::from_millis(None, None).timestamp_millis();
+    let (headers, id) = call(&layer, Request::get("/").body(()).unwrap());
+    assert!(id.timestamp_millis() >= before);
+    assert_eq!(headers.get(REQUEST_ID_HEADER).unwrap(), &id.to_string());
+
+    let req = Request::get("/")
+        .header("x-request-id", "not-a-ksuid")
+        .body(())
+        .unwrap();
+    let (headers, id) = call(&layer, req);
+    assert_eq!(headers.get(REQUEST_ID_HEADER).unwrap(), &id.to_string());
+    assert_eq!(headers.get_all(REQUEST_ID_HEADER).iter().count(), 1);
+
+    // Custom header
+    let layer = RequestIdLayer::with_header(HeaderName::from_static("x-trace"));
+    let req = Request::get("/")
+        .header("x-trace", base62)
+        .body(())
+        .unwrap();
+    let (headers, id) = call(&layer, req);
+    assert_eq!(id.to_string(), base62);
+    assert!(headers.get(REQUEST_ID_HEADER).is_none());
+}
+
+#[cfg(feature = "tracing")]
+#[test]
+fn test_record_ksuid() {
+    use std::sync::{Arc, Mutex};
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Metadata, Subscriber};
+
+    // Collects the fields recorded as strings
+    #[derive(Clone, Default)]
+    struct Recorder(Arc<Mutex<Vec<(String, String)>>>);
+
+    impl Visit for Recorder {
+        fn record_str(&mut self, field: &Field, value: &str) {
+            let mut fields = self.0.lock().unwrap();
+            fields.push((field.name().to_owned(), value.to_owned()));
+        }
+
+        fn record_debug(&mut self, _field: &Field, _value: &dyn std::fmt::Debug) {}
+    }
+
+    impl Subscriber for Recorder {
+        fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, _span: &Attributes<'_>) -> Id {
+            Id::from_u64(1)
+        }
+
+        fn record(&self, _span: &Id, values: &Record<'_>) {
+            values.record(&mut self.clone());
+        }
+
+        fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+        fn event(&self, _event: &Event<'_>) {}
+
+        fn enter(&self, _span: &Id) {}
+
+        fn exit(&self, _span: &Id) {}
+    }
+
+    let recorder = Recorder::default();
+    let ksuid = Ksuid::new(None, None);
+    let ksuidms = KsuidMs::new(None, None);
+    tracing::subscriber::with_default(recorder.clone(), || {
+        let span = tracing::info_span!(
+            "request",
+            id = tracing::field::Empty,
+            parent = tracing::field::Empty
+        );
+        record_ksuid(&span, "id", &ksuid);
+        record_ksuid(&span, "parent", &ksuidms);
+        // Undeclared fields are ignored
+        record_ksuid(&span, "other", &ksuid);
+    });
+
+    let fields = recorder.0.lock().unwrap();
+    assert_eq!(
+        *fields,
+        vec![
+            ("id".to_owned(), ksuid.to_string()),
+            ("parent".to_owned(), ksuidms.to_string())
+        ]
+    );
+}
+
+#[cfg(feature = "actix-web")]
+#[test]
+fn test_actix_web() {
+    use actix_web::body::to_bytes;
+    use actix_web::http::StatusCode;
+    use actix_web::test::{self, TestRequest};
+    use actix_web::{web, App, FromRequest, ResponseError};
+
+    actix_web::rt::System::new().block_on(async {
+        let base62 = "1srOrx2ZWZBpBUvZwXKQmoEYga2";
+        let req = TestRequest::default().param("id", base62).to_http_request();
+        assert_eq!(
+            Ksuid::extract(&req).await.unwrap(),
+            Ksuid::from_base62(base62).unwrap()
+        );
+        assert_eq!(
+            KsuidMs::extract(&req).await.unwrap(),
+            KsuidMs::from_base62(base62).unwrap()
+        );
+
+        let req = TestRequest::default()
+            .param("id", "not-a-ksuid")
+            .to_http_request();
+        let err = Ksuid::extract(&req).await.unwrap_err();
+        let rejection = err.as_error::<KsuidRejection>().unwrap();
+        assert_eq!(
+            rejection.detail(),
+            "Got base62 ksuid of unexpected length 11"
+        );
+        let response = rejection.error_response();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(
+            response.headers().get("content-type").unwrap(),
+            "application/problem+json"
+        );
+        let body = to_bytes(response.into_body()).await.unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(body["status"], 400);
+        assert_eq!(body["detail"], "Got base62 ksuid of unexpected length 11");
+
+        // Routes without exactly one path parameter are a server bug, not an invalid id
+        let req = TestRequest::default().to_http_request();
+        let err = Ksuid::extract(&req).await.unwrap_err();
+        assert_eq!(
+            err.as_response_error().status_code(),
+            StatusCode::INTERNAL_SERVER_ERROR
+        );
+        let req = TestRequest::default()
+            .param("a", base62)
+            .param("b", base62)
+            .to_http_request();
+        let err = Ksuid::extract(&req).await.unwrap_err();
+        assert_eq!(
+            err.as_response_error().status_code(),
+            StatusCode::INTERNAL_SERVER_ERROR
+        );
+
+        // The error handlers render any parameter error as problem details
+        #[derive(Deserialize)]
+        struct Paging {
+            limit: u32,
+        }
+
+        async fn get_number(n: web::Path<u32>, paging: web::Query<Paging>) -> String {
+            format!("{} {}", n, paging.limit)
+        }
+
+        let app = test::init_service(
+            App::new()
+                .app_data(web::PathConfig::default().error_handler(actix_path_error_handler))
+                .app_data(web::QueryConfig::default().error_handler(actix_query_error_handler))
+                .route("/numbers/{n}", web::get().to(get_number)),
+        )
+        .await;
+        for (uri, title) in [
+            ("/numbers/abc?limit=1", "Invalid path parameters"),
+            ("/numbers/1?limit=abc", "Invalid query string"),
+        ] {
+            let req = TestRequest::get().uri(uri).to_request();
+            let response = test::call_service(&app, req).await;
+            assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+            assert_eq!(
+                response.headers().get("content-type").unwrap(),
+                "application/problem+json"
+            );
+            let body: serde_json::Value = test::read_body_json(response).await;
+            assert_eq!(body["title"], title);
+            assert_eq!(body["status"], 400);
+        }
+    });
+
+    // Quotes in the detail are escaped
+    let rejection = KsuidRejection::new("\"quoted\"");
+    let body: serde_json::Value = serde_json::from_str(&rejection.to_problem_json()).unwrap();
+    assert_eq!(body["detail"], "\"quoted\"");
+}
+
+#[cfg(feature = "axum")]
+#[test]
+fn test_axum() {
+    use axum::body::{to_bytes, Body};
+    use axum::http::{Request, StatusCode};
+    use axum::routing::get;
+    use axum::Router;
+    use tower::ServiceExt;
+
+    async fn get_item(KsuidParam(id): KsuidParam) -> String {
+        id.to_string()
+    }
+
+    async fn get_item_ms(KsuidParam(id): KsuidParam<KsuidMs>) -> String {
+        id.timestamp_millis().to_string()
+    }
+
+    let app = Router::new()
+        .route("/items/{id}", get(get_item))
+        .route("/ms/{id}", get(get_item_ms))
+        .route("/tenants/{tenant}/items/{id}", get(get_item));
+    let call = |uri: &str| {
+        let req = Request::get(uri).body(Body::empty()).unwrap();
+        let app = app.clone();
+        tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap()
+            .block_on(async move {
+                let response = app.oneshot(req).await.unwrap();
+                let status = response.status();
+                let content_type = response.headers().get("content-type").cloned();
+                let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+                (
+                    status,
+                    content_type,
+                    String::from_utf8(body.to_vec()).unwrap(),
+                )
+            })
+    };
+
+    let base62 = "1srOrx2ZWZBpBUvZwXKQmoEYga2";
+    let (status, _, body) = call(&format!("/items/{}", base62));
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body, base62);
+
+    let (status, _, body) = call(&format!("/ms/{}", base62));
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(
+        body,
+        KsuidMs::from_base62(base62)
+            .unwrap()
+            .timestamp_millis()
+            .to_string()
+    );
+
+    let (status, content_type, body) = call("/items/not-a-ksuid");
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(content_type.unwrap(), "application/problem+json");
+    let body: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(body["status"], 400);
+    assert_eq!(body["detail"], "Got base62 ksuid of unexpected length 11");
+
+    // Routes without exactly one path parameter are a server bug, not an invalid id
+    let (status, content_type, _) = call(&format!("/tenants/acme/items/{}", base62));
+    assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+    assert_ne!(content_type.unwrap(), "application/problem+json");
+}
+
+// Rocket's route macros need to be at module level
+#[cfg(feature = "rocket")]
+mod rocket_routes {
+    use rocket::{get, FromForm};
+    use svix_ksuid::*;
+
+    #[get("/items/<id>")]
+    pub fn get_item(id: Ksuid) -> String {
+        id.to_string()
+    }
+
+    #[derive(FromForm)]
+    pub struct Query {
+        after: Option<KsuidMs>,
+    }
+
+    #[get("/items?<query..>")]
+    pub fn list_items(query: Query) -> String {
+        query.after.map(|id| id.to_string()).unwrap_or_default()
+    }
+
+    #[get("/search?<after>")]
+    pub fn search(after: Ksuid) -> String {
+        after.to_string()
+    }
+}
+
+#[cfg(feature = "rocket")]
+#[test]
+fn test_rocket() {
+    use rocket::http::Status;
+    use rocket::local::blocking::Client;
+    use rocket::routes;
+    use rocket_routes::*;
+
+    let rocket = rocket::build().mount("/", routes![get_item, list_items, search]);
+    let client = Client::tracked(rocket).unwrap();
+
+    let base62 = "1srOrx2ZWZBpBUvZwXKQmoEYga2";
+    let response = client.get(format!("/items/{}", base62)).dispatch();
+    assert_eq!(response.status(), Status::Ok);
+    assert_eq!(response.into_string().unwrap(), base62);
+
+    // Invalid ids never make it to the handler
+    let response = client.get("/items/not-a-ksuid").dispatch();
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let response = client.get(format!("/items?after={}", base62)).dispatch();
+    assert_eq!(response.status(), Status::Ok);
+    assert_eq!(response.into_string().unwrap(), base62);
+
+    let response = client.get("/items").dispatch();
+    assert_eq!(response.into_string().unwrap(), "");
+
+    let response = client.get(format!("/search?after={}", base62)).dispatch();
+    assert_eq!(response.into_string().unwrap(), base62);
+
+    let response = client.get("/search?after=not-a-ksuid").dispatch();
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+}
+
+#[cfg(feature = "poem-openapi")]
+#[test]
+fn test_poem_openapi() {
+    use poem_openapi::registry::MetaSchemaRef;
+    use poem_openapi::types::{ParseFromJSON, ParseFromParameter, ToJSON, Type};
+    use serde_json::json;
+
+    let base62 = "1srOrx2ZWZBpBUvZwXKQmoEYga2";
+    let ksuid = Ksuid::from_base62(base62).unwrap();
+    assert_eq!(ksuid.to_json(), Some(json!(base62)));
+    assert_eq!(Ksuid::parse_from_json(Some(json!(base62))).unwrap(), ksuid);
+    assert!(Ksuid::parse_from_json(Some(json!("not-a-ksuid"))).is_err());
+    assert!(Ksuid::parse_from_json(Some(json!(1))).is_err());
+    assert!(Ksuid::parse_from_json(None).is_err());
+    assert_eq!(Ksuid::parse_from_parameter(base62).unwrap(), ksuid);
+    assert!(Ksuid::parse_from_parameter("not-a-ksuid").is_err());
+
+    let ksuidms = KsuidMs::from_base62(base62).unwrap();
+    assert_eq!(ksuidms.to_json(), Some(json!(base62)));
+    assert_eq!(KsuidMs::parse_from_parameter(base62).unwrap(), ksuidms);
+
+    match Ksuid::schema_ref() {
+        MetaSchemaRef::Inline(schema) => {
+            assert_eq!(schema.ty, "string");
+            assert_eq!(schema.format, Some("ksuid"));
+            assert_eq!(schema.min_length, Some(27));
+            assert_eq!(schema.max_length, Some(27));
+            assert_eq!(schema.pattern.as_deref(), Some(KSUID_PATTERN));
+        }
+        MetaSchemaRef::Reference(_) => panic!("Expected an inline schema"),
+    }
+    assert_ne!(Ksuid::name(), KsuidMs::name());
+}
+
+#[cfg(feature = "salvo")]
+#[test]
+fn test_salvo() {
+    use salvo_core::http::body::ResBody;
+    use salvo_core::http::StatusCode;
+    use salvo_core::{Depot, Extractible, Request, Response, Writer};
+
+    let base62 = "1srOrx2ZWZBpBUvZwXKQmoEYga2";
+    let ksuid = Ksuid::from_base62(base62).unwrap();
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap();
+
+    runtime.block_on(async {
+        let mut req = Request::new();
+        req.params_mut().insert("id", base62.to_owned());
+        assert_eq!(Ksuid::extract(&mut req).await.unwrap(), ksuid);
+        assert_eq!(
+            Ksuid::extract_with_arg(&mut req, "id").await.unwrap(),
+            ksuid
+        );
+        assert_eq!(
+            KsuidMs::extract_with_arg(&mut req, "id").await.unwrap(),
+            KsuidMs::from_bytes(*ksuid.bytes())
+        );
+        assert!(Ksuid::extract_with_arg(&mut req, "other").await.is_err());
+
+        // Falls back to the query
+        let mut req = Request::new();
+        *req.uri_mut() = format!("/items?after={}", base62).parse().unwrap();
+        assert_eq!(
+            Ksuid::extract_with_arg(&mut req, "after").await.unwrap(),
+            ksuid
+        );
+        // Routes without exactly one path parameter are a server bug, not an invalid id
+        let err = Ksuid::extract(&mut req).await.unwrap_err();
+        let mut res = Response::new();
+        err.write(&mut req, &mut Depot::new(), &mut res).await;
+        assert_eq!(res.status_code, Some(StatusCode::INTERNAL_SERVER_ERROR));
+    });
+
+    let mut req = Request::new();
+    req.params_mut().insert("id", "not-a-ksuid".to_owned());
+    let err = runtime
+        .block_on(Ksuid::extract_with_arg(&mut req, "id"))
+        .unwrap_err();
+    let rejection = KsuidRejection::new("Got base62 ksuid of unexpected length 11");
+    assert_eq!(format!("{:?}", err), format!("{:?}", rejection));
+
+    let mut res = Response::new();
+    res.render(rejection.clone());
+    assert_eq!(res.status_code, Some(StatusCode::BAD_REQUEST));
+    assert_eq!(
+        res.headers().get("content-type").unwrap(),
+        "application/problem+json"
+    );
+    match res.take_body() {
+        ResBody::Once(body) => assert_eq!(body, rejection.to_problem_json()),
+        _ => panic!("Expected a single chunk body"),
+    }
+}
+
+#[cfg(feature = "opentelemetry")]
+#[test]
+fn test_opentelemetry() {
+    use opentelemetry::{SpanId, TraceId};
+
+    let payload: Vec<u8> = (1..=16).collect();
+    let ksuid = Ksuid::from_seconds(Some(1_621_627_443), Some(&payload));
+    assert_eq!(
+        ksuid.trace_id(),
+        TraceId::from_hex("0102030405060708090a0b0c0d0e0f10").unwrap()
+    );
+    assert_eq!(
+        ksuid.span_id(),
+        SpanId::from_hex("090a0b0c0d0e0f10").unwrap()
+    );
+    assert_eq!(
+        Ksuid::from_trace_id(Some(1_621_627_443), ksuid.trace_id()),
+        ksuid
+    );
+    assert_eq!(
+        Ksuid::from_trace_id(None, ksuid.trace_id()).trace_id(),
+        ksuid.trace_id()
+    );
+
+    // Uses the same bytes as a normal ksuid
+    let ksuidms = KsuidMs::from_bytes(*ksuid.bytes());
+    assert_eq!(ksuidms.trace_id(), ksuid.trace_id());
+    assert_eq!(ksuidms.span_id(), ksuid.span_id());
+    assert_eq!(
+        KsuidMs::from_trace_id(Some(1_621_627_443), ksuid.trace_id()),
+        ksuidms
+    );
+}
+
+#[cfg(feature = "ffi")]
+#[test]
+fn test_ffi() {
+    use svix_ksuid::ffi::*;
+
+    unsafe {
+        let mut id = [0u8; KSUID_FFI_BYTES];
+        assert_eq!(ksuid_new(id.as_mut_ptr()), KSUID_OK);
+        let mut s = [0 as std::os::raw::c_char; KSUID_FFI_STRING_SIZE];
+        assert_eq!(ksuid_to_string(id.as_ptr(), s.as_mut_ptr()), KSUID_OK);
+        let as_str = std::ffi::CStr::from_ptr(s.as_ptr()).to_str().unwrap();
+        assert_eq!(Ksuid::from_bytes(id).to_string(), as_str);
+
+        let mut parsed = [0u8; KSUID_FFI_BYTES];
+        assert_eq!(ksuid_parse(s.as_ptr(), 27, parsed.as_mut_ptr()), KSUID_OK);
+        assert_eq!(parsed, id);
+        assert_eq!(ksuid_is_valid(s.as_ptr(), 27), 1);
+        assert_eq!(ksuid_is_valid(s.as_ptr(), 26), 0);
+        assert_eq!(
+            ksuid_parse(s.as_ptr(), 26, parsed.as_mut_ptr()),
+            KSUID_ERR_INVALID
+        );
+
+        let payload = [7u8; 16];
+        assert_eq!(
+            ksuid_from_parts(1_621_627_443, payload.as_ptr(), id.as_mut_ptr()),
+            KSUID_OK
+        );
+        let mut timestamp = 0i64;
+        assert_eq!(ksuid_timestamp(id.as_ptr(), &mut timestamp), KSUID_OK);
+        assert_eq!(timestamp, 1_621_627_443);
+        assert_eq!(Ksuid::from_bytes(id).payload(), &payload);
+
+        assert_eq!(ksuid_ms_new(id.as_mut_ptr()), KSUID_OK);
+        assert_eq!(
+            ksuid_ms_timestamp_millis(id.as_ptr(), &mut timestamp),
+            KSUID_OK
+        );
+        assert_eq!(KsuidMs::from_bytes(id).timestamp_millis(), timestamp);
+
+        assert_eq!(ksuid_new(std::ptr::null_mut()), KSUID_ERR_NULL);
+        assert_eq!(
+            ksuid_to_string(std::ptr::null(), s.as_mut_ptr()),
+            KSUID_ERR_NULL
+        );
+        assert_eq!(ksuid_is_valid(std::ptr::null(), 27), KSUID_ERR_NULL);
+    }
+}
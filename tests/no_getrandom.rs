@@ -0,0 +1,64 @@
+#![cfg(not(feature = "getrandom"))]
+
+use std::time::{Duration, UNIX_EPOCH};
+use svix_ksuid::*;
+
+struct Counter(u8);
+
+impl EntropySource for Counter {
+    fn fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        for b in dest.iter_mut() {
+            *b = self.0;
+            self.0 = self.0.wrapping_add(1);
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn test_new_with_entropy() {
+    let timestamp = UNIX_EPOCH + Duration::from_secs(1_621_627_443);
+    let ksuid = Ksuid::new_with_entropy(Some(timestamp), &mut Counter(0)).unwrap();
+    assert_eq!(ksuid.system_time(), timestamp);
+    assert_eq!(
+        ksuid.payload(),
+        &core::array::from_fn::<u8, 16, _>(|i| i as u8)
+    );
+
+    let ksuid = KsuidMs::new_with_entropy(Some(timestamp), &mut Counter(0)).unwrap();
+    assert_eq!(ksuid.system_time(), timestamp);
+    assert_eq!(
+        ksuid.payload(),
+        &core::array::from_fn::<u8, 15, _>(|i| i as u8)
+    );
+}
+
+#[test]
+fn test_generator_from_entropy() {
+    let mut generator = Generator::from_entropy(Counter(0));
+    let first: Ksuid = generator.generate();
+    let second: Ksuid = generator.generate();
+    assert_eq!(first.payload()[0], 0);
+    assert_eq!(second.payload()[0], 16);
+}
+
+#[test]
+fn test_from_system_time_and_payload() {
+    let timestamp = UNIX_EPOCH + Duration::from_millis(1_621_627_443_112);
+    let ksuid = KsuidMs::from_system_time_and_payload(timestamp, &[7; 15]);
+    assert_eq!(ksuid.system_time(), timestamp);
+    assert_eq!(ksuid.payload(), &[7; 15]);
+}
+
+#[test]
+fn test_default_entropy_without_source() {
+    // No source is set with set_entropy_source() in this test binary
+    let err = Ksuid::new_with_entropy(None, &mut DefaultEntropy).unwrap_err();
+    assert!(err.to_string().contains("set_entropy_source"));
+}
+
+#[test]
+fn test_secure_ksuid_without_source() {
+    // No source is set with set_entropy_source() in this test binary
+    assert!(SecureKsuid::<Ksuid>::try_new().is_err());
+}